@@ -24,6 +24,15 @@ pub struct Mutations {
     pub for_exprs: FxHashMap<NodeId, ExprMut>,
     /// Used for validation, not dts generation.
     pub for_callable: FxHashMap<NodeId, CallableMut>,
+
+    /// If `true`, the analyzer stores the type of each expression into
+    /// [Mutations::expr_types].
+    pub record_expr_types: bool,
+    /// Used by lint passes, not dts generation.
+    pub expr_types: FxHashMap<NodeId, Type>,
+    /// Contextual types of expressions, recorded along with
+    /// [Mutations::expr_types]. Used by lint passes, not dts generation.
+    pub expr_contextual_types: FxHashMap<NodeId, Type>,
 }
 
 #[derive(Default)]
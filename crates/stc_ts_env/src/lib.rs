@@ -3,6 +3,7 @@ use std::{str::FromStr, sync::Arc};
 use parking_lot::Mutex;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+use stc_ts_builtin_types::Lib;
 use stc_ts_errors::{Error, ErrorKind};
 use stc_ts_type_ops::Fix;
 use stc_ts_types::{Id, Type};
@@ -12,9 +13,13 @@ use swc_atoms::JsWord;
 use swc_common::{Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::EsVersion;
 
-pub use self::marks::{MarkExt, Marks};
+pub use self::{
+    marks::{MarkExt, Marks},
+    options::{option_kind, OptionKind},
+};
 
 mod marks;
+mod options;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct BuiltIn {
@@ -26,6 +31,22 @@ impl BuiltIn {
     pub fn new(vars: FxHashMap<JsWord, Type>, types: FxHashMap<JsWord, Type>) -> Self {
         BuiltIn { vars, types }
     }
+
+    /// Returns declarations of `self` with declarations of `other` which are
+    /// not declared by `self`.
+    pub fn merged_with(&self, other: &BuiltIn) -> BuiltIn {
+        let mut vars = self.vars.clone();
+        for (name, ty) in &other.vars {
+            vars.entry(name.clone()).or_insert_with(|| ty.clone());
+        }
+
+        let mut types = self.types.clone();
+        for (name, ty) in &other.types {
+            types.entry(name.clone()).or_insert_with(|| ty.clone());
+        }
+
+        BuiltIn { vars, types }
+    }
 }
 
 /// Stuffs which can be changed between runs.
@@ -36,8 +57,11 @@ pub struct Env {
     target: EsVersion,
     module: ModuleConfig,
     builtin: Arc<BuiltIn>,
+    /// Libraries used to create `builtin`.
+    libs: Vec<Lib>,
     global_types: Arc<Mutex<FxHashMap<JsWord, Type>>>,
     global_vars: Arc<Mutex<FxHashMap<JsWord, Type>>>,
+    jsx_import_source: Option<JsWord>,
 }
 
 impl Env {
@@ -45,14 +69,45 @@ impl Env {
         Self {
             stable: env,
             builtin,
+            libs: Default::default(),
             target,
             module,
             global_types: Default::default(),
             global_vars: Default::default(),
             rule,
+            jsx_import_source: None,
         }
     }
 
+    /// Sets the module specifier used to import the `JSX` namespace in
+    /// [JsxMode::ReactJsx] and [JsxMode::ReactJsxdev].
+    pub fn with_jsx_import_source(mut self, jsx_import_source: Option<JsWord>) -> Self {
+        self.jsx_import_source = jsx_import_source;
+        self
+    }
+
+    /// Records libraries used to create the built-in declarations.
+    pub fn with_libs(mut self, libs: Vec<Lib>) -> Self {
+        self.libs = libs;
+        self
+    }
+
+    /// Replaces the built-in declarations. Global types and variables
+    /// declared so far are kept.
+    pub fn with_builtin(mut self, builtin: Arc<BuiltIn>) -> Self {
+        self.builtin = builtin;
+        self
+    }
+
+    pub fn builtin(&self) -> &BuiltIn {
+        &self.builtin
+    }
+
+    /// Empty if the [Env] is not created from libraries.
+    pub fn libs(&self) -> &[Lib] {
+        &self.libs
+    }
+
     pub const fn shared(&self) -> &StableEnv {
         &self.stable
     }
@@ -69,6 +124,11 @@ impl Env {
         self.rule
     }
 
+    /// `jsxImportSource` of `tsc`. Defaults to `react`.
+    pub fn jsx_import_source(&self) -> JsWord {
+        self.jsx_import_source.clone().unwrap_or_else(|| "react".into())
+    }
+
     pub fn declare_global_var(&mut self, name: JsWord, ty: Type) {
         ty.assert_clone_cheap();
 
@@ -189,6 +249,13 @@ pub struct Rule {
     pub always_strict: bool,
     pub strict_null_checks: bool,
     pub strict_function_types: bool,
+    pub exact_optional_property_types: bool,
+    pub no_unchecked_indexed_access: bool,
+    pub no_property_access_from_index_signature: bool,
+    pub strict_property_initialization: bool,
+    pub no_implicit_override: bool,
+    pub use_unknown_in_catch_variables: bool,
+    pub strict_bind_call_apply: bool,
 
     pub allow_unreachable_code: bool,
     pub allow_unused_labels: bool,
@@ -201,6 +268,8 @@ pub struct Rule {
     pub no_unused_parameters: bool,
     pub use_define_property_for_class_fields: bool,
     pub no_lib: bool,
+    pub es_module_interop: bool,
+    pub allow_synthetic_default_imports: bool,
 
     pub jsx: JsxMode,
 }
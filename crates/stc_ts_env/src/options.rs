@@ -0,0 +1,182 @@
+//! Compiler options of `tsc`.
+//!
+//! This is shared by `tsconfig.json` and the `// @option: value` directives of
+//! conformance tests, so both configure the type checker in the same way.
+
+use crate::Rule;
+
+/// How the type checker uses a compiler option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionKind {
+    /// A boolean flag stored in [Rule]. See [Rule::set_option].
+    Rule,
+
+    /// Stored in [crate::Env] instead of [Rule], like `target`, `module`, `lib`
+    /// or `jsxImportSource`. The caller should handle it.
+    Env,
+
+    /// Does not change the result of type checking, like options only related
+    /// to emitting.
+    Ignored,
+
+    /// Changes the result of type checking, but is not supported yet. The
+    /// caller should report it, or skip the test using it.
+    Unsupported,
+}
+
+/// Options stored in [crate::Env].
+const ENV_OPTIONS: &[&str] = &["target", "module", "lib", "jsx", "jsximportsource"];
+
+/// Options which do not change the result of type checking.
+const IGNORED_OPTIONS: &[&str] = &[
+    "charset",
+    "composite",
+    "declaration",
+    "declarationdir",
+    "declarationmap",
+    "diagnostics",
+    "disablesizelimit",
+    "downleveliteration",
+    "emitbom",
+    "emitdeclarationonly",
+    "emitdecoratormetadata",
+    "extendeddiagnostics",
+    "incremental",
+    "inlinesourcemap",
+    "inlinesources",
+    "listemittedfiles",
+    "listfiles",
+    "maproot",
+    "newline",
+    "noemit",
+    "noemithelpers",
+    "noemitonerror",
+    "noerrortruncation",
+    "noimplicitusestrict",
+    "notypesandsymbols",
+    "out",
+    "outdir",
+    "outfile",
+    "preserveconstenums",
+    "pretty",
+    "removecomments",
+    "skipdefaultlibcheck",
+    "skiplibcheck",
+    "sourcemap",
+    "sourceroot",
+    "stripinternal",
+    "traceresolution",
+    "tsbuildinfofile",
+    "usecasesensitivefilenames",
+];
+
+/// Options which change the result of type checking, but are not supported
+/// yet.
+const UNSUPPORTED_OPTIONS: &[&str] = &[
+    "allowarbitraryextensions",
+    "allowimportingtsextensions",
+    "allowjs",
+    "allowumdglobalaccess",
+    "baseurl",
+    "checkjs",
+    "customconditions",
+    "experimentaldecorators",
+    "forceconsistentcasinginfilenames",
+    "importhelpers",
+    "importsnotusedasvalues",
+    "isolatedmodules",
+    "jsxfactory",
+    "jsxfragmentfactory",
+    "keyofstringsonly",
+    "maxnodemodulejsdepth",
+    "moduledetection",
+    "moduleresolution",
+    "modulesuffixes",
+    "noresolve",
+    "paths",
+    "preservesymlinks",
+    "preservevalueimports",
+    "reactnamespace",
+    "resolvejsonmodule",
+    "resolvepackagejsonexports",
+    "resolvepackagejsonimports",
+    "rootdir",
+    "rootdirs",
+    "typeroots",
+    "types",
+    "verbatimmodulesyntax",
+];
+
+/// Returns how the type checker uses the option named `name`, or [None] if it's
+/// not an option of `tsc`.
+///
+/// `name` is case-insensitive, like `tsc`.
+pub fn option_kind(name: &str) -> Option<OptionKind> {
+    let name = name.to_ascii_lowercase();
+
+    if Rule::default().set_option(&name, true) {
+        return Some(OptionKind::Rule);
+    }
+
+    if ENV_OPTIONS.contains(&&*name) {
+        return Some(OptionKind::Env);
+    }
+
+    if IGNORED_OPTIONS.contains(&&*name) {
+        return Some(OptionKind::Ignored);
+    }
+
+    if UNSUPPORTED_OPTIONS.contains(&&*name) {
+        return Some(OptionKind::Unsupported);
+    }
+
+    None
+}
+
+impl Rule {
+    /// Sets the boolean option named `name`. `name` is case-insensitive.
+    ///
+    /// Returns `false` if the option is not stored in [Rule].
+    pub fn set_option(&mut self, name: &str, value: bool) -> bool {
+        match &*name.to_ascii_lowercase() {
+            "strict" => {
+                self.no_implicit_any = value;
+                self.no_implicit_this = value;
+                self.always_strict = value;
+                self.strict_null_checks = value;
+                self.strict_function_types = value;
+                self.strict_property_initialization = value;
+                self.use_unknown_in_catch_variables = value;
+                self.strict_bind_call_apply = value;
+            }
+            "noimplicitany" => self.no_implicit_any = value,
+            "noimplicitthis" => self.no_implicit_this = value,
+            "alwaysstrict" => self.always_strict = value,
+            "strictnullchecks" => self.strict_null_checks = value,
+            "strictfunctiontypes" => self.strict_function_types = value,
+            "exactoptionalpropertytypes" => self.exact_optional_property_types = value,
+            "nouncheckedindexedaccess" => self.no_unchecked_indexed_access = value,
+            "nopropertyaccessfromindexsignature" => self.no_property_access_from_index_signature = value,
+            "strictpropertyinitialization" => self.strict_property_initialization = value,
+            "noimplicitoverride" => self.no_implicit_override = value,
+            "useunknownincatchvariables" => self.use_unknown_in_catch_variables = value,
+            "strictbindcallapply" => self.strict_bind_call_apply = value,
+            "allowunreachablecode" => self.allow_unreachable_code = value,
+            "allowunusedlabels" => self.allow_unused_labels = value,
+            "nofallthroughcasesinswitch" => self.no_fallthrough_cases_in_switch = value,
+            "noimplicitreturns" => self.no_implicit_returns = value,
+            "suppressexcesspropertyerrors" => self.suppress_excess_property_errors = value,
+            "suppressimplicitanyindexerrors" => self.suppress_implicit_any_index_errors = value,
+            "nostrictgenericchecks" => self.no_strict_generic_checks = value,
+            "nounusedlocals" => self.no_unused_locals = value,
+            "nounusedparameters" => self.no_unused_parameters = value,
+            "usedefineforclassfields" => self.use_define_property_for_class_fields = value,
+            "nolib" => self.no_lib = value,
+            "esmoduleinterop" => self.es_module_interop = value,
+            "allowsyntheticdefaultimports" => self.allow_synthetic_default_imports = value,
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -9,6 +9,7 @@ use std::{
     ops::RangeInclusive,
     panic::Location,
     path::PathBuf,
+    sync::Arc,
 };
 
 use ansi_term::Color::Yellow;
@@ -16,11 +17,11 @@ use derivative::Derivative;
 use fmt::Formatter;
 use static_assertions::assert_eq_size;
 use stc_ts_types::{name::Name, Id, Key, ModuleId, Type, TypeElement, TypeParamInstantiation};
-use stc_utils::stack::StackOverflowError;
+use stc_utils::stack::{InstantiationLimitError, StackOverflowError};
 use swc_atoms::JsWord;
 use swc_common::{
     errors::{DiagnosticId, Handler},
-    Span, Spanned, DUMMY_SP,
+    FileName, Span, Spanned, DUMMY_SP,
 };
 use swc_ecma_ast::{AssignOp, BinaryOp, UpdateOp};
 
@@ -134,6 +135,34 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2604
+    JsxElementTypeHasNoSignatures {
+        span: Span,
+    },
+
+    /// TS2607
+    JsxElementClassWithoutAttributesProperty {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS2608
+    JsxTypeHasMoreThanOneProperty {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS2710
+    JsxChildrenSpecifiedTwice {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS2786
+    InvalidJsxComponent {
+        span: Span,
+    },
+
     /// TS2538
     TypeCannotBeUsedForIndex {
         span: Span,
@@ -235,10 +264,50 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2565
+    ClassPropUsedBeforeInitialization {
+        span: Span,
+    },
+
     /// TS2610
     DefinedWithAccessorInSuper {
         span: Span,
     },
+
+    /// TS4111
+    ///
+    /// `Property '{prop}' comes from an index signature, so it must be
+    /// accessed with ['{prop}'].`
+    PropertyFromIndexSignatureMustBeAccessedWithBracket {
+        span: Span,
+        prop: Box<Key>,
+    },
+
+    /// TS4112
+    OverrideInClassWithoutSuperClass {
+        span: Span,
+    },
+
+    /// TS4113
+    OverrideOfUnknownMember {
+        span: Span,
+    },
+
+    /// TS4114
+    OverrideModifierRequired {
+        span: Span,
+    },
+
+    /// TS4115
+    OverrideModifierRequiredForParamProp {
+        span: Span,
+    },
+
+    /// TS4116
+    OverrideModifierRequiredForAbstractMember {
+        span: Span,
+    },
+
     /// TS5048
     OptionInvalidForEs3 {
         span: Span,
@@ -619,6 +688,44 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2589
+    ExcessivelyDeepInstantiation {
+        span: Span,
+    },
+
+    /// TS2578
+    UnusedTsExpectError {
+        span: Span,
+    },
+
+    /// The analyzer panicked while validating a file.
+    ///
+    /// This is not a typescript error, and the code is `0`.
+    InternalError {
+        span: Span,
+        info: Box<InternalErrorInfo>,
+    },
+
+    /// A file could not be loaded or parsed, so it's not checked.
+    ///
+    /// This is not a typescript error, and the code is `0`.
+    LoadFailed {
+        span: Span,
+        file: Arc<FileName>,
+        /// Cause of the failure.
+        msg: String,
+    },
+
+    /// Reported by a lint pass of `stc_ts_passes`.
+    ///
+    /// This is not a typescript error, and the code is `0`.
+    Lint {
+        span: Span,
+        /// Name of the lint rule.
+        rule: &'static str,
+        msg: String,
+    },
+
     /// TS2420
     InvalidImplOfInterface {
         span: Span,
@@ -883,6 +990,11 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS18033
+    BigIntInEnum {
+        span: Span,
+    },
+
     /// TS2352
     InvalidTupleCast {
         span: Span,
@@ -920,6 +1032,11 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS7015
+    ImplicitAnyBecauseIndexIsNotNumber {
+        span: Span,
+    },
+
     /// TS7022
     ImplicitAnyBecauseOfSelfRef {
         span: Span,
@@ -1115,6 +1232,45 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2375, or TS2379 if wrapped in [ErrorKind::WrongArgType]
+    ///
+    /// Type '{1}' is not assignable to type '{0}' with
+    /// 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the
+    /// types of the target's properties.
+    ///
+    /// [ErrorKind::AssignFailed] caused by
+    /// [ErrorKind::UndefinedNotAssignableToExactOptionalProp].
+    ExactOptionalPropAssignFailed {
+        span: Span,
+        #[derivative(Debug = "ignore")]
+        left: Box<Type>,
+        #[derivative(Debug = "ignore")]
+        right: Box<Type>,
+        cause: Vec<Error>,
+    },
+
+    /// TS2412
+    ///
+    /// Type '{1}' is not assignable to type '{0}' with
+    /// 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the
+    /// type of the target.
+    ///
+    /// `obj.prop = undefined` where `prop` is an optional property and
+    /// `exactOptionalPropertyTypes` is enabled.
+    ExactOptionalPropWriteFailed {
+        span: Span,
+        #[derivative(Debug = "ignore")]
+        left: Box<Type>,
+        #[derivative(Debug = "ignore")]
+        right: Box<Type>,
+    },
+
+    /// `undefined` is assigned to an optional property which does not accept
+    /// `undefined` because `exactOptionalPropertyTypes` is enabled.
+    UndefinedNotAssignableToExactOptionalProp {
+        span: Span,
+    },
+
     ObjectAssignFailed {
         span: Span,
         errors: Vec<Error>,
@@ -1256,6 +1412,21 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS1259
+    ModuleCanOnlyBeDefaultImportedUsingFlag {
+        span: Span,
+    },
+
+    /// TS1196
+    CatchVarTypeAnnMustBeAnyOrUnknown {
+        span: Span,
+    },
+
+    /// TS2497
+    ModuleCanOnlyBeReferencedWithDefaultImport {
+        span: Span,
+    },
+
     AnyTypeUsedAsCalleeWithTypeArgs {
         span: Span,
     },
@@ -1724,6 +1895,9 @@ impl ErrorKind {
             ErrorKind::TS1318 { .. } => 1318,
             ErrorKind::TS1319 { .. } => 1319,
             ErrorKind::ExportEqualsMixedWithOtherExports { .. } => 2309,
+            ErrorKind::ModuleCanOnlyBeDefaultImportedUsingFlag { .. } => 1259,
+            ErrorKind::CatchVarTypeAnnMustBeAnyOrUnknown { .. } => 1196,
+            ErrorKind::ModuleCanOnlyBeReferencedWithDefaultImport { .. } => 2497,
             ErrorKind::AnyTypeUsedAsCalleeWithTypeArgs { .. } => 2347,
             ErrorKind::InvalidLhsOfInOperator { .. } => 2360,
             ErrorKind::InvalidRhsForInOperator { .. } => 2638,
@@ -1756,7 +1930,11 @@ impl ErrorKind {
             | ErrorKind::InvalidAssignmentOfArray { .. }
             | ErrorKind::UnknownPropertyInObjectLiteralAssignment { .. }
             | ErrorKind::InvalidOpAssign { .. }
-            | ErrorKind::TupleAssignError { .. } => 2322,
+            | ErrorKind::TupleAssignError { .. }
+            | ErrorKind::UndefinedNotAssignableToExactOptionalProp { .. } => 2322,
+
+            ErrorKind::ExactOptionalPropAssignFailed { .. } => 2375,
+            ErrorKind::ExactOptionalPropWriteFailed { .. } => 2412,
 
             ErrorKind::NonOverlappingTypeCast { .. } => 2352,
 
@@ -1807,6 +1985,7 @@ impl ErrorKind {
             ErrorKind::CannotAssignAbstractConstructorToNonAbstractConstructor { .. } => 2322,
             ErrorKind::CannotCreateInstanceOfAbstractClass { .. } => 2511,
             ErrorKind::NotSatisfyConstraint { .. } => 2344,
+            ErrorKind::WrongArgType { inner, .. } if matches!(&***inner, ErrorKind::ExactOptionalPropAssignFailed { .. }) => 2379,
             ErrorKind::WrongArgType { .. } => 2345,
 
             ErrorKind::ComputedMemberInEnumWithStrMember { .. } => 2553,
@@ -1962,6 +2141,8 @@ impl ErrorKind {
 
             ErrorKind::ImplicitAnyBecauseIndexTypeIsWrong { .. } => 7053,
 
+            ErrorKind::ImplicitAnyBecauseIndexIsNotNumber { .. } => 7015,
+
             ErrorKind::ImplicitAnyBecauseOfSelfRef { .. } => 7022,
 
             ErrorKind::ConstructorIsKeyword { .. } => 18012,
@@ -1976,6 +2157,8 @@ impl ErrorKind {
 
             ErrorKind::OptionalChainCannotContainPrivateIdentifier { .. } => 18030,
 
+            ErrorKind::BigIntInEnum { .. } => 18033,
+
             ErrorKind::TypeAnnOnLhsOfForInLoops { .. } => 2404,
             ErrorKind::TypeAnnOnLhsOfForOfLoops { .. } => 2483,
 
@@ -2082,7 +2265,15 @@ impl ErrorKind {
 
             ErrorKind::DefinedWithAccessorInSuper { .. } => 2610,
 
+            ErrorKind::PropertyFromIndexSignatureMustBeAccessedWithBracket { .. } => 4111,
+            ErrorKind::OverrideInClassWithoutSuperClass { .. } => 4112,
+            ErrorKind::OverrideOfUnknownMember { .. } => 4113,
+            ErrorKind::OverrideModifierRequired { .. } => 4114,
+            ErrorKind::OverrideModifierRequiredForParamProp { .. } => 4115,
+            ErrorKind::OverrideModifierRequiredForAbstractMember { .. } => 4116,
+
             ErrorKind::ClassPropNotInitialized { .. } => 2564,
+            ErrorKind::ClassPropUsedBeforeInitialization { .. } => 2565,
 
             ErrorKind::VarMayNotBeInitialized { .. } => 2454,
 
@@ -2119,6 +2310,11 @@ impl ErrorKind {
             ErrorKind::TypeCannotBeUsedForIndex { .. } => 2538,
 
             ErrorKind::ImplicitAnyBecauseThereIsNoJsxInterface { .. } => 7026,
+            ErrorKind::JsxElementTypeHasNoSignatures { .. } => 2604,
+            ErrorKind::JsxElementClassWithoutAttributesProperty { .. } => 2607,
+            ErrorKind::JsxTypeHasMoreThanOneProperty { .. } => 2608,
+            ErrorKind::JsxChildrenSpecifiedTwice { .. } => 2710,
+            ErrorKind::InvalidJsxComponent { .. } => 2786,
 
             ErrorKind::ClassConstructorPrivate { .. } => 2673,
 
@@ -2140,6 +2336,10 @@ impl ErrorKind {
 
             ErrorKind::NonStringDynamicImport { .. } => 7036,
 
+            ErrorKind::ExcessivelyDeepInstantiation { .. } => 2589,
+
+            ErrorKind::UnusedTsExpectError { .. } => 2578,
+
             _ => 0,
         }
     }
@@ -2211,6 +2411,17 @@ impl From<Errors> for ErrorKind {
     }
 }
 
+/// Details of [ErrorKind::InternalError].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InternalErrorInfo {
+    /// Name of the file being validated.
+    pub file: String,
+    /// Message of the panic.
+    pub msg: String,
+    /// See [stc_utils::panic_context].
+    pub context: Vec<String>,
+}
+
 /// A utility type to track
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Errors(Vec<Error>);
@@ -2294,3 +2505,15 @@ impl From<StackOverflowError> for Error {
         ErrorKind::from(e).into()
     }
 }
+
+impl From<InstantiationLimitError> for ErrorKind {
+    fn from(e: InstantiationLimitError) -> Self {
+        ErrorKind::ExcessivelyDeepInstantiation { span: e.span }
+    }
+}
+
+impl From<InstantiationLimitError> for Error {
+    fn from(e: InstantiationLimitError) -> Self {
+        ErrorKind::from(e).into()
+    }
+}
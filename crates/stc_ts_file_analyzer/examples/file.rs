@@ -51,7 +51,8 @@ fn profile_file(file_name: &Path) {
                 libs.sort();
                 libs.dedup();
 
-                let env = Env::simple(case.rule, case.target, case.module_config, &libs);
+                let env =
+                    Env::simple(case.rule, case.target, case.module_config, &libs).with_jsx_import_source(case.jsx_import_source.clone());
                 let stable_env = env.shared().clone();
                 let generator = module_id::ModuleIdGenerator::default();
                 let path = Arc::new(FileName::Real(file_name.to_path_buf()));
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, cmp::max};
+use std::{borrow::Cow, cmp::max, iter::once};
 
 use fxhash::FxHashMap;
 use itertools::Itertools;
@@ -911,6 +911,23 @@ impl Analyzer<'_, '_> {
                 }
 
                 (_, RPat::Rest(..)) => {
+                    // `(a: number, b: string) => void` and `(...args: [number, string]) => void`
+                    if let Type::Tuple(r_tuple) = r.ty.normalize_instance() {
+                        if r_tuple.elems.iter().all(|elem| !elem.ty.is_rest()) && li.clone().all(|l| !matches!(l.pat, RPat::Rest(..))) {
+                            for (l, elem) in once(l).chain(li).zip(r_tuple.elems.iter()) {
+                                let r_ty = match elem.ty.normalize() {
+                                    Type::Optional(ty) => &*ty.ty,
+                                    ty => ty,
+                                };
+
+                                self.assign_param_type(data, &l.ty, r_ty, opts)
+                                    .context("tried to assign a rest parameter of a tuple type to parameters")?;
+                            }
+
+                            return Ok(());
+                        }
+                    }
+
                     // If r is an iterator, we should assign each element to l.
                     if let Ok(r_iter) = self.get_iterator(span, Cow::Borrowed(&r.ty), Default::default()) {
                         if let Ok(l_iter) = self.get_iterator(span, Cow::Borrowed(&l.ty), Default::default()) {
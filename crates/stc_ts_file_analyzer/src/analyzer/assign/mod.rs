@@ -10,7 +10,7 @@ use stc_ts_types::{
     LitType, Mapped, PropertySignature, QueryExpr, QueryType, Readonly, Ref, RestType, StringMapping, ThisType, Tuple, TupleElement, Type,
     TypeElement, TypeLit, TypeParam,
 };
-use stc_utils::{cache::Freeze, dev_span, ext::SpanExt, stack};
+use stc_utils::{cache::Freeze, dev_span, ext::SpanExt, stack, stats};
 use swc_atoms::js_word;
 use swc_common::{EqIgnoreSpan, Span, Spanned, TypeEq, DUMMY_SP};
 use swc_ecma_ast::{TruePlusMinus::*, *};
@@ -62,6 +62,10 @@ pub(crate) struct AssignOpts {
     /// - `Some(false)` is Used for `extends` check.
     pub allow_unknown_rhs: Option<bool>,
 
+    /// If `true`, unknown properties with a hyphenated name like `data-foo`
+    /// are allowed. Used for JSX attributes.
+    pub allow_unknown_hyphenated_rhs: bool,
+
     pub allow_missing_fields: bool,
 
     /// Allow assigning `unknown` type to other types. This should be `true` for
@@ -451,6 +455,7 @@ impl Analyzer<'_, '_> {
         right.assert_valid();
 
         let _stack = stack::track(opts.span)?;
+        stats::record(|s| s.assign_checks += 1);
 
         // if cfg!(debug_assertions) && span.is_dummy() {
         //     print_backtrace();
@@ -467,11 +472,18 @@ impl Analyzer<'_, '_> {
         }
 
         res.convert_err(|err| match err {
+            _ if is_caused_by_exact_optional_prop(&err) => ErrorKind::ExactOptionalPropAssignFailed {
+                span: opts.span,
+                left: box left.clone(),
+                right: box right.clone(),
+                cause: vec![err.into()],
+            },
             ErrorKind::AssignFailed { .. }
             | ErrorKind::Errors { .. }
             | ErrorKind::Unimplemented { .. }
             | ErrorKind::TupleAssignError { .. }
-            | ErrorKind::ObjectAssignFailed { .. } => err,
+            | ErrorKind::ObjectAssignFailed { .. }
+            | ErrorKind::ExactOptionalPropAssignFailed { .. } => err,
             _ => ErrorKind::AssignFailed {
                 span: opts.span,
                 left: box left.clone(),
@@ -3488,3 +3500,15 @@ pub(crate) fn get_tuple_subtract_count(t: &[TupleElement]) -> usize {
         }
     }
 }
+
+/// Returns `true` if `err` is caused by assigning `undefined` to an optional
+/// property while `exactOptionalPropertyTypes` is enabled.
+fn is_caused_by_exact_optional_prop(err: &ErrorKind) -> bool {
+    match err {
+        ErrorKind::UndefinedNotAssignableToExactOptionalProp { .. } => true,
+        ErrorKind::AssignFailed { cause: errors, .. } | ErrorKind::Errors { errors, .. } | ErrorKind::ObjectAssignFailed { errors, .. } => {
+            errors.iter().any(|err| is_caused_by_exact_optional_prop(err))
+        }
+        _ => false,
+    }
+}
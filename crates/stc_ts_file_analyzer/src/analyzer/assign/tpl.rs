@@ -3,7 +3,7 @@
 use stc_ts_ast_rnode::RTsLit;
 use stc_ts_errors::{debug::force_dump_type_as_string, ErrorKind};
 use stc_ts_types::{IntrinsicKind, LitType, StringMapping, TplType, Type};
-use stc_utils::dev_span;
+use stc_utils::{cache::Freeze, dev_span};
 use swc_common::{Span, TypeEq};
 use swc_ecma_ast::TsKeywordTypeKind;
 
@@ -39,7 +39,7 @@ impl Analyzer<'_, '_> {
         };
 
         for (i, ty) in inference.iter().enumerate() {
-            if !self.is_valid_type_for_tpl_lit_placeholder(span, ty, &l.types[i])? {
+            if !self.is_valid_type_for_tpl_lit_placeholder(ty, &l.types[i])? {
                 return Err(ErrorKind::SimpleAssignFailed { span, cause: None }.context(format!(
                     "verified types:\nsource = {}\ntarget = {}",
                     force_dump_type_as_string(ty),
@@ -52,7 +52,7 @@ impl Analyzer<'_, '_> {
     }
 
     /// Ported from `isValidTypeForTemplateLiteralPlaceholder` of `tsc`
-    pub(crate) fn is_valid_type_for_tpl_lit_placeholder(&mut self, span: Span, source: &Type, target: &Type) -> VResult<bool> {
+    pub(crate) fn is_valid_type_for_tpl_lit_placeholder(&mut self, source: &Type, target: &Type) -> VResult<bool> {
         let _tracing = dev_span!(
             "is_valid_type_for_tpl_lit_placeholder",
             source = tracing::field::display(&force_dump_type_as_string(source)),
@@ -85,17 +85,26 @@ impl Analyzer<'_, '_> {
                     ..
                 }) = target.normalize()
                 {
-                    return self.is_member_of_string_mapping(span, source, target);
+                    return self.is_member_of_string_mapping(source, target);
                 }
 
                 // TODO(kdy1): Return `Ok(false)` instead
             }
 
+            // The mapping over a pattern literal like `Uppercase<`${number}`>` is expanded in
+            // the target, so it's expanded in the same way here.
+            Type::StringMapping(mapping) => {
+                let expanded = self.expand_intrinsic_types(mapping.span, mapping, mapping.span)?.freezed();
+                if !matches!(expanded.normalize(), Type::StringMapping(..)) {
+                    return self.is_valid_type_for_tpl_lit_placeholder(&expanded, target);
+                }
+            }
+
             Type::Tpl(source) => {
                 if source.quasis.len() == 2 && source.quasis[0].value == "" && source.quasis[1].value == "" {
-                    // TODO(kdy1): Return `Ok(self.is_type_assignable_to(span, &source.types[0],
+                    // TODO(kdy1): Return `Ok(self.is_type_assignable_to(&source.types[0],
                     // target))` instead
-                    if self.is_type_assignable_to(span, &source.types[0], target) {
+                    if self.is_type_assignable_to(&source.types[0], target) {
                         return Ok(true);
                     }
                 } else {
@@ -106,7 +115,7 @@ impl Analyzer<'_, '_> {
             _ => {}
         }
 
-        Ok(self.is_type_assignable_to(span, source, target))
+        Ok(self.is_type_assignable_to(source, target))
     }
 
     /// Ported from `templateLiteralTypesDefinitelyUnrelated` of `tsc`.
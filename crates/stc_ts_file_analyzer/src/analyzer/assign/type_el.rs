@@ -24,6 +24,7 @@ use crate::{
         util::ResultExt,
         Analyzer,
     },
+    type_facts::TypeFacts,
     VResult,
 };
 
@@ -177,6 +178,10 @@ impl Analyzer<'_, '_> {
                                     continue;
                                 }
 
+                                if opts.allow_unknown_hyphenated_rhs && matches!(key, Key::Normal { sym, .. } if sym.contains('-')) {
+                                    continue;
+                                }
+
                                 done.push(key.clone());
                             }
 
@@ -1183,12 +1188,30 @@ impl Analyzer<'_, '_> {
                                                 }
                                             }
 
-                                            self.assign_inner(
-                                                data,
-                                                lp.type_ann.as_deref().unwrap_or(&Type::any(span, Default::default())),
-                                                rp.type_ann.as_deref().unwrap_or(&Type::any(span, Default::default())),
-                                                opts,
-                                            )
+                                            let any = Type::any(span, Default::default());
+                                            let l_ty = lp.type_ann.as_deref().unwrap_or(&any);
+                                            let r_ty = rp.type_ann.as_deref().unwrap_or(&any);
+                                            let exact = self.rule().exact_optional_property_types;
+
+                                            match self.assign_inner(data, l_ty, r_ty, opts) {
+                                                Ok(()) => Ok(()),
+                                                // With `exactOptionalPropertyTypes`, optional properties don't accept
+                                                // `undefined` implicitly. We use a dedicated error so that the caller
+                                                // can report TS2375 instead of TS2322, but only if the other parts of
+                                                // the type are assignable.
+                                                Err(err)
+                                                    if exact && lp.optional && !l_ty.contains_undefined() && r_ty.contains_undefined() =>
+                                                {
+                                                    let r_ty = self.apply_type_facts_to_type(TypeFacts::NEUndefined, r_ty.clone());
+
+                                                    if r_ty.is_never() || self.assign_inner(data, l_ty, &r_ty, opts).is_ok() {
+                                                        Err(ErrorKind::UndefinedNotAssignableToExactOptionalProp { span: rp.span }.into())
+                                                    } else {
+                                                        Err(err)
+                                                    }
+                                                }
+                                                Err(err) => Err(err),
+                                            }
                                         })()?;
 
                                         if let Some(pos) = unhandled_rhs.iter().position(|span| *span == rm.span()) {
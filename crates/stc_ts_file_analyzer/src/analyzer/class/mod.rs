@@ -41,6 +41,7 @@ use crate::{
 };
 
 mod order;
+mod prop_init;
 mod type_param;
 
 #[derive(Debug, Default)]
@@ -70,35 +71,6 @@ impl Analyzer<'_, '_> {
         };
 
         if !self.config.is_builtin {
-            // Disabled because of false positives when the constructor initializes the
-            // field.
-            #[allow(clippy::overly_complex_bool_expr)]
-            if false && self.rule().strict_null_checks {
-                if value.is_none() {
-                    if let Some(ty) = &ty {
-                        if self
-                            .assign_with_opts(
-                                &mut Default::default(),
-                                ty,
-                                &Type::Keyword(KeywordType {
-                                    span,
-                                    kind: TsKeywordTypeKind::TsUndefinedKeyword,
-                                    metadata: Default::default(),
-                                    tracker: Default::default(),
-                                }),
-                                AssignOpts {
-                                    span,
-                                    ..Default::default()
-                                },
-                            )
-                            .is_err()
-                        {
-                            self.storage.report(ErrorKind::ClassPropNotInitialized { span }.into())
-                        }
-                    }
-                }
-            }
-
             // Report error if type is not found.
             if let Some(ty) = &ty {
                 self.normalize(Some(span), Cow::Borrowed(ty), Default::default())
@@ -1539,6 +1511,165 @@ impl Analyzer<'_, '_> {
 
         self.storage.report_all(errors);
     }
+
+    /// Reports errors for `override` modifiers.
+    ///
+    /// TS4112 and TS4113 are always reported, while TS4114, TS4115 and TS4116
+    /// are reported only if `noImplicitOverride` is enabled.
+    fn report_errors_for_override_modifiers(&mut self, c: &RClass, class: &ClassDef) {
+        if self.config.is_builtin {
+            return;
+        }
+
+        let no_implicit_override = self.rule().no_implicit_override && !self.ctx.in_declare;
+
+        let prop_name_key = |key: &RPropName| match key {
+            RPropName::Ident(i) => Some(Key::Normal {
+                span: i.span,
+                sym: i.sym.clone(),
+            }),
+            RPropName::Str(s) => Some(Key::Normal {
+                span: s.span,
+                sym: s.value.clone(),
+            }),
+            _ => None,
+        };
+
+        let mut members = vec![];
+        for member in &c.body {
+            let (key, kind, is_static, is_override) = match member {
+                // `declare` only redeclares the type of an inherited property.
+                RClassMember::ClassProp(p) if p.declare => continue,
+                RClassMember::ClassProp(p) => (
+                    prop_name_key(&p.key),
+                    MemberKind::Normal {
+                        is_abstract: p.is_abstract,
+                    },
+                    p.is_static,
+                    p.is_override,
+                ),
+                RClassMember::Method(m) => (
+                    prop_name_key(&m.key),
+                    MemberKind::Normal {
+                        is_abstract: m.is_abstract,
+                    },
+                    m.is_static,
+                    m.is_override,
+                ),
+                RClassMember::Constructor(c) => {
+                    // Parameter properties are instance properties.
+                    for param in &c.params {
+                        if let RParamOrTsParamProp::TsParamProp(p) = param {
+                            let i = match &p.param {
+                                RTsParamPropParam::Ident(i) => &i.id,
+                                RTsParamPropParam::Assign(RAssignPat {
+                                    left: box RPat::Ident(i), ..
+                                }) => &i.id,
+                                _ => continue,
+                            };
+                            let key = Key::Normal {
+                                span: i.span,
+                                sym: i.sym.clone(),
+                            };
+
+                            members.push((key, MemberKind::ParamProp, false, p.is_override));
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            members.extend(key.map(|key| (key, kind, is_static, is_override)));
+        }
+
+        for (key, kind, is_static, is_override) in members {
+            let span = key.span();
+
+            let super_class = match &class.super_class {
+                Some(v) => v,
+                None => {
+                    if is_override {
+                        self.storage.report(ErrorKind::OverrideInClassWithoutSuperClass { span }.into());
+                    }
+                    continue;
+                }
+            };
+
+            match self.find_member_of_super_class(span, super_class, &key, is_static) {
+                SuperMember::Missing => {
+                    if is_override {
+                        self.storage.report(ErrorKind::OverrideOfUnknownMember { span }.into());
+                    }
+                }
+                SuperMember::Found { is_abstract } => {
+                    if !is_override && no_implicit_override {
+                        if is_abstract {
+                            // Implementing an abstract member does not require `override`, unless
+                            // the member is also abstract.
+                            if let MemberKind::Normal { is_abstract: true } = kind {
+                                self.storage
+                                    .report(ErrorKind::OverrideModifierRequiredForAbstractMember { span }.into());
+                            }
+                        } else if let MemberKind::ParamProp = kind {
+                            self.storage.report(ErrorKind::OverrideModifierRequiredForParamProp { span }.into());
+                        } else {
+                            self.storage.report(ErrorKind::OverrideModifierRequired { span }.into());
+                        }
+                    }
+                }
+                SuperMember::Unknown => {}
+            }
+        }
+    }
+
+    /// Finds a member named `key` from `super_ty` and its super classes.
+    fn find_member_of_super_class(&mut self, span: Span, super_ty: &Type, key: &Key, is_static: bool) -> SuperMember {
+        let super_ty = match self.normalize(Some(span), Cow::Borrowed(super_ty), Default::default()) {
+            Ok(v) => v,
+            Err(..) => return SuperMember::Unknown,
+        };
+
+        let sc = match super_ty.normalize() {
+            Type::ClassDef(sc) => sc,
+            _ => return SuperMember::Unknown,
+        };
+
+        for sm in &sc.body {
+            let (sm_key, sm_is_static, is_abstract) = match sm {
+                ClassMember::Method(m) => (&m.key, m.is_static, m.is_abstract),
+                ClassMember::Property(p) => (&p.key, p.is_static, p.is_abstract),
+                _ => continue,
+            };
+
+            if sm_is_static == is_static && sm_key.type_eq(key) {
+                return SuperMember::Found { is_abstract };
+            }
+        }
+
+        match &sc.super_class {
+            Some(super_ty) => self.find_member_of_super_class(span, super_ty, key, is_static),
+            None => SuperMember::Missing,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum MemberKind {
+    Normal {
+        is_abstract: bool,
+    },
+    /// A parameter property of the constructor.
+    ParamProp,
+}
+
+enum SuperMember {
+    Found {
+        is_abstract: bool,
+    },
+    Missing,
+    /// The super class is not a class, or it can't be resolved.
+    Unknown,
 }
 
 /// Order:
@@ -2037,7 +2168,10 @@ impl Analyzer<'_, '_> {
                 .report_errors_for_class_member_incompatible_with_index_signature(&class)
                 .report(&mut child.storage);
 
+            child.report_errors_for_uninitialized_class_props(c, &class);
+
             child.validate_inherited_members_from_super_class(None, &class);
+            child.report_errors_for_override_modifiers(c, &class);
             child.report_errors_for_wrong_implementations_of_class(None, &class);
             child.report_errors_for_conflicting_interfaces(&class.implements);
 
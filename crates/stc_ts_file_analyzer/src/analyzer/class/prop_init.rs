@@ -0,0 +1,513 @@
+use std::borrow::Cow;
+
+use fxhash::FxHashSet;
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{
+    RArrowExpr, RAssignExpr, RBinExpr, RBreakStmt, RClass, RClassMember, RCondExpr, RContinueStmt, RDoWhileStmt, RExpr, RForInStmt,
+    RForOfStmt, RForStmt, RFunction, RGetterProp, RIfStmt, RLabeledStmt, RLit, RMemberExpr, RMemberProp, RParamOrTsParamProp, RPat,
+    RPatOrExpr, RPropName, RReturnStmt, RSetterProp, RSwitchStmt, RThrowStmt, RTryStmt, RTsParamPropParam, RWhileStmt,
+};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::{ClassDef, ClassMember, Key, Type};
+use swc_atoms::JsWord;
+use swc_common::Span;
+use swc_ecma_ast::*;
+
+use crate::analyzer::Analyzer;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PropKey {
+    Normal(JsWord),
+    Private(JsWord),
+}
+
+impl PropKey {
+    fn matches(&self, key: &Key) -> bool {
+        match (self, key) {
+            (PropKey::Normal(l), Key::Normal { sym, .. }) => *l == *sym,
+            (PropKey::Private(l), Key::Private(r)) => *l == *r.id.sym(),
+            _ => false,
+        }
+    }
+}
+
+impl Analyzer<'_, '_> {
+    /// Reports instance properties which are not definitely assigned in the
+    /// constructor (TS2564), and properties used in the constructor before
+    /// being assigned (TS2565).
+    ///
+    /// Only properties without an initializer, a `?` or a `!` are checked, and
+    /// calls in the constructor are assumed to not assign properties.
+    pub(super) fn report_errors_for_uninitialized_class_props(&mut self, c: &RClass, class: &ClassDef) {
+        if self.config.is_builtin || self.ctx.in_declare {
+            return;
+        }
+
+        let rule = self.rule();
+        if !rule.strict_property_initialization || !rule.strict_null_checks {
+            return;
+        }
+
+        let constructor = c.body.iter().find_map(|member| match member {
+            RClassMember::Constructor(c) if c.body.is_some() => Some(c),
+            _ => None,
+        });
+
+        // Parameter properties are always initialized.
+        let param_props = constructor
+            .into_iter()
+            .flat_map(|c| &c.params)
+            .filter_map(|param| match param {
+                RParamOrTsParamProp::TsParamProp(p) => match &p.param {
+                    RTsParamPropParam::Ident(i) => Some(i.id.sym.clone()),
+                    RTsParamPropParam::Assign(p) => match &*p.left {
+                        RPat::Ident(i) => Some(i.id.sym.clone()),
+                        _ => None,
+                    },
+                },
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+
+        let mut props = vec![];
+        for member in &c.body {
+            let (key, span) = match member {
+                RClassMember::ClassProp(p)
+                    if !p.is_static
+                        && !p.is_optional
+                        && !p.is_abstract
+                        && !p.definite
+                        && !p.declare
+                        && p.value.is_none()
+                        && p.type_ann.is_some() =>
+                {
+                    match &p.key {
+                        RPropName::Ident(i) if !param_props.contains(&i.sym) => (PropKey::Normal(i.sym.clone()), i.span),
+                        RPropName::Str(s) => (PropKey::Normal(s.value.clone()), s.span),
+                        _ => continue,
+                    }
+                }
+
+                RClassMember::PrivateProp(p)
+                    if !p.is_static && !p.is_optional && !p.definite && p.value.is_none() && p.type_ann.is_some() =>
+                {
+                    (PropKey::Private(p.key.id.sym.clone()), p.key.span)
+                }
+
+                _ => continue,
+            };
+
+            let ty = class.body.iter().find_map(|member| match member {
+                ClassMember::Property(p) if !p.is_static && key.matches(&p.key) => p.value.as_deref(),
+                _ => None,
+            });
+            match ty {
+                Some(ty) if !self.accepts_undefined(span, ty) => props.push((key, span)),
+                _ => {}
+            }
+        }
+
+        if props.is_empty() {
+            return;
+        }
+
+        let mut v = InitAnalyzer {
+            props: props.iter().map(|(key, _)| key.clone()).collect(),
+            state: Some(Default::default()),
+            returns: Default::default(),
+            breaks: Default::default(),
+            used_before_assigned: Default::default(),
+        };
+
+        if let Some(body) = constructor.and_then(|c| c.body.as_ref()) {
+            body.visit_with(&mut v);
+        }
+
+        for span in v.used_before_assigned {
+            self.storage.report(ErrorKind::ClassPropUsedBeforeInitialization { span }.into());
+        }
+
+        // Properties should be assigned at the end of the constructor and at all
+        // `return` statements.
+        let assigned = match v.returns.into_iter().fold(v.state, |acc, s| join(acc, Some(s))) {
+            Some(v) => v,
+            // The constructor always throws.
+            None => return,
+        };
+
+        for (key, span) in props {
+            if !assigned.contains(&key) {
+                self.storage.report(ErrorKind::ClassPropNotInitialized { span }.into());
+            }
+        }
+    }
+
+    fn accepts_undefined(&mut self, span: Span, ty: &Type) -> bool {
+        let ty = match self.normalize(Some(span), Cow::Borrowed(ty), Default::default()) {
+            Ok(ty) => ty,
+            Err(..) => return true,
+        };
+
+        match ty.normalize() {
+            Type::Union(u) => u.types.iter().any(|ty| self.accepts_undefined(span, ty)),
+            ty => {
+                ty.is_any()
+                    || ty.is_unknown()
+                    || ty.is_kwd(TsKeywordTypeKind::TsUndefinedKeyword)
+                    || ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword)
+            }
+        }
+    }
+}
+
+/// Intersection of properties assigned in two code paths. [None] means that
+/// the path is unreachable.
+fn join(l: Option<FxHashSet<PropKey>>, r: Option<FxHashSet<PropKey>>) -> Option<FxHashSet<PropKey>> {
+    match (l, r) {
+        (None, v) | (v, None) => v,
+        (Some(mut l), Some(r)) => {
+            l.retain(|key| r.contains(key));
+            Some(l)
+        }
+    }
+}
+
+/// Definite assignment analysis for `this.foo` in a constructor.
+struct InitAnalyzer {
+    props: FxHashSet<PropKey>,
+    /// Properties assigned in the current code path. [None] if the current
+    /// code path is unreachable.
+    state: Option<FxHashSet<PropKey>>,
+    /// Assigned properties at each `return` statement.
+    returns: Vec<FxHashSet<PropKey>>,
+    /// Assigned properties at each `break` statement, for each loop, `switch`
+    /// statement or labeled statement. Labeled statements have a label.
+    breaks: Vec<(Option<JsWord>, Vec<Option<FxHashSet<PropKey>>>)>,
+    used_before_assigned: Vec<Span>,
+}
+
+impl InitAnalyzer {
+    /// Returns the key if `e` is `this.foo` and `foo` is checked.
+    fn prop_of(&self, e: &RMemberExpr) -> Option<(PropKey, Span)> {
+        if !matches!(&*e.obj, RExpr::This(..)) {
+            return None;
+        }
+
+        let (key, span) = match &e.prop {
+            RMemberProp::Ident(i) => (PropKey::Normal(i.sym.clone()), i.span),
+            RMemberProp::PrivateName(p) => (PropKey::Private(p.id.sym.clone()), p.id.span),
+            RMemberProp::Computed(c) => match &*c.expr {
+                RExpr::Lit(RLit::Str(s)) => (PropKey::Normal(s.value.clone()), s.span),
+                _ => return None,
+            },
+        };
+
+        if self.props.contains(&key) {
+            Some((key, span))
+        } else {
+            None
+        }
+    }
+
+    fn assign(&mut self, key: PropKey) {
+        if let Some(state) = &mut self.state {
+            state.insert(key);
+        }
+    }
+
+    /// Runs `op` and returns the state after `op`, while restoring the state
+    /// before `op`.
+    fn fork(&mut self, op: impl FnOnce(&mut Self)) -> Option<FxHashSet<PropKey>> {
+        let base = self.state.clone();
+        op(self);
+        std::mem::replace(&mut self.state, base)
+    }
+
+    /// Runs `op` and returns the state after `break` statements targeting
+    /// the statement, which has `label` if it's a labeled statement.
+    fn with_break_target(&mut self, label: Option<JsWord>, op: impl FnOnce(&mut Self)) -> Option<FxHashSet<PropKey>> {
+        self.breaks.push((label, vec![]));
+        op(self);
+        let (_, breaks) = self.breaks.pop().unwrap_or_default();
+
+        breaks.into_iter().fold(None, join)
+    }
+
+    fn visit_loop(&mut self, test: Option<&RExpr>, body: &dyn Fn(&mut Self)) {
+        test.visit_with(self);
+
+        // The body may not be executed at all, except for `while (true)`.
+        let is_infinite = match test {
+            Some(RExpr::Lit(RLit::Bool(b))) => b.value,
+            Some(..) => false,
+            None => true,
+        };
+
+        let base = self.state.clone();
+        let breaks = self.with_break_target(None, |v| {
+            body(v);
+        });
+
+        self.state = if is_infinite { breaks } else { join(base, breaks) };
+    }
+}
+
+impl Visit<RMemberExpr> for InitAnalyzer {
+    fn visit(&mut self, e: &RMemberExpr) {
+        match self.prop_of(e) {
+            Some((key, span)) => {
+                if let Some(state) = &self.state {
+                    if !state.contains(&key) {
+                        self.used_before_assigned.push(span);
+                    }
+                }
+            }
+            None => e.visit_children_with(self),
+        }
+    }
+}
+
+impl Visit<RPat> for InitAnalyzer {
+    fn visit(&mut self, p: &RPat) {
+        if let RPat::Expr(box RExpr::Member(e)) = p {
+            if let Some((key, _)) = self.prop_of(e) {
+                self.assign(key);
+                return;
+            }
+        }
+
+        p.visit_children_with(self);
+    }
+}
+
+impl Visit<RAssignExpr> for InitAnalyzer {
+    fn visit(&mut self, e: &RAssignExpr) {
+        match e.op {
+            op!("=") => {
+                e.right.visit_with(self);
+
+                match &e.left {
+                    RPatOrExpr::Expr(box RExpr::Member(m)) | RPatOrExpr::Pat(box RPat::Expr(box RExpr::Member(m))) => {
+                        match self.prop_of(m) {
+                            Some((key, _)) => self.assign(key),
+                            None => m.visit_with(self),
+                        }
+                    }
+                    _ => e.left.visit_with(self),
+                }
+            }
+
+            op!("&&=") | op!("||=") | op!("??=") => {
+                e.left.visit_with(self);
+                self.fork(|v| e.right.visit_with(v));
+            }
+
+            _ => {
+                e.left.visit_with(self);
+                e.right.visit_with(self);
+            }
+        }
+    }
+}
+
+impl Visit<RBinExpr> for InitAnalyzer {
+    fn visit(&mut self, e: &RBinExpr) {
+        e.left.visit_with(self);
+
+        match e.op {
+            op!("&&") | op!("||") | op!("??") => {
+                self.fork(|v| e.right.visit_with(v));
+            }
+            _ => e.right.visit_with(self),
+        }
+    }
+}
+
+impl Visit<RCondExpr> for InitAnalyzer {
+    fn visit(&mut self, e: &RCondExpr) {
+        e.test.visit_with(self);
+
+        let cons = self.fork(|v| e.cons.visit_with(v));
+        e.alt.visit_with(self);
+
+        self.state = join(cons, self.state.take());
+    }
+}
+
+impl Visit<RIfStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RIfStmt) {
+        s.test.visit_with(self);
+
+        let cons = self.fork(|v| s.cons.visit_with(v));
+        s.alt.visit_with(self);
+
+        self.state = join(cons, self.state.take());
+    }
+}
+
+impl Visit<RSwitchStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RSwitchStmt) {
+        s.discriminant.visit_with(self);
+
+        let base = self.state.clone();
+        let has_default = s.cases.iter().any(|case| case.test.is_none());
+
+        let breaks = self.with_break_target(None, |v| {
+            // State of the fallthrough from the previous case.
+            v.state = None;
+
+            for case in &s.cases {
+                v.state = join(v.state.take(), base.clone());
+
+                case.test.visit_with(v);
+                case.cons.visit_with(v);
+            }
+        });
+
+        let end = join(self.state.take(), breaks);
+        self.state = if has_default { end } else { join(end, base) };
+    }
+}
+
+impl Visit<RTryStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RTryStmt) {
+        // Errors can be thrown anywhere in the `try` block, so the `catch` block
+        // starts with the state before the `try` block.
+        let base = self.state.clone();
+
+        s.block.visit_with(self);
+
+        if let Some(handler) = &s.handler {
+            let block = self.state.take();
+            self.state = base;
+            handler.visit_with(self);
+
+            self.state = join(block, self.state.take());
+        }
+
+        s.finalizer.visit_with(self);
+    }
+}
+
+impl Visit<RWhileStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RWhileStmt) {
+        self.visit_loop(Some(&s.test), &|v| s.body.visit_with(v));
+    }
+}
+
+impl Visit<RDoWhileStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RDoWhileStmt) {
+        // The body is executed at least once.
+        let breaks = self.with_break_target(None, |v| {
+            s.body.visit_with(v);
+            s.test.visit_with(v);
+        });
+
+        self.state = join(self.state.take(), breaks);
+    }
+}
+
+impl Visit<RForStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RForStmt) {
+        s.init.visit_with(self);
+
+        self.visit_loop(s.test.as_deref(), &|v| {
+            s.body.visit_with(v);
+            s.update.visit_with(v);
+        });
+    }
+}
+
+impl Visit<RForInStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RForInStmt) {
+        s.right.visit_with(self);
+
+        let base = self.state.clone();
+        let breaks = self.with_break_target(None, |v| {
+            s.left.visit_with(v);
+            s.body.visit_with(v);
+        });
+
+        self.state = join(base, breaks);
+    }
+}
+
+impl Visit<RForOfStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RForOfStmt) {
+        s.right.visit_with(self);
+
+        let base = self.state.clone();
+        let breaks = self.with_break_target(None, |v| {
+            s.left.visit_with(v);
+            s.body.visit_with(v);
+        });
+
+        self.state = join(base, breaks);
+    }
+}
+
+impl Visit<RLabeledStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RLabeledStmt) {
+        let breaks = self.with_break_target(Some(s.label.sym.clone()), |v| s.body.visit_with(v));
+
+        self.state = join(self.state.take(), breaks);
+    }
+}
+
+impl Visit<RBreakStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RBreakStmt) {
+        let state = self.state.take();
+
+        // `break` without a label targets the innermost loop or `switch` statement.
+        let label = s.label.as_ref().map(|label| &label.sym);
+        if let Some((_, breaks)) = self.breaks.iter_mut().rev().find(|(l, _)| l.as_ref() == label) {
+            breaks.push(state);
+        }
+    }
+}
+
+impl Visit<RContinueStmt> for InitAnalyzer {
+    fn visit(&mut self, _: &RContinueStmt) {
+        self.state = None;
+    }
+}
+
+impl Visit<RReturnStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RReturnStmt) {
+        s.arg.visit_with(self);
+
+        if let Some(state) = self.state.take() {
+            self.returns.push(state);
+        }
+    }
+}
+
+impl Visit<RThrowStmt> for InitAnalyzer {
+    fn visit(&mut self, s: &RThrowStmt) {
+        s.arg.visit_with(self);
+
+        self.state = None;
+    }
+}
+
+/// Nested functions are not analyzed because we don't know when they are
+/// called.
+impl Visit<RFunction> for InitAnalyzer {
+    fn visit(&mut self, _: &RFunction) {}
+}
+
+impl Visit<RArrowExpr> for InitAnalyzer {
+    fn visit(&mut self, _: &RArrowExpr) {}
+}
+
+impl Visit<RGetterProp> for InitAnalyzer {
+    fn visit(&mut self, _: &RGetterProp) {}
+}
+
+impl Visit<RSetterProp> for InitAnalyzer {
+    fn visit(&mut self, _: &RSetterProp) {}
+}
+
+impl Visit<RClass> for InitAnalyzer {
+    fn visit(&mut self, _: &RClass) {}
+}
@@ -26,7 +26,7 @@ use swc_common::{Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::*;
 use tracing::info;
 
-use super::{generic::ExtendsOpts, types::NormalizeTypeOpts};
+use super::{generic::ExtendsOpts, relation::Relation, types::NormalizeTypeOpts};
 use crate::{
     analyzer::{
         assign::AssignOpts,
@@ -50,6 +50,9 @@ pub(crate) struct CondFacts {
     pub vars: FxHashMap<Name, Type>,
     pub excludes: FxHashMap<Name, Vec<Type>>,
     pub types: FxHashMap<Id, Type>,
+    /// Facts of element accesses using a variable as the key, like
+    /// `obj[key]`. Used by `noUncheckedIndexedAccess`.
+    pub elem_facts: FxHashMap<(Name, Id), TypeFacts>,
 }
 
 impl CondFacts {
@@ -121,6 +124,7 @@ impl CondFacts {
             vars: take(&mut self.vars),
             excludes: take(&mut self.excludes),
             types: take(&mut self.types),
+            elem_facts: take(&mut self.elem_facts),
         }
     }
 
@@ -288,6 +292,10 @@ impl AddAssign for CondFacts {
             *self.facts.entry(k.clone()).or_insert(TypeFacts::None) |= v;
         }
 
+        for (k, v) in rhs.elem_facts {
+            *self.elem_facts.entry(k).or_insert(TypeFacts::None) |= v;
+        }
+
         self.types.extend(rhs.types);
 
         for (k, v) in rhs.vars {
@@ -336,6 +344,7 @@ impl BitOr for CondFacts {
             vars: CondFacts::or(self.vars, rhs.vars),
             types: CondFacts::or(self.types, rhs.types),
             excludes: CondFacts::or(self.excludes, rhs.excludes),
+            elem_facts: CondFacts::or(self.elem_facts, rhs.elem_facts),
         }
     }
 }
@@ -421,6 +430,12 @@ impl Analyzer<'_, '_> {
                 }
             }
 
+            for (k, v) in facts_from_cons.elem_facts {
+                if let Some(&v2) = facts_from_alt.elem_facts.get(&k) {
+                    *self.cur_facts.true_facts.elem_facts.entry(k).or_insert(TypeFacts::None) |= v & v2;
+                }
+            }
+
             for (k, types1) in facts_from_cons.excludes {
                 if let Some(types2) = facts_from_alt.excludes.get(&k) {
                     let types = types1
@@ -523,8 +538,11 @@ impl Analyzer<'_, '_> {
                         break;
                     }
 
-                    // TODO:
-                    // if self.is_type_related_to(b, ty, Relation::StrictSubtype) {
+                    if self.is_type_related_to(b, ty, Relation::StrictSubtype) {
+                        // Remove ty.
+                        continue 'outer;
+                    }
+
                     match self.extends(
                         span,
                         b,
@@ -540,7 +558,6 @@ impl Analyzer<'_, '_> {
                         }
                         res => {}
                     }
-                    // }
                 }
             }
 
@@ -738,7 +755,13 @@ impl Analyzer<'_, '_> {
         let res: VResult<Type> = try {
             match *lhs {
                 RPatOrExpr::Expr(ref expr) | RPatOrExpr::Pat(box RPat::Expr(ref expr)) => {
-                    let lhs_ty = expr.validate_with_args(self, (TypeOfMode::LValue, None, None));
+                    let (lhs_ty, lhs_obj_ty) = match &**expr {
+                        RExpr::Member(member) => match self.type_and_obj_of_member_expr(member, TypeOfMode::LValue, true) {
+                            Ok((ty, obj_ty)) => (Ok(ty), obj_ty),
+                            Err(err) => (Err(err), None),
+                        },
+                        _ => (expr.validate_with_args(self, (TypeOfMode::LValue, None, None)), None),
+                    };
                     let mut lhs_ty = match lhs_ty {
                         Ok(v) => v,
                         _ => Type::any(lhs.span(), Default::default()),
@@ -746,7 +769,7 @@ impl Analyzer<'_, '_> {
                     lhs_ty.freeze();
 
                     if op == op!("=") {
-                        self.assign_with_opts(
+                        let mut res = self.assign_with_opts(
                             &mut Default::default(),
                             &lhs_ty,
                             rhs_ty,
@@ -755,7 +778,26 @@ impl Analyzer<'_, '_> {
                                 left_ident_span: Some(lhs.span()),
                                 ..Default::default()
                             },
-                        )?
+                        );
+
+                        // `obj.prop = undefined` where `prop` is optional.
+                        if let (RExpr::Member(member), Some(obj)) = (&**expr, &lhs_obj_ty) {
+                            if res.is_err()
+                                && self.rule().exact_optional_property_types
+                                && !lhs_ty.contains_undefined()
+                                && rhs_ty.contains_undefined()
+                                && matches!(self.is_optional_property(member.span, obj, &member.prop), Ok(Some(true)))
+                            {
+                                res = Err(ErrorKind::ExactOptionalPropWriteFailed {
+                                    span,
+                                    left: box lhs_ty.clone(),
+                                    right: box rhs_ty.clone(),
+                                }
+                                .into());
+                            }
+                        }
+
+                        res?
                     } else {
                         self.assign_with_operator(span, op, &lhs_ty, rhs_ty)?;
                     }
@@ -1036,7 +1078,7 @@ impl Analyzer<'_, '_> {
                         }
 
                         let elem_ty = self
-                            .get_element_from_iterator(span, Cow::Borrowed(&ty), i)
+                            .get_element_for_array_pat(span, Cow::Borrowed(&ty), i)
                             .context("tried to get an element of type to assign with an array pattern")
                             .report(&mut self.storage)
                             .freezed();
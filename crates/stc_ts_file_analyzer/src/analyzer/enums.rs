@@ -17,6 +17,7 @@ use swc_ecma_ast::*;
 use crate::{
     analyzer::{scope::VarKind, util::ResultExt, Analyzer},
     ty::{Enum, EnumMember, Type},
+    util::js_number_to_string,
     validator::ValidateWith,
     VResult,
 };
@@ -97,7 +98,14 @@ impl Analyzer<'_, '_> {
                         .or_else(|err| match &m.init {
                             None => Err(err),
                             Some(v) => {
-                                if e.is_const {
+                                if let ErrorKind::BigIntInEnum { .. } = &*err {
+                                    // `bigint` is not a valid enum value, even for non-const enums.
+                                    if e.is_const {
+                                        self.storage.report(ErrorKind::InvalidInitInConstEnum { span: v.span() }.into());
+                                    } else {
+                                        self.storage.report(err);
+                                    }
+                                } else if e.is_const {
                                     self.storage.report(err);
                                 }
                                 Ok(box Type::any(m.span, Default::default()))
@@ -205,6 +213,7 @@ impl Evaluator<'_> {
             match expr {
                 RExpr::Lit(RLit::Str(s)) => return Ok(RTsLit::Str(s.clone())),
                 RExpr::Lit(RLit::Num(s)) => return Ok(RTsLit::Number(s.clone())),
+                RExpr::Lit(RLit::BigInt(v)) => return Err(ErrorKind::BigIntInEnum { span: v.span }.into()),
                 RExpr::Bin(ref bin) => {
                     let v = self.compute_bin(analyzer, span, bin)?;
 
@@ -273,7 +282,7 @@ impl Evaluator<'_> {
                         RTsLit::Str(_) => {}
                         RTsLit::Bool(_) => {}
                         RTsLit::Tpl(_) => {}
-                        RTsLit::BigInt(_) => unimplemented!("BigInt in enum"),
+                        RTsLit::BigInt(_) => return Err(ErrorKind::BigIntInEnum { span: expr.span }.into()),
                     }
                 }
 
@@ -283,10 +292,40 @@ impl Evaluator<'_> {
                     }
                 }
 
+                RExpr::Tpl(ref t) => {
+                    let mut value = String::new();
+
+                    for (i, quasi) in t.quasis.iter().enumerate() {
+                        match &quasi.cooked {
+                            Some(cooked) => value.push_str(cooked),
+                            None => return Err(ErrorKind::InvalidEnumInit { span }.into()),
+                        }
+
+                        if let Some(expr) = t.exprs.get(i) {
+                            match self.compute(analyzer, span, None, Some(expr))? {
+                                RTsLit::Str(s) => value.push_str(&s.value),
+                                RTsLit::Number(n) => value.push_str(&js_number_to_string(n.value)),
+                                RTsLit::BigInt(..) => return Err(ErrorKind::BigIntInEnum { span: expr.span() }.into()),
+                                _ => return Err(ErrorKind::InvalidEnumInit { span }.into()),
+                            }
+                        }
+                    }
+
+                    return Ok(RTsLit::Str(RStr {
+                        span: t.span,
+                        value: value.into(),
+                        raw: None,
+                    }));
+                }
+
                 _ => {
                     let res = expr.validate_with_default(analyzer)?;
                     let res = analyzer.expand_enum_variant(res)?;
 
+                    if res.is_bigint_like() {
+                        return Err(ErrorKind::BigIntInEnum { span: expr.span() }.into());
+                    }
+
                     if let Type::Lit(ty) = res.normalize() {
                         return Ok(ty.lit.clone());
                     }
@@ -318,6 +357,8 @@ impl Evaluator<'_> {
                         op!(bin, "-") => l - r,
                         op!("*") => l * r,
                         op!("/") => l / r,
+                        op!("%") => l % r,
+                        op!("**") => l.powf(r),
 
                         // TODO
                         op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
@@ -341,14 +382,15 @@ impl Evaluator<'_> {
             }),
             (RTsLit::Number(l), RTsLit::Str(r)) if expr.op == op!(bin, "+") => RTsLit::Str(RStr {
                 span,
-                value: format!("{}{}", l.value, r.value).into(),
+                value: format!("{}{}", js_number_to_string(l.value), r.value).into(),
                 raw: None,
             }),
             (RTsLit::Str(l), RTsLit::Number(r)) if expr.op == op!(bin, "+") => RTsLit::Str(RStr {
                 span,
-                value: format!("{}{}", l.value, r.value).into(),
+                value: format!("{}{}", l.value, js_number_to_string(r.value)).into(),
                 raw: None,
             }),
+            (RTsLit::BigInt(..), _) | (_, RTsLit::BigInt(..)) => Err(ErrorKind::BigIntInEnum { span: expr.span })?,
             _ => Err(ErrorKind::InvalidEnumInit { span })?,
         })
     }
@@ -1,8 +1,8 @@
 use rnode::{NodeId, VisitWith};
 use stc_ts_ast_rnode::{
-    RBindingIdent, RDecl, RDefaultDecl, RExportAll, RExportDecl, RExportDefaultDecl, RExportDefaultExpr, RExportNamedSpecifier,
-    RExportSpecifier, RExpr, RIdent, RModuleExportName, RNamedExport, RPat, RStmt, RTsExportAssignment, RTsModuleName, RTsTypeAnn,
-    RVarDecl, RVarDeclarator,
+    RAssignExpr, RBindingIdent, RComputedPropName, RDecl, RDefaultDecl, RExportAll, RExportDecl, RExportDefaultDecl, RExportDefaultExpr,
+    RExportNamedSpecifier, RExportSpecifier, RExpr, RIdent, RLit, RMemberExpr, RMemberProp, RModuleExportName, RNamedExport, RPat,
+    RPatOrExpr, RStmt, RTsExportAssignment, RTsModuleName, RTsTypeAnn, RVarDecl, RVarDeclarator,
 };
 use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_file_analyzer_macros::extra_validator;
@@ -10,7 +10,7 @@ use stc_ts_types::{Id, IdCtx, Key};
 use stc_ts_utils::find_ids_in_pat;
 use stc_utils::{cache::Freeze, dev_span};
 use swc_atoms::{js_word, JsWord};
-use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_common::{FileName, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 
 use crate::{
@@ -302,6 +302,9 @@ impl Analyzer<'_, '_> {
         self.with_ctx(ctx)
             .export_expr(node.span, js_word!("default"), node.node_id, &node.expr)?;
 
+        let needs_default_import = self.needs_default_import(&node.expr);
+        self.storage.mark_export_equals(self.ctx.module_id, needs_default_import);
+
         Ok(())
     }
 }
@@ -512,3 +515,101 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 }
+
+impl Analyzer<'_, '_> {
+    /// Returns `true` if `e`, the target of `export =`, is a class, a function
+    /// or an enum which is not merged with a namespace.
+    ///
+    /// Only variables and namespaces can be imported using `import * as`.
+    fn needs_default_import(&mut self, e: &RExpr) -> bool {
+        let id = match e {
+            RExpr::Ident(i) => Id::from(i),
+            _ => return false,
+        };
+
+        let is_namespace = match self.find_type(&id) {
+            Ok(Some(types)) => types
+                .into_iter()
+                .any(|ty| matches!(ty.normalize(), Type::Namespace(..) | Type::Module(..))),
+            _ => false,
+        };
+        if is_namespace {
+            return false;
+        }
+
+        matches!(
+            self.find_var(&id).map(|var| var.kind),
+            Some(VarKind::Class | VarKind::Fn | VarKind::Enum)
+        )
+    }
+
+    /// Returns `true` if the module being validated is a JavaScript file.
+    pub(crate) fn is_js_file(&self) -> bool {
+        if self.config.is_builtin {
+            return false;
+        }
+
+        match &*self.storage.path(self.ctx.module_id) {
+            FileName::Real(path) => matches!(path.extension().and_then(|ext| ext.to_str()), Some("js" | "jsx" | "cjs" | "mjs")),
+            _ => false,
+        }
+    }
+
+    /// Returns the name of the export if `e` is `module.exports = foo`,
+    /// `exports.foo = bar` or `module.exports.foo = bar` in a JavaScript file.
+    ///
+    /// `module.exports` is stored as `default`, like `export =`.
+    pub(crate) fn commonjs_export_target(&self, e: &RAssignExpr) -> Option<JsWord> {
+        if e.op != op!("=") || !self.is_js_file() {
+            return None;
+        }
+
+        match &e.left {
+            RPatOrExpr::Expr(box RExpr::Member(m)) | RPatOrExpr::Pat(box RPat::Expr(box RExpr::Member(m))) => {
+                if self.is_module_exports(m) {
+                    return Some(js_word!("default"));
+                }
+
+                let prop = match &m.prop {
+                    RMemberProp::Ident(i) => i.sym.clone(),
+                    RMemberProp::Computed(RComputedPropName {
+                        expr: box RExpr::Lit(RLit::Str(s)),
+                        ..
+                    }) => s.value.clone(),
+                    _ => return None,
+                };
+
+                match &*m.obj {
+                    RExpr::Ident(obj) if &*obj.sym == "exports" && self.find_var(&obj.into()).is_none() => Some(prop),
+                    RExpr::Member(obj) if self.is_module_exports(obj) => Some(prop),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Stores `ty` as an export of the current module, assigned using
+    /// [Analyzer::commonjs_export_target].
+    pub(crate) fn export_commonjs(&mut self, span: Span, name: JsWord, ty: Type) {
+        // `module.exports =` works like `export =`.
+        if name == js_word!("default") {
+            self.storage.mark_export_equals(self.ctx.module_id, false);
+        }
+        self.storage.export_var(span, self.ctx.module_id, name, ty);
+    }
+
+    /// Returns `true` if `m` is `module.exports`.
+    fn is_module_exports(&self, m: &RMemberExpr) -> bool {
+        let is_exports = match &m.prop {
+            RMemberProp::Ident(i) => &*i.sym == "exports",
+            RMemberProp::Computed(RComputedPropName {
+                expr: box RExpr::Lit(RLit::Str(s)),
+                ..
+            }) => &*s.value == "exports",
+            _ => false,
+        };
+
+        is_exports && matches!(&*m.obj, RExpr::Ident(obj) if &*obj.sym == "module" && self.find_var(&obj.into()).is_none())
+    }
+}
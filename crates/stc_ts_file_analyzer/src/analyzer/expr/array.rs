@@ -245,6 +245,30 @@ impl Analyzer<'_, '_> {
 impl Analyzer<'_, '_> {
     /// Get `n`th element from the `iterator`.
     pub(crate) fn get_element_from_iterator<'a>(&mut self, span: Span, iterator: Cow<'a, Type>, n: usize) -> VResult<Cow<'a, Type>> {
+        self.get_element_from_iterator_with_opts(span, iterator, n, Default::default())
+    }
+
+    /// Get `n`th element from the `iterator` to destructure it using an array
+    /// pattern.
+    ///
+    /// With `noUncheckedIndexedAccess`, elements of arrays may be `undefined`.
+    pub(crate) fn get_element_for_array_pat<'a>(&mut self, span: Span, iterator: Cow<'a, Type>, n: usize) -> VResult<Cow<'a, Type>> {
+        let opts = AccessPropertyOpts {
+            include_undefined_for_index_signature: self.rule().no_unchecked_indexed_access,
+            ..Default::default()
+        };
+
+        self.get_element_from_iterator_with_opts(span, iterator, n, opts)
+    }
+
+    /// `opts` is used to access elements of arrays and tuples.
+    fn get_element_from_iterator_with_opts<'a>(
+        &mut self,
+        span: Span,
+        iterator: Cow<'a, Type>,
+        n: usize,
+        opts: AccessPropertyOpts,
+    ) -> VResult<Cow<'a, Type>> {
         debug!("Calculating element type of an iterator ({})", dump_type_as_string(&iterator));
 
         if iterator.is_any() {
@@ -266,7 +290,7 @@ impl Analyzer<'_, '_> {
                     .context("tried to expand iterator to get nth element")?;
 
                 return self
-                    .get_element_from_iterator(span, iterator, n)
+                    .get_element_from_iterator_with_opts(span, iterator, n, opts)
                     .context("tried to get element from an expanded iterator");
             }
 
@@ -277,7 +301,7 @@ impl Analyzer<'_, '_> {
                 let mut errors = vec![];
                 for (idx, iterator_elem) in u.types.iter().enumerate() {
                     let res = self
-                        .get_element_from_iterator(span, Cow::Borrowed(iterator_elem), n)
+                        .get_element_from_iterator_with_opts(span, Cow::Borrowed(iterator_elem), n, opts)
                         .with_context(|| format!("failed to get element type from {}th element", idx))
                         .convert_err(|err| match err {
                             ErrorKind::TupleIndexError { span, .. } => ErrorKind::TupleTooShort { span },
@@ -333,7 +357,7 @@ impl Analyzer<'_, '_> {
                         }),
                         TypeOfMode::RValue,
                         IdCtx::Var,
-                        Default::default(),
+                        opts,
                     )
                     .map(Cow::Owned)
                     .context("tried to access property of a type to calculate element type");
@@ -28,6 +28,7 @@ use crate::{
         assign::AssignOpts,
         expr::{type_cast::CastableOpts, TypeOfMode},
         generic::ExtendsOpts,
+        relation::Relation,
         scope::ExpandOpts,
         types::NormalizeTypeOpts,
         util::{Comparator, ResultExt},
@@ -134,7 +135,8 @@ impl Analyzer<'_, '_> {
 
         let rhs = self
             .with_child(ScopeKind::Flow, true_facts_for_rhs.clone(), |child: &mut Analyzer| -> VResult<_> {
-                child.ctx.should_store_truthy_for_access = false;
+                // `a && b.c` is truthy only if `b.c` is truthy.
+                child.ctx.should_store_truthy_for_access = ctx.should_store_truthy_for_access;
 
                 let any_type_param = TypeParamInstantiation {
                     span,
@@ -216,6 +218,12 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
+                for (k, type_fact) in lhs_facts.true_facts.elem_facts.drain() {
+                    if let Entry::Occupied(mut e) = self.cur_facts.true_facts.elem_facts.entry(k) {
+                        *e.get_mut() &= type_fact;
+                    }
+                }
+
                 self.cur_facts += lhs_facts;
             } else if op == op!("&&") {
                 self.cur_facts.true_facts += true_facts_for_rhs;
@@ -223,6 +231,10 @@ impl Analyzer<'_, '_> {
                 for (k, v) in additional_false_facts.facts.drain() {
                     *self.cur_facts.false_facts.facts.entry(k.clone()).or_default() &= v;
                 }
+
+                for (k, v) in additional_false_facts.elem_facts.drain() {
+                    *self.cur_facts.false_facts.elem_facts.entry(k).or_default() &= v;
+                }
             }
 
             self.cur_facts.false_facts += additional_false_facts;
@@ -866,7 +878,7 @@ impl Analyzer<'_, '_> {
 
             op!("in") => {
                 if self.ctx.in_cond {
-                    let left = match &**left {
+                    let prop = match &**left {
                         RExpr::Lit(RLit::Str(s)) => Some(s.value.clone()),
                         RExpr::Tpl(t) if t.quasis.len() == 1 => t.quasis[0].cooked.clone().map(|v| (&*v).into()),
                         _ => match lt.normalize() {
@@ -877,13 +889,27 @@ impl Analyzer<'_, '_> {
                     let name = Name::try_from(&**right).ok();
 
                     if let Some(name) = name {
-                        if let Some(property) = left {
+                        if let Some(property) = prop {
                             let new_ty = self.narrow_types_with_property(span, &rt, &property, None)?.fixed().freezed();
 
                             self.add_deep_type_fact(span, name.clone(), new_ty.clone(), true);
+
+                            // With `noUncheckedIndexedAccess`, `obj[property]` is not `undefined` if
+                            // `property in obj` is true.
+                            if self.rule().no_unchecked_indexed_access {
+                                let mut prop_name = name.clone();
+                                prop_name.push(property.clone());
+                                *self.cur_facts.true_facts.facts.entry(prop_name).or_default() |= TypeFacts::NEUndefined;
+                            }
+
                             if rt.is_union_type() {
                                 self.cur_facts.false_facts.excludes.entry(name).or_default().push(new_ty);
                             }
+                        } else if let RExpr::Ident(key) = &**left {
+                            // Same for `obj[key]` if `key in obj` is true.
+                            if self.rule().no_unchecked_indexed_access {
+                                *self.cur_facts.true_facts.elem_facts.entry((name, key.into())).or_default() |= TypeFacts::NEUndefined;
+                            }
                         }
                     }
                 }
@@ -1225,6 +1251,12 @@ impl Analyzer<'_, '_> {
             return Ok(false);
         }
 
+        if self.is_type_related_to(case_ty, disc_ty, Relation::Comparable)
+            || self.is_type_related_to(disc_ty, case_ty, Relation::Comparable)
+        {
+            return Ok(true);
+        }
+
         if self.ctx.in_switch_case_test {
             if disc_ty.is_intersection() {
                 return Ok(true);
@@ -1335,7 +1367,9 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        if orig_ty.is_any() {
+        // `unknown` is narrowed like `any`, e.g. for catch clause variables with
+        // `useUnknownInCatchVariables`.
+        if orig_ty.is_any() || orig_ty.is_unknown() {
             if ty.is_interface() || ty.is_type_lit() {
                 if let Ok(result) = self.access_property(
                     span,
@@ -1356,6 +1390,14 @@ impl Analyzer<'_, '_> {
                     return Ok(result);
                 }
             }
+            if let Type::ClassDef(def) = ty.normalize() {
+                return Ok(Type::Class(Class {
+                    span,
+                    def: def.clone(),
+                    metadata: Default::default(),
+                    tracker: Default::default(),
+                }));
+            }
             return Ok(ty.into_owned());
         }
 
@@ -322,36 +322,25 @@ impl Analyzer<'_, '_> {
         }
 
         match *callee {
-            RExpr::Ident(ref i) if i.sym == js_word!("require") => {
-                let id = args
-                    .iter()
-                    .cloned()
-                    .map(|arg| match arg {
-                        RExprOrSpread { spread: None, expr } => match *expr {
-                            RExpr::Lit(RLit::Str(RStr { span, value, .. })) => RIdent::new(value, span).into(),
-                            _ => unimplemented!("dynamic import: require()"),
-                        },
-                        _ => unimplemented!("error reporting: spread element in require()"),
-                    })
-                    .next()
-                    .unwrap();
-                if let Some(dep) = self.find_imported_var(&id)? {
-                    let dep = dep;
-                    unimplemented!("dep: {:#?}", dep);
-                }
-
-                // if let Some(Type::Enum(ref e)) = self.scope.find_type(&i.into()) {
-                //     return Ok(RTsType::TsTypeRef(RTsTypeRef {
-                //         span,
-                //         type_name: RTsEntityName::Ident(i.clone()),
-                //         type_params: None,
-                //     })
-                //     .into());
-                // }
-                Err(ErrorKind::UndefinedSymbol {
-                    sym: i.into(),
-                    span: i.span(),
-                })?
+            RExpr::Ident(ref i) if kind == ExtractKind::Call && i.sym == js_word!("require") && self.find_var(&i.into()).is_none() => {
+                // `require` is not declared by user, so it's a CommonJS import.
+                let src = match args.first() {
+                    Some(RExprOrSpread { spread: None, expr }) => match &**expr {
+                        RExpr::Lit(RLit::Str(RStr { value, .. })) => Some(value.clone()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                for arg in args.iter().skip(if src.is_some() { 1 } else { 0 }) {
+                    arg.expr.validate_with_default(self).report(&mut self.storage);
+                }
+
+                return match src {
+                    Some(src) => self.load_required_module(span, &src),
+                    // Dynamic `require()` is typed as `any`, like `tsc` does.
+                    None => Ok(Type::any(span, Default::default())),
+                };
             }
 
             _ => {}
@@ -633,6 +622,25 @@ impl Analyzer<'_, '_> {
                     return Ok(Type::any(span, Default::default()));
                 }
 
+                Type::Function(..) | Type::Constructor(..)
+                    if self.rule().strict_bind_call_apply
+                        && matches!(prop, Key::Normal { sym, .. } if matches!(&**sym, "bind" | "call" | "apply")) =>
+                {
+                    return self.call_bind_call_apply(
+                        span,
+                        kind,
+                        expr,
+                        &obj_type,
+                        prop,
+                        type_args,
+                        args,
+                        arg_types,
+                        spread_arg_types,
+                        type_ann,
+                        opts,
+                    );
+                }
+
                 Type::Array(obj) => {
                     if let Key::Computed(key) = prop {
                         if let Type::Symbol(key_ty) = key.ty.normalize() {
@@ -1020,6 +1028,110 @@ impl Analyzer<'_, '_> {
         Ok(candidates)
     }
 
+    /// Calls `bind`, `call` or `apply` of a function or a constructor while
+    /// `strictBindCallApply` is enabled.
+    ///
+    /// Methods of `CallableFunction` and `NewableFunction` are used instead of
+    /// the ones of `Function`, and type parameters used by their `this`
+    /// parameters are inferred from `callee`.
+    fn call_bind_call_apply(
+        &mut self,
+        span: Span,
+        kind: ExtractKind,
+        expr: ReEvalMode,
+        callee: &Type,
+        prop: &Key,
+        type_args: Option<&TypeParamInstantiation>,
+        args: &[RExprOrSpread],
+        arg_types: &[TypeOrSpread],
+        spread_arg_types: &[TypeOrSpread],
+        type_ann: Option<&Type>,
+        opts: CallOpts,
+    ) -> VResult<Type> {
+        let name = match callee.normalize() {
+            Type::Function(..) => "CallableFunction",
+            _ => "NewableFunction",
+        };
+        let interface = self.env.get_global_type(span, &name.into())?;
+        let members = match interface.normalize() {
+            Type::Interface(i) => &*i.body,
+            _ => &[],
+        };
+
+        let mut candidates = vec![];
+        for m in members {
+            self.check_type_element_for_call(span, kind, &mut candidates, m, prop, opts);
+        }
+
+        let candidates = candidates
+            .into_iter()
+            .map(|c| self.instantiate_this_param_of_candidate(span, c, callee))
+            .collect::<VResult<Vec<_>>>()?;
+
+        self.select_and_invoke(
+            span,
+            kind,
+            expr,
+            &candidates,
+            type_args,
+            args,
+            arg_types,
+            spread_arg_types,
+            type_ann,
+            Default::default(),
+        )?
+        .ok_or_else(|| {
+            ErrorKind::NoCallablePropertyWithName {
+                span,
+                obj: box callee.clone(),
+                key: box prop.clone(),
+            }
+            .into()
+        })
+    }
+
+    /// Replaces type parameters used by the `this` parameter of `c` with the
+    /// types inferred from `receiver`, and removes the `this` parameter.
+    fn instantiate_this_param_of_candidate(&mut self, span: Span, c: CallCandidate, receiver: &Type) -> VResult<CallCandidate> {
+        let this_param = match c.params.first() {
+            Some(FnParam {
+                pat:
+                    RPat::Ident(RBindingIdent {
+                        id: RIdent { sym: js_word!("this"), .. },
+                        ..
+                    }),
+                ty,
+                ..
+            }) => ty,
+            _ => return Ok(c),
+        };
+
+        let map = self.infer_type_of_this_param(span, this_param, receiver)?;
+
+        let params = c.params[1..]
+            .iter()
+            .map(|param| -> VResult<_> {
+                let ty = box self.expand_type_params(&map, *param.ty.clone(), Default::default())?;
+
+                Ok(FnParam { ty, ..param.clone() })
+            })
+            .collect::<VResult<_>>()?;
+        let ret_ty = box self.expand_type_params(&map, *c.ret_ty, Default::default())?;
+        let type_params = c
+            .type_params
+            .map(|decl| TypeParamDecl {
+                params: decl.params.into_iter().filter(|param| !map.contains_key(&param.name)).collect(),
+                ..decl
+            })
+            .filter(|decl| !decl.params.is_empty());
+
+        Ok(CallCandidate {
+            type_params,
+            params,
+            ret_ty,
+        })
+    }
+
     fn call_property_of_class(
         &mut self,
         span: Span,
@@ -2301,9 +2413,17 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
-                Some(&v.pat)
+                Some(v)
+            })
+            .map(|param| match (&param.pat, param.ty.normalize_instance()) {
+                // `...args: [a: number, b?: string]`
+                (RPat::Rest(..), Type::Tuple(tuple)) => tuple
+                    .elems
+                    .iter()
+                    .filter(|elem| !matches!(elem.ty.normalize(), Type::Optional(..) | Type::Rest(..)))
+                    .count(),
+                _ => count_required_pat(&param.pat),
             })
-            .map(count_required_pat)
             .sum();
 
         let mut max_param = Some(params.len());
@@ -2480,6 +2600,13 @@ impl Analyzer<'_, '_> {
             && callable
                 .iter()
                 .all(|(_, res)| matches!(res, ArgCheckResult::WrongArgCount | ArgCheckResult::ArgTypeMismatch))
+            // Like `tsc`, errors of the only candidate with a matching arity are reported
+            // directly.
+            && callable
+                .iter()
+                .filter(|(_, res)| matches!(res, ArgCheckResult::ArgTypeMismatch))
+                .count()
+                != 1
         {
             return Err(ErrorKind::NoMatchingOverload { span }.context("tried to select a call candidate"));
         }
@@ -3920,6 +4047,7 @@ fn test_arg_check_result_order() {
 }
 
 /// TODO(kdy1): Use cow
+#[derive(Clone)]
 pub(super) struct CallCandidate {
     pub type_params: Option<TypeParamDecl>,
     pub params: Vec<FnParam>,
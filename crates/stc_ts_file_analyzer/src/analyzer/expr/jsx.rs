@@ -1,25 +1,42 @@
+use std::borrow::Cow;
+
+use fxhash::FxHashMap;
 use stc_ts_ast_rnode::{
     RBool, RJSXAttrName, RJSXAttrOrSpread, RJSXAttrValue, RJSXElement, RJSXElementChild, RJSXElementName, RJSXExpr, RJSXExprContainer,
-    RJSXFragment, RJSXMemberExpr, RJSXNamespacedName, RJSXObject, RJSXSpreadChild, RJSXText, RTsLit,
+    RJSXFragment, RJSXMemberExpr, RJSXNamespacedName, RJSXObject, RJSXSpreadChild, RJSXText, RTsLit, RTsTypeParamInstantiation,
 };
 use stc_ts_env::JsxMode;
 use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_file_analyzer_macros::validator;
 use stc_ts_types::{
-    CommonTypeMetadata, Id, IdCtx, Key, KeywordType, KeywordTypeMetadata, LitType, PropertySignature, Type, TypeElement, TypeLit,
+    Alias, Array, CommonTypeMetadata, FnParam, Id, IdCtx, Interface, Key, KeywordType, KeywordTypeMetadata, LitType, PropertySignature,
+    Type, TypeElement, TypeLit, TypeParam,
 };
 use stc_utils::cache::Freeze;
-use swc_atoms::JsWord;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{Span, Spanned};
 use swc_ecma_ast::TsKeywordTypeKind;
 
-use super::{AccessPropertyOpts, TypeOfMode};
+use super::{
+    call_new::{CallCandidate, ExtractKind},
+    AccessPropertyOpts, TypeOfMode,
+};
 use crate::{
-    analyzer::{assign::AssignOpts, util::ResultExt, Analyzer},
+    analyzer::{assign::AssignOpts, util::ResultExt, Analyzer, ScopeKind},
     validator::ValidateWith,
     VResult,
 };
 
+/// Names of the types in the `JSX` namespace.
+const INTRINSIC_ELEMENTS: &str = "IntrinsicElements";
+const ELEMENT: &str = "Element";
+const ELEMENT_CLASS: &str = "ElementClass";
+const ELEMENT_ATTRIBUTES_PROPERTY: &str = "ElementAttributesProperty";
+const ELEMENT_CHILDREN_ATTRIBUTE: &str = "ElementChildrenAttribute";
+const LIBRARY_MANAGED_ATTRIBUTES: &str = "LibraryManagedAttributes";
+const INTRINSIC_ATTRIBUTES: &str = "IntrinsicAttributes";
+const INTRINSIC_CLASS_ATTRIBUTES: &str = "IntrinsicClassAttributes";
+
 #[derive(Debug)]
 pub enum ResolvedJsxName {
     /// [Type] is the object.
@@ -62,7 +79,7 @@ impl Analyzer<'_, '_> {
     }
 
     fn get_jsx_intrinsic_element_list(&mut self, span: Span) -> VResult<Option<Type>> {
-        let jsx = self.get_jsx_namespace();
+        let jsx = self.get_jsx_namespace(span);
         let jsx = match jsx {
             Some(v) => v,
             None => return Ok(None),
@@ -74,7 +91,7 @@ impl Analyzer<'_, '_> {
                 &jsx,
                 &Key::Normal {
                     span,
-                    sym: "IntrinsicElements".into(),
+                    sym: INTRINSIC_ELEMENTS.into(),
                 },
                 TypeOfMode::RValue,
                 IdCtx::Var,
@@ -88,7 +105,18 @@ impl Analyzer<'_, '_> {
         ))
     }
 
-    fn get_jsx_namespace(&mut self) -> Option<Type> {
+    /// Returns the `JSX` namespace.
+    ///
+    /// In [JsxMode::ReactJsx] and [JsxMode::ReactJsxdev], the namespace
+    /// exported from `${jsxImportSource}/jsx-runtime` is preferred over the
+    /// global one.
+    fn get_jsx_namespace(&mut self, span: Span) -> Option<Type> {
+        if matches!(self.rule().jsx, JsxMode::ReactJsx | JsxMode::ReactJsxdev) {
+            if let Some(jsx) = self.get_jsx_namespace_from_import_source(span) {
+                return Some(jsx);
+            }
+        }
+
         let top_level_ctxt = self.storage.top_level_ctxt(self.ctx.module_id);
 
         let types = self.find_type(&Id::new("JSX".into(), top_level_ctxt)).ok().flatten()?;
@@ -102,7 +130,182 @@ impl Analyzer<'_, '_> {
         None
     }
 
-    fn validate_jsx_attrs(&mut self, jsx_element_span: Span, name: &ResolvedJsxName, attrs: &[RJSXAttrOrSpread]) -> VResult<()> {
+    fn get_jsx_namespace_from_import_source(&mut self, span: Span) -> Option<Type> {
+        if self.config.is_builtin {
+            return None;
+        }
+
+        let runtime = match self.rule().jsx {
+            JsxMode::ReactJsxdev => "jsx-dev-runtime",
+            _ => "jsx-runtime",
+        };
+        // The `@jsxImportSource` pragma of the file overrides the option.
+        let source = match self.data.jsx_import_sources.get(&self.ctx.module_id) {
+            Some(source) => source.clone(),
+            None => self.env.jsx_import_source(),
+        };
+        let src: JsWord = format!("{}/{}", source, runtime).into();
+
+        let ctxt = self.ctx.module_id;
+        let base = self.storage.path(ctxt);
+        let dep_id = self.loader.module_id(&base, &src)?;
+
+        let module = match self.data.imports.get(&(ctxt, dep_id)).cloned() {
+            Some(v) => v,
+            None => self.load_import_lazily(span, &base, dep_id, &src).ok()?,
+        };
+        if module.is_any() {
+            return None;
+        }
+
+        self.access_property(
+            span,
+            &module,
+            &Key::Normal { span, sym: "JSX".into() },
+            TypeOfMode::RValue,
+            IdCtx::Type,
+            AccessPropertyOpts {
+                disallow_creating_indexed_type_from_ty_els: true,
+                ..Default::default()
+            },
+        )
+        .ok()
+        .filter(|ty| ty.is_module())
+    }
+
+    /// Returns `JSX.${name}`.
+    fn get_jsx_type(&mut self, span: Span, name: &str) -> Option<Type> {
+        let jsx = self.get_jsx_namespace(span)?;
+
+        self.access_property(
+            span,
+            &jsx,
+            &Key::Normal { span, sym: name.into() },
+            TypeOfMode::RValue,
+            IdCtx::Type,
+            AccessPropertyOpts {
+                disallow_creating_indexed_type_from_ty_els: true,
+                ..Default::default()
+            },
+        )
+        .ok()
+        .map(Type::freezed)
+    }
+
+    /// Returns the type of a jsx element, which is `JSX.Element`.
+    fn get_jsx_element_type(&mut self, span: Span) -> Type {
+        self.get_jsx_type(span, ELEMENT)
+            .unwrap_or_else(|| Type::any(span, Default::default()))
+    }
+
+    /// Returns the name of the only property of `JSX.${name}`, which is used
+    /// for `JSX.ElementAttributesProperty` and `JSX.ElementChildrenAttribute`.
+    ///
+    /// Returns an empty string if the type has no property.
+    ///
+    /// Ported from `getNameFromJsxElementAttributesContainer` of `tsc`.
+    fn get_jsx_property_name(&mut self, span: Span, name: &str) -> VResult<Option<JsWord>> {
+        let ty = match self.get_jsx_type(span, name) {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let ty_span = ty.span();
+
+        let lit = match self.convert_type_to_type_lit(span, Cow::Owned(ty))? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let keys = lit
+            .members
+            .iter()
+            .filter_map(|m| match m {
+                TypeElement::Property(p) => Some(&p.key),
+                TypeElement::Method(m) => Some(&m.key),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        match &*keys {
+            [] => Ok(Some(js_word!(""))),
+            [Key::Normal { sym, .. }] => Ok(Some(sym.clone())),
+            [_] => Ok(None),
+            _ => Err(ErrorKind::JsxTypeHasMoreThanOneProperty {
+                span: if ty_span.is_dummy() { span } else { ty_span },
+                name: name.into(),
+            }
+            .into()),
+        }
+    }
+
+    /// Instantiates a generic type in the `JSX` namespace, like
+    /// `JSX.LibraryManagedAttributes<C, P>`.
+    fn instantiate_jsx_type(&mut self, span: Span, ty: Type, type_args: Vec<Type>) -> VResult<Type> {
+        let (type_params, body) = match ty.normalize() {
+            Type::Alias(Alias {
+                type_params: Some(type_params),
+                ty: body,
+                ..
+            }) => (type_params.params.clone(), Some(*body.clone())),
+            Type::Interface(Interface {
+                type_params: Some(type_params),
+                ..
+            }) => (type_params.params.clone(), None),
+            _ => return Ok(ty),
+        };
+        let body = match body {
+            Some(body) => body,
+            None => match self.convert_type_to_type_lit(span, Cow::Owned(ty.clone()))? {
+                Some(lit) => Type::TypeLit(lit.into_owned()),
+                None => return Ok(ty),
+            },
+        };
+
+        let map = jsx_type_arg_map(span, &type_params, type_args);
+
+        Ok(self.expand_type_params(&map, body, Default::default())?.freezed())
+    }
+
+    /// Validates the children of a jsx element or a fragment.
+    ///
+    /// Returns the type of the children property, if there are children.
+    fn validate_jsx_children(&mut self, span: Span, children: &[RJSXElementChild]) -> VResult<Option<Type>> {
+        let mut types = vec![];
+        for child in children {
+            if let RJSXElementChild::JSXText(text) = child {
+                // Whitespaces containing a line break are not children.
+                if text.value.trim().is_empty() && text.value.contains('\n') {
+                    continue;
+                }
+            }
+
+            if let Some(ty) = child.validate_with(self)? {
+                types.push(ty);
+            }
+        }
+
+        Ok(match types.len() {
+            0 => None,
+            1 => types.pop(),
+            _ => Some(Type::Array(Array {
+                span,
+                elem_type: box Type::new_union(span, types),
+                metadata: Default::default(),
+                tracker: Default::default(),
+            })),
+        })
+    }
+
+    /// Creates the type of the attributes object.
+    ///
+    /// `props` is used as the contextual type of attributes.
+    fn validate_jsx_attrs(
+        &mut self,
+        jsx_element_span: Span,
+        attrs: &[RJSXAttrOrSpread],
+        children: Option<&Type>,
+        props: Option<&Type>,
+    ) -> VResult<Type> {
         let mut object = Type::TypeLit(TypeLit {
             span: jsx_element_span,
             members: vec![],
@@ -113,11 +316,30 @@ impl Analyzer<'_, '_> {
             match attr {
                 RJSXAttrOrSpread::JSXAttr(attr) => match &attr.name {
                     RJSXAttrName::Ident(attr_name) => {
+                        let key = Key::Normal {
+                            span: attr_name.span,
+                            sym: attr_name.sym.clone(),
+                        };
+                        let type_ann = match props {
+                            Some(props) => self
+                                .access_property(
+                                    attr_name.span,
+                                    props,
+                                    &key,
+                                    TypeOfMode::RValue,
+                                    IdCtx::Var,
+                                    AccessPropertyOpts {
+                                        disallow_creating_indexed_type_from_ty_els: true,
+                                        ..Default::default()
+                                    },
+                                )
+                                .ok()
+                                .map(Type::freezed),
+                            None => None,
+                        };
+
                         let value = match &attr.value {
-                            Some(v) => {
-                                // TODO(kdy1): Pass down type annotation
-                                v.validate_with_args(self, None)?
-                            }
+                            Some(v) => v.validate_with_args(self, type_ann.as_ref())?,
                             None => Some(Type::Lit(LitType {
                                 span: attr_name.span,
                                 lit: RTsLit::Bool(RBool {
@@ -136,10 +358,7 @@ impl Analyzer<'_, '_> {
                                     span: attr.span,
                                     accessibility: None,
                                     readonly: false,
-                                    key: Key::Normal {
-                                        span: attr_name.span,
-                                        sym: attr_name.sym.clone(),
-                                    },
+                                    key,
                                     optional: false,
                                     params: Default::default(),
                                     type_ann: Some(box value),
@@ -165,34 +384,367 @@ impl Analyzer<'_, '_> {
             }
         }
 
+        if let Some(children) = children {
+            if let Some(name) = self.get_jsx_property_name(jsx_element_span, ELEMENT_CHILDREN_ATTRIBUTE)? {
+                if !name.is_empty() {
+                    let explicit = attrs.iter().find_map(|attr| match attr {
+                        RJSXAttrOrSpread::JSXAttr(attr) if matches!(&attr.name, RJSXAttrName::Ident(i) if i.sym == name) => Some(attr),
+                        _ => None,
+                    });
+                    if let Some(explicit) = explicit {
+                        self.storage.report(
+                            ErrorKind::JsxChildrenSpecifiedTwice {
+                                span: explicit.span,
+                                name: name.clone(),
+                            }
+                            .into(),
+                        );
+                    }
+
+                    object = self.append_type_element(
+                        object,
+                        TypeElement::Property(PropertySignature {
+                            span: children.span(),
+                            accessibility: None,
+                            readonly: false,
+                            key: Key::Normal {
+                                span: children.span(),
+                                sym: name,
+                            },
+                            optional: false,
+                            params: Default::default(),
+                            type_ann: Some(box children.clone()),
+                            type_params: None,
+                            metadata: Default::default(),
+                            accessor: Default::default(),
+                        }),
+                    )?;
+                }
+            }
+        }
+
         object.freeze();
 
-        match name {
-            ResolvedJsxName::Intrinsic(name) => {
-                self.assign_with_opts(
-                    &mut Default::default(),
-                    name,
-                    &object,
-                    AssignOpts {
-                        span: jsx_element_span,
-                        allow_missing_fields: true,
-                        ..Default::default()
-                    },
+        Ok(object)
+    }
+
+    /// Validates the attributes of an intrinsic element.
+    fn validate_jsx_intrinsic_element(
+        &mut self,
+        jsx_element_span: Span,
+        element: &Type,
+        attrs: &[RJSXAttrOrSpread],
+        children: Option<&Type>,
+    ) -> VResult<()> {
+        let object = self.validate_jsx_attrs(jsx_element_span, attrs, children, Some(element))?;
+
+        self.assign_with_opts(
+            &mut Default::default(),
+            element,
+            &object,
+            AssignOpts {
+                span: jsx_element_span,
+                allow_missing_fields: true,
+                allow_unknown_hyphenated_rhs: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Validates the attributes and the children of a function or class
+    /// component, selecting the matching overload.
+    ///
+    /// Ported from `resolveJsxOpeningLikeElement` of `tsc`.
+    fn validate_jsx_component(
+        &mut self,
+        jsx_element_span: Span,
+        name_span: Span,
+        component: &Type,
+        type_args: Option<&RTsTypeParamInstantiation>,
+        attrs: &[RJSXAttrOrSpread],
+        children: Option<&Type>,
+    ) -> VResult<()> {
+        if component.is_any() || component.is_unknown() {
+            self.validate_jsx_attrs(jsx_element_span, attrs, children, None)?;
+            return Ok(());
+        }
+
+        // `const Tag = "div"; <Tag />`
+        if let Type::Lit(LitType { lit: RTsLit::Str(tag), .. }) = component.normalize() {
+            let element = self.get_jsx_intrinsic_element(name_span, &tag.value)?.freezed();
+            return self.validate_jsx_intrinsic_element(jsx_element_span, &element, attrs, children);
+        }
+
+        // Functions are callable with `new` in javascript, but they are not class
+        // components.
+        let is_fn = match component.normalize() {
+            Type::Function(..) => true,
+            Type::Union(u) => u.types.iter().all(|ty| matches!(ty.normalize(), Type::Function(..))),
+            _ => false,
+        };
+
+        let mut is_class = !is_fn;
+        let mut candidates = if is_fn {
+            vec![]
+        } else {
+            self.extract_callee_candidates(name_span, ExtractKind::New, component)?
+        };
+        if candidates.is_empty() {
+            is_class = false;
+            candidates = self.extract_callee_candidates(name_span, ExtractKind::Call, component)?;
+        }
+
+        if candidates.is_empty() {
+            self.validate_jsx_attrs(jsx_element_span, attrs, children, None)?;
+            return Err(ErrorKind::JsxElementTypeHasNoSignatures { span: name_span }.into());
+        }
+
+        let type_args = match type_args {
+            Some(type_args) => Some(type_args.validate_with(self)?.freezed()),
+            None => None,
+        };
+
+        // Attributes are contextually typed only if there's no ambiguity.
+        let contextual_props = match &*candidates {
+            [candidate] if candidate.type_params.is_none() => {
+                Some(self.get_jsx_props_type(jsx_element_span, name_span, component, is_class, candidate, !attrs.is_empty())?)
+            }
+            _ => None,
+        };
+        let object = self.validate_jsx_attrs(jsx_element_span, attrs, children, contextual_props.as_ref())?;
+
+        self.validate_jsx_component_return_type(name_span, is_class, &candidates);
+
+        let mut errors = vec![];
+        for candidate in &candidates {
+            let candidate = match (&candidate.type_params, &type_args) {
+                (Some(type_params), _) => {
+                    let map = match &type_args {
+                        Some(type_args) => jsx_type_arg_map(name_span, &type_params.params, type_args.params.clone()),
+                        None => {
+                            let param = first_param_type(name_span, candidate);
+                            self.infer_type_with_types(name_span, &type_params.params, &param, &object, Default::default())?
+                        }
+                    };
+
+                    self.instantiate_call_candidate(candidate, &map)?
+                }
+                _ => candidate.clone(),
+            };
+
+            let props = match &contextual_props {
+                Some(props) => props.clone(),
+                None => self.get_jsx_props_type(jsx_element_span, name_span, component, is_class, &candidate, !attrs.is_empty())?,
+            };
+
+            match self.assign_with_opts(
+                &mut Default::default(),
+                &props,
+                &object,
+                AssignOpts {
+                    span: jsx_element_span,
+                    allow_unknown_hyphenated_rhs: true,
+                    ..Default::default()
+                },
+            ) {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        if errors.len() == 1 {
+            return Err(errors.pop().unwrap());
+        }
+
+        Err(ErrorKind::NoMatchingOverload { span: name_span }.into())
+    }
+
+    /// Expands type parameters of `candidate` using `map`.
+    ///
+    /// Parameters and the return type are expanded separately, because
+    /// expanding a function type as a whole may result in another kind of type,
+    /// like a reference or a union.
+    fn instantiate_call_candidate(&mut self, candidate: &CallCandidate, map: &FxHashMap<Id, Type>) -> VResult<CallCandidate> {
+        let params = candidate
+            .params
+            .iter()
+            .map(|param| -> VResult<_> {
+                let ty = box self.expand_type_params(map, *param.ty.clone(), Default::default())?.freezed();
+
+                Ok(FnParam { ty, ..param.clone() })
+            })
+            .collect::<VResult<_>>()?;
+        let ret_ty = box self.expand_type_params(map, *candidate.ret_ty.clone(), Default::default())?.freezed();
+
+        Ok(CallCandidate {
+            type_params: None,
+            params,
+            ret_ty,
+        })
+    }
+
+    /// Ported from `getJsxPropsTypeFromClassElement` and
+    /// `getJsxPropsTypeFromCallSignature` of `tsc`.
+    fn get_jsx_props_type(
+        &mut self,
+        jsx_element_span: Span,
+        name_span: Span,
+        component: &Type,
+        is_class: bool,
+        candidate: &CallCandidate,
+        has_attrs: bool,
+    ) -> VResult<Type> {
+        let props = if is_class {
+            match self.get_jsx_property_name(jsx_element_span, ELEMENT_ATTRIBUTES_PROPERTY)? {
+                // If there's no `JSX.ElementAttributesProperty`, the first parameter is the props.
+                None => first_param_type(name_span, candidate),
+                // If `JSX.ElementAttributesProperty` is empty, the instance type is the props.
+                Some(name) if name.is_empty() => *candidate.ret_ty.clone(),
+                Some(name) => {
+                    let res = self.access_property(
+                        name_span,
+                        &candidate.ret_ty,
+                        &Key::Normal {
+                            span: name_span,
+                            sym: name.clone(),
+                        },
+                        TypeOfMode::RValue,
+                        IdCtx::Var,
+                        AccessPropertyOpts {
+                            disallow_creating_indexed_type_from_ty_els: true,
+                            ..Default::default()
+                        },
+                    );
+
+                    match res {
+                        Ok(ty) => ty,
+                        Err(..) => {
+                            if has_attrs {
+                                self.storage
+                                    .report(ErrorKind::JsxElementClassWithoutAttributesProperty { span: name_span, name }.into());
+                            }
+                            return Ok(Type::unknown(name_span, Default::default()));
+                        }
+                    }
+                }
+            }
+        } else {
+            first_param_type(name_span, candidate)
+        };
+
+        let props = match self.get_jsx_type(jsx_element_span, LIBRARY_MANAGED_ATTRIBUTES) {
+            Some(managed) => self.instantiate_jsx_type(jsx_element_span, managed, vec![component.clone(), props])?,
+            None => props,
+        };
+
+        if props.is_any() {
+            return Ok(props.freezed());
+        }
+
+        let mut types = vec![props];
+        if is_class {
+            if let Some(class_attrs) = self.get_jsx_type(jsx_element_span, INTRINSIC_CLASS_ATTRIBUTES) {
+                types.push(self.instantiate_jsx_type(jsx_element_span, class_attrs, vec![*candidate.ret_ty.clone()])?);
+            }
+        }
+        if let Some(attrs) = self.get_jsx_type(jsx_element_span, INTRINSIC_ATTRIBUTES) {
+            types.push(attrs);
+        }
+
+        Ok(Type::new_intersection(jsx_element_span, types).freezed())
+    }
+
+    /// Ported from `checkJsxReturnAssignableToAppropriateBound` of `tsc`.
+    fn validate_jsx_component_return_type(&mut self, name_span: Span, is_class: bool, candidates: &[CallCandidate]) {
+        let bound = if is_class {
+            match self.get_jsx_type(name_span, ELEMENT_CLASS) {
+                Some(v) => v,
+                None => return,
+            }
+        } else {
+            match self.get_jsx_type(name_span, ELEMENT) {
+                Some(v) => Type::new_union(
+                    name_span,
+                    vec![
+                        v,
+                        Type::Keyword(KeywordType {
+                            span: name_span,
+                            kind: TsKeywordTypeKind::TsNullKeyword,
+                            metadata: Default::default(),
+                            tracker: Default::default(),
+                        }),
+                    ],
                 )
-                .report(&mut self.storage);
+                .freezed(),
+                None => return,
             }
-            ResolvedJsxName::Value(name) => {}
+        };
+
+        // Return types of generic signatures depend on the type arguments.
+        let ret_types = candidates
+            .iter()
+            .filter(|c| c.type_params.is_none())
+            .map(|c| *c.ret_ty.clone())
+            .collect::<Vec<_>>();
+        if ret_types.is_empty() {
+            return;
+        }
+        let ret_ty = Type::new_union(name_span, ret_types).freezed();
+        if ret_ty.is_any() {
+            return;
         }
 
-        Ok(())
+        if self
+            .assign_with_opts(
+                &mut Default::default(),
+                &bound,
+                &ret_ty,
+                AssignOpts {
+                    span: name_span,
+                    ..Default::default()
+                },
+            )
+            .is_err()
+        {
+            self.storage.report(ErrorKind::InvalidJsxComponent { span: name_span }.into());
+        }
     }
 }
 
+fn first_param_type(span: Span, candidate: &CallCandidate) -> Type {
+    candidate
+        .params
+        .first()
+        .map(|p| *p.ty.clone())
+        .unwrap_or_else(|| Type::unknown(span, Default::default()))
+}
+
+/// Missing type arguments are filled with defaults of type parameters, or
+/// `unknown`.
+fn jsx_type_arg_map(span: Span, type_params: &[TypeParam], type_args: Vec<Type>) -> FxHashMap<Id, Type> {
+    let mut type_args = type_args.into_iter();
+
+    type_params
+        .iter()
+        .map(|param| {
+            let arg = type_args
+                .next()
+                .or_else(|| param.default.as_deref().cloned())
+                .unwrap_or_else(|| Type::unknown(span, Default::default()));
+
+            (param.name.clone(), arg.freezed())
+        })
+        .collect()
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, e: &RJSXElement, type_ann: Option<&Type>) -> VResult<Type> {
-        let mut name = e.opening.name.validate_with(self)?;
-        let children = e.children.validate_with(self)?;
+        // Overloaded functions are resolved as `typeof Foo` outside of a call.
+        let mut name = self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
+            e.opening.name.validate_with(analyzer)
+        })?;
+        let children = self.validate_jsx_children(e.span, &e.children)?.freezed();
 
         match &mut name {
             ResolvedJsxName::Intrinsic(name) => {
@@ -203,21 +755,34 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        self.validate_jsx_attrs(e.span, &name, &e.opening.attrs).report(&mut self.storage);
-
-        match name {
-            ResolvedJsxName::Intrinsic(name) => Ok(name),
-            ResolvedJsxName::Value(name) => Ok(name),
+        match &name {
+            ResolvedJsxName::Intrinsic(element) => {
+                self.validate_jsx_intrinsic_element(e.span, element, &e.opening.attrs, children.as_ref())
+                    .report(&mut self.storage);
+            }
+            ResolvedJsxName::Value(component) => {
+                self.validate_jsx_component(
+                    e.span,
+                    e.opening.name.span(),
+                    component,
+                    e.opening.type_args.as_deref(),
+                    &e.opening.attrs,
+                    children.as_ref(),
+                )
+                .report(&mut self.storage);
+            }
         }
+
+        Ok(self.get_jsx_element_type(e.span))
     }
 }
 
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, e: &RJSXFragment, type_ann: Option<&Type>) -> VResult<Type> {
-        let children = e.children.validate_with(self)?;
+        self.validate_jsx_children(e.span, &e.children)?;
 
-        self.get_jsx_intrinsic_element(e.span, &"Fragment".into())
+        Ok(self.get_jsx_element_type(e.span))
     }
 }
 
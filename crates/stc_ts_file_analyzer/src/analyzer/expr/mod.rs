@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    cell::{Cell, RefCell},
     collections::HashMap,
     convert::{TryFrom, TryInto},
     time::{Duration, Instant},
@@ -8,9 +9,9 @@ use std::{
 use optional_chaining::is_obj_opt_chaining;
 use rnode::{NodeId, VisitWith};
 use stc_ts_ast_rnode::{
-    RAssignExpr, RBindingIdent, RClassExpr, RExpr, RFnExpr, RIdent, RInvalid, RLit, RMemberExpr, RMemberProp, RNull, RNumber,
-    ROptChainBase, ROptChainExpr, RParam, RParenExpr, RPat, RPatOrExpr, RSeqExpr, RStr, RSuper, RSuperProp, RSuperPropExpr, RThisExpr,
-    RTpl, RTsEntityName, RTsEnumMemberId, RTsLit, RTsNonNullExpr, RUnaryExpr,
+    RAssignExpr, RBindingIdent, RClassExpr, RComputedPropName, RExpr, RFnExpr, RIdent, RInvalid, RLit, RMemberExpr, RMemberProp, RNull,
+    RNumber, ROptChainBase, ROptChainExpr, RParam, RParenExpr, RPat, RPatOrExpr, RSeqExpr, RStr, RSuper, RSuperProp, RSuperPropExpr,
+    RThisExpr, RTpl, RTsEntityName, RTsEnumMemberId, RTsLit, RTsNonNullExpr, RUnaryExpr,
 };
 use stc_ts_base_type_ops::bindings::BindingKind;
 use stc_ts_env::ModuleConfig;
@@ -26,7 +27,7 @@ use stc_ts_types::{
     Key, KeywordType, KeywordTypeMetadata, LitType, Method, Module, ModuleTypeData, OptionalType, PropertySignature, QueryExpr, QueryType,
     QueryTypeMetadata, Readonly, StaticThis, ThisType, TplElem, TplType, TplTypeMetadata, TypeParamInstantiation,
 };
-use stc_utils::{cache::Freeze, dev_span, ext::TypeVecExt, panic_ctx, stack};
+use stc_utils::{cache::Freeze, dev_span, ext::TypeVecExt, panic_context, panic_ctx, stack};
 use swc_atoms::js_word;
 use swc_common::{SourceMapper, Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::{op, EsVersion, TruePlusMinus, TsKeywordTypeKind, VarDeclKind};
@@ -103,6 +104,7 @@ impl Analyzer<'_, '_> {
         type_ann: Option<&Type>,
     ) -> VResult<Type> {
         let _stack = stack::start(64);
+        panic_context::record_span(e.span());
         let _ctx = panic_ctx!(format!(
             "validate {}\n{}\nExpr: {:?}",
             self.cm.span_to_string(e.span()),
@@ -267,6 +269,12 @@ impl Analyzer<'_, '_> {
                                 TypeFacts::Truthy | TypeFacts::NEUndefinedOrNull | TypeFacts::NEUndefined | TypeFacts::NENull,
                             );
                             self.cur_facts.false_facts.facts.insert(name, TypeFacts::Falsy);
+                        } else if let Some(key) = elem_fact_key(expr) {
+                            self.cur_facts.true_facts.elem_facts.insert(
+                                key.clone(),
+                                TypeFacts::Truthy | TypeFacts::NEUndefinedOrNull | TypeFacts::NEUndefined | TypeFacts::NENull,
+                            );
+                            self.cur_facts.false_facts.elem_facts.insert(key, TypeFacts::Falsy);
                         }
                     }
 
@@ -327,6 +335,22 @@ impl Analyzer<'_, '_> {
         ty.freeze();
         self.cur_facts.assert_clone_cheap();
 
+        if let Some(mutations) = &mut self.mutations {
+            if mutations.record_expr_types {
+                if let Some(node_id) = e.node_id() {
+                    if !node_id.is_invalid() {
+                        mutations.expr_types.insert(node_id, ty.clone());
+
+                        if let Some(type_ann) = &type_ann {
+                            mutations
+                                .expr_contextual_types
+                                .insert(node_id, type_ann.clone().into_owned().freezed());
+                        }
+                    }
+                }
+            }
+        }
+
         if !self.config.is_builtin && !ty.is_any() {
             debug_assert_ne!(
                 ty.span(),
@@ -360,6 +384,8 @@ impl Analyzer<'_, '_> {
             ..self.ctx
         };
         self.with_ctx(ctx).with(|analyzer: &mut Analyzer| {
+            let commonjs_export = analyzer.commonjs_export_target(e);
+
             let span = e.span();
             let mut mark_var_as_truthy = false;
             let mut skip_right = false;
@@ -396,6 +422,7 @@ impl Analyzer<'_, '_> {
                                                     types,
                                                     private_vars,
                                                     vars,
+                                                    ..
                                                 },
                                             ..
                                         }) => {
@@ -428,6 +455,9 @@ impl Analyzer<'_, '_> {
                     (any_span, ty_of_left.as_ref())
                 }
 
+                // `module` and `exports` are not declared in JavaScript files.
+                RPatOrExpr::Pat(box RPat::Expr(..)) | RPatOrExpr::Expr(..) if commonjs_export.is_some() => (None, None),
+
                 RPatOrExpr::Pat(box RPat::Expr(ref e)) | RPatOrExpr::Expr(ref e) => {
                     ty_of_left = e
                         .validate_with_args(analyzer, (TypeOfMode::LValue, None, None))
@@ -595,6 +625,13 @@ impl Analyzer<'_, '_> {
             rhs_ty.respan(e.right.span());
             rhs_ty.freeze();
 
+            // The target is not declared, so we don't check the assignment.
+            if let Some(name) = commonjs_export {
+                analyzer.export_commonjs(span, name, rhs_ty.clone());
+
+                return Ok(rhs_ty);
+            }
+
             let ret_ty = analyzer.try_assign(span, e.op, &e.left, &rhs_ty);
 
             if let Some(span) = any_span {
@@ -608,7 +645,7 @@ impl Analyzer<'_, '_> {
 
 /// All fields defaults to default value of the type. (`false` for [bool]).
 #[derive(Debug, Clone, Copy, Default)]
-pub(crate) struct AccessPropertyOpts {
+pub(crate) struct AccessPropertyOpts<'a> {
     pub do_not_validate_type_of_computed_prop: bool,
 
     pub disallow_indexing_array_with_string: bool,
@@ -650,6 +687,17 @@ pub(crate) struct AccessPropertyOpts {
     /// `true` means that the provided [Key] is crated from a computed key.
     pub is_key_computed: bool,
 
+    /// Add `undefined` to the type of elements accessed using an index
+    /// signature.
+    ///
+    /// This is `true` for reads of member expressions if
+    /// `noUncheckedIndexedAccess` is enabled.
+    pub include_undefined_for_index_signature: bool,
+
+    /// If [Some], `access_property` records facts used to report errors
+    /// about the access in it.
+    pub facts: Option<&'a AccessPropertyFacts>,
+
     /// `true` means parent type is union
     pub is_in_union: bool,
 
@@ -659,6 +707,26 @@ pub(crate) struct AccessPropertyOpts {
     pub use_last_element_for_tuple_on_out_of_bound: bool,
 }
 
+/// Facts found by `access_property`.
+///
+/// `access_property` may visit multiple types for an access, like members of a
+/// union, so the caller reports errors for these facts once.
+#[derive(Debug, Default)]
+pub(crate) struct AccessPropertyFacts {
+    /// `true` if a property accessed with a dot is resolved using an index
+    /// signature.
+    index_signature_accessed_with_dot: Cell<bool>,
+
+    /// The first error for an implicit `any` created by indexing an object.
+    implicit_any_index_error: RefCell<Option<ErrorKind>>,
+}
+
+impl AccessPropertyFacts {
+    fn record_implicit_any_index_error(&self, err: ErrorKind) {
+        self.implicit_any_index_error.borrow_mut().get_or_insert(err);
+    }
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, e: &RSeqExpr, mode: TypeOfMode, type_ann: Option<&Type>) -> VResult<Type> {
@@ -785,6 +853,29 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Returns whether the property `prop` of `obj` is declared as optional, or
+    /// [None] if it's unknown.
+    pub(crate) fn is_optional_property(&mut self, span: Span, obj: &Type, prop: &RMemberProp) -> VResult<Option<bool>> {
+        let prop = match prop {
+            RMemberProp::Ident(i) => Key::Normal {
+                span: i.span,
+                sym: i.sym.clone(),
+            },
+            _ => return Ok(None),
+        };
+
+        let type_lit = match self.convert_type_to_type_lit(span, Cow::Borrowed(obj))? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        Ok(type_lit.members.iter().find_map(|el| match el {
+            TypeElement::Property(p) if self.key_matches(span, &p.key, &prop, false) => Some(p.optional),
+            TypeElement::Method(m) if self.key_matches(span, &m.key, &prop, false) => Some(m.optional),
+            _ => None,
+        }))
+    }
+
     /// Check if key matches.
     ///
     /// # Parameters
@@ -942,7 +1033,11 @@ impl Analyzer<'_, '_> {
                             }
 
                             if let Some(ref type_ann) = p.type_ann {
-                                if p.optional {
+                                // With `exactOptionalPropertyTypes`, `undefined` can be read from an
+                                // optional property but it can't be written to it.
+                                let exact = type_mode == TypeOfMode::LValue && self.rule().exact_optional_property_types;
+
+                                if p.optional && !exact {
                                     matching_elements.push(type_ann.clone().union_with_undefined(span));
                                 } else {
                                     matching_elements.push(*type_ann.clone());
@@ -1068,7 +1163,7 @@ impl Analyzer<'_, '_> {
                         }
 
                         if let Some(type_ann) = type_ann {
-                            return Ok(Some(*type_ann.clone()));
+                            return Ok(Some(self.index_signature_access_result(span, prop, *type_ann.clone(), opts)));
                         }
                     }
                 }
@@ -1102,6 +1197,10 @@ impl Analyzer<'_, '_> {
                 // I guess it's because javascript work in that way.
 
                 if index_ty.is_kwd(TsKeywordTypeKind::TsNumberKeyword) && prop_ty.is_str() && prop.is_computed() {
+                    if let Some(facts) = opts.facts {
+                        facts.record_implicit_any_index_error(ErrorKind::ImplicitAnyBecauseIndexIsNotNumber { span });
+                    }
+
                     return Ok(Some(Type::any(span, Default::default())));
                 }
 
@@ -1113,16 +1212,19 @@ impl Analyzer<'_, '_> {
                         return Err(ErrorKind::ReadOnly { span }.into());
                     }
                     if let Some(type_ann) = type_ann {
-                        return Ok(Some(*type_ann.clone()));
+                        return Ok(Some(self.index_signature_access_result(span, prop, *type_ann.clone(), opts)));
                     }
 
                     return Ok(Some(Type::any(span, Default::default())));
                 }
 
                 if (**index_ty).type_eq(&*prop_ty) {
-                    return Ok(Some(
+                    return Ok(Some(self.index_signature_access_result(
+                        span,
+                        prop,
                         type_ann.clone().map(|v| *v).unwrap_or_else(|| Type::any(span, Default::default())),
-                    ));
+                        opts,
+                    )));
                 }
 
                 if let Type::EnumVariant(..) = prop_ty.normalize() {
@@ -1173,6 +1275,24 @@ impl Analyzer<'_, '_> {
         Ok(Some(result))
     }
 
+    /// Adds `undefined` to `ty` if `opts` requires it, and records that an
+    /// index signature is accessed with a dot so the caller can report it for
+    /// `noPropertyAccessFromIndexSignature`.
+    ///
+    /// This should be used for types of elements accessed using an index
+    /// signature.
+    fn index_signature_access_result(&mut self, span: Span, prop: &Key, ty: Type, opts: AccessPropertyOpts) -> Type {
+        if let (Key::Normal { .. }, Some(facts)) = (prop, opts.facts) {
+            facts.index_signature_accessed_with_dot.set(true);
+        }
+
+        if opts.include_undefined_for_index_signature && !ty.is_any() {
+            ty.union_with_undefined(span)
+        } else {
+            ty
+        }
+    }
+
     pub(super) fn access_property(
         &mut self,
         span: Span,
@@ -1653,11 +1773,16 @@ impl Analyzer<'_, '_> {
                                         || self.assign(span, &mut Default::default(), index_ty, &prop_ty).is_ok();
 
                                     if indexed {
-                                        return Ok(index
-                                            .type_ann
-                                            .clone()
-                                            .map(|v| *v)
-                                            .unwrap_or_else(|| Type::any(span, Default::default())));
+                                        return Ok(self.index_signature_access_result(
+                                            span,
+                                            prop,
+                                            index
+                                                .type_ann
+                                                .clone()
+                                                .map(|v| *v)
+                                                .unwrap_or_else(|| Type::any(span, Default::default())),
+                                            opts,
+                                        ));
                                     }
                                 }
                             }
@@ -2074,11 +2199,16 @@ impl Analyzer<'_, '_> {
                                     || self.assign(span, &mut Default::default(), index_ty, &prop_ty).is_ok();
 
                                 if indexed {
-                                    return Ok(index
-                                        .type_ann
-                                        .clone()
-                                        .map(|v| *v)
-                                        .unwrap_or_else(|| Type::any(span, Default::default())));
+                                    return Ok(self.index_signature_access_result(
+                                        span,
+                                        prop,
+                                        index
+                                            .type_ann
+                                            .clone()
+                                            .map(|v| *v)
+                                            .unwrap_or_else(|| Type::any(span, Default::default())),
+                                        opts,
+                                    ));
                                 }
                             }
                         }
@@ -2121,6 +2251,10 @@ impl Analyzer<'_, '_> {
                     };
 
                 if has_better_default {
+                    if let Some(facts) = opts.facts {
+                        facts.record_implicit_any_index_error(ErrorKind::ImplicitAnyBecauseIndexTypeIsWrong { span });
+                    }
+
                     return Ok(Type::any(span, Default::default()));
                 }
 
@@ -2314,21 +2448,21 @@ impl Analyzer<'_, '_> {
                     self.scope.store_type_param(Id::word("T".into()), *elem_type.clone());
                 }
 
-                if let Key::Computed(prop) = prop {
-                    match prop.ty.normalize() {
+                if let Key::Computed(key) = prop {
+                    match key.ty.normalize() {
                         Type::Keyword(KeywordType {
                             kind: TsKeywordTypeKind::TsNumberKeyword,
                             ..
                         })
                         | Type::Lit(LitType {
                             lit: RTsLit::Number(..), ..
-                        }) => return Ok(*elem_type),
+                        }) => return Ok(self.index_signature_access_result(span, prop, *elem_type, opts)),
 
                         _ => {}
                     }
                 }
                 if let Key::Num(n) = prop {
-                    return Ok(*elem_type.clone());
+                    return Ok(self.index_signature_access_result(span, prop, *elem_type.clone(), opts));
                 }
 
                 let array_ty = self.env.get_global_type(span, &js_word!("Array"))?;
@@ -2385,7 +2519,13 @@ impl Analyzer<'_, '_> {
                                 kind: TsKeywordTypeKind::TsStringKeyword,
                                 ..
                             })
-                            | Type::Lit(LitType { lit: RTsLit::Str(..), .. }) => Ok(Type::any(span, Default::default())),
+                            | Type::Lit(LitType { lit: RTsLit::Str(..), .. }) => {
+                                if let Some(facts) = opts.facts {
+                                    facts.record_implicit_any_index_error(ErrorKind::ImplicitAnyBecauseIndexIsNotNumber { span });
+                                }
+
+                                Ok(Type::any(span, Default::default()))
+                            }
                             _ => Err(err),
                         }
                     });
@@ -2433,6 +2573,10 @@ impl Analyzer<'_, '_> {
                 }
 
                 if prop.is_computed() && !opts.do_not_use_any_for_object {
+                    if let Some(facts) = opts.facts {
+                        facts.record_implicit_any_index_error(ErrorKind::ImplicitAnyBecauseIndexTypeIsWrong { span });
+                    }
+
                     return Ok(Type::any(span, Default::default()));
                 }
 
@@ -2867,11 +3011,16 @@ impl Analyzer<'_, '_> {
 
                                 let indexed = self.assign(span, &mut Default::default(), index_ty, &prop_ty).is_ok();
                                 if indexed {
-                                    return Ok(index
-                                        .type_ann
-                                        .clone()
-                                        .map(|v| *v)
-                                        .unwrap_or_else(|| Type::any(span, Default::default())));
+                                    return Ok(self.index_signature_access_result(
+                                        span,
+                                        prop,
+                                        index
+                                            .type_ann
+                                            .clone()
+                                            .map(|v| *v)
+                                            .unwrap_or_else(|| Type::any(span, Default::default())),
+                                        opts,
+                                    ));
                                 }
                             }
                         }
@@ -3674,10 +3823,6 @@ impl Analyzer<'_, '_> {
             _ => {}
         }
 
-        if i.sym == js_word!("require") {
-            unreachable!("typeof(require('...'))");
-        }
-
         if let Some(ty) = self.find_imported_var(&i.into())? {
             debug!("({}) type_of({}): resolved import", self.scope.depth(), Id::from(i));
             return Ok(ty);
@@ -4115,13 +4260,49 @@ impl Analyzer<'_, '_> {
         }
     }
 
-    /// TODO(kdy1): Expand type arguments if provided.
     fn type_of_member_expr(
         &mut self,
         expr: &RMemberExpr,
         type_mode: TypeOfMode,
         include_optional_chaining_undefined: bool,
     ) -> VResult<Type> {
+        self.type_of_member_expr_with_obj(expr, type_mode, include_optional_chaining_undefined)
+            .map(|(ty, _)| ty)
+    }
+
+    /// Validates `expr` like [RExpr::Member], and returns the type of `expr`
+    /// with the type of the object.
+    ///
+    /// The type of the object is [None] if `expr` is resolved without
+    /// validating the object, e.g. if it's a name of a type fact.
+    pub(crate) fn type_and_obj_of_member_expr(
+        &mut self,
+        expr: &RMemberExpr,
+        type_mode: TypeOfMode,
+        include_optional_chaining_undefined: bool,
+    ) -> VResult<(Type, Option<Type>)> {
+        let (mut ty, obj_ty) = self.type_of_member_expr_with_obj(expr, type_mode, include_optional_chaining_undefined)?;
+
+        self.replace_invalid_type_params(&mut ty);
+        ty.fix();
+        ty.freeze();
+
+        if let Some(mutations) = &mut self.mutations {
+            if mutations.record_expr_types && !expr.node_id.is_invalid() {
+                mutations.expr_types.insert(expr.node_id, ty.clone());
+            }
+        }
+
+        Ok((ty, obj_ty))
+    }
+
+    /// TODO(kdy1): Expand type arguments if provided.
+    fn type_of_member_expr_with_obj(
+        &mut self,
+        expr: &RMemberExpr,
+        type_mode: TypeOfMode,
+        include_optional_chaining_undefined: bool,
+    ) -> VResult<(Type, Option<Type>)> {
         let RMemberExpr {
             ref obj, ref prop, span, ..
         } = *expr;
@@ -4133,7 +4314,7 @@ impl Analyzer<'_, '_> {
             if let Some(name) = &name {
                 if let Some(mut ty) = self.scope.get_type_from_name(name) {
                     ty.respan(span);
-                    return Ok(ty);
+                    return Ok((ty, None));
                 }
             }
         }
@@ -4195,6 +4376,23 @@ impl Analyzer<'_, '_> {
                     ty: box Type::any(span, Default::default()),
                 })
             });
+        // `arr[k]` in `for (const k in arr)` is an access using a numeric key.
+        if let (
+            Key::Computed(key),
+            RMemberProp::Computed(RComputedPropName {
+                expr: box RExpr::Ident(i), ..
+            }),
+        ) = (&mut prop, &expr.prop)
+        {
+            if self.data.for_in_vars_with_numeric_keys.contains(&Id::from(i)) {
+                key.ty = box Type::Keyword(KeywordType {
+                    span: i.span,
+                    kind: TsKeywordTypeKind::TsNumberKeyword,
+                    metadata: Default::default(),
+                    tracker: Default::default(),
+                });
+            }
+        }
         prop.freeze();
 
         let prop_access_ctx = Ctx {
@@ -4203,20 +4401,36 @@ impl Analyzer<'_, '_> {
         };
 
         let ctx = self.ctx;
-        let mut ty = self
-            .with_ctx(prop_access_ctx)
-            .access_property(
-                span,
-                &obj_ty,
-                &prop,
-                type_mode,
-                IdCtx::Var,
-                AccessPropertyOpts {
-                    check_for_undefined_or_null: true,
-                    ..Default::default()
-                },
-            )
-            .context("tried to access property of an object to calculate type of a member expression")?;
+        let include_undefined_for_index_signature = type_mode == TypeOfMode::RValue && self.rule().no_unchecked_indexed_access;
+        let facts = AccessPropertyFacts::default();
+        let res = self.with_ctx(prop_access_ctx).access_property(
+            span,
+            &obj_ty,
+            &prop,
+            type_mode,
+            IdCtx::Var,
+            AccessPropertyOpts {
+                check_for_undefined_or_null: true,
+                include_undefined_for_index_signature,
+                facts: Some(&facts),
+                ..Default::default()
+            },
+        );
+        if facts.index_signature_accessed_with_dot.get() && !computed && self.rule().no_property_access_from_index_signature {
+            self.storage.report(
+                ErrorKind::PropertyFromIndexSignatureMustBeAccessedWithBracket {
+                    span,
+                    prop: box prop.clone(),
+                }
+                .into(),
+            );
+        }
+        if let Some(err) = facts.implicit_any_index_error.into_inner() {
+            if computed && self.rule().no_implicit_any && !self.rule().suppress_implicit_any_index_errors {
+                self.storage.report(err.into());
+            }
+        }
+        let mut ty = res.context("tried to access property of an object to calculate type of a member expression")?;
 
         if !self.config.is_builtin {
             if let Some(name) = name {
@@ -4237,6 +4451,12 @@ impl Analyzer<'_, '_> {
                 }
 
                 debug_assert_ne!(ty.span(), DUMMY_SP);
+            } else if let Some(key) = elem_fact_key(expr) {
+                ty = self.apply_elem_type_facts(&key, ty);
+
+                if ty.span().is_dummy() {
+                    ty.respan(span);
+                }
             }
         }
 
@@ -4275,12 +4495,12 @@ impl Analyzer<'_, '_> {
         };
 
         if should_be_optional && include_optional_chaining_undefined {
-            Ok(ty.union_with_undefined(span))
+            Ok((ty.union_with_undefined(span), Some(obj_ty)))
         } else {
             if !self.config.is_builtin {
                 debug_assert_ne!(ty.span(), DUMMY_SP);
             }
-            Ok(ty)
+            Ok((ty, Some(obj_ty)))
         }
     }
 
@@ -4679,6 +4899,17 @@ impl Analyzer<'_, '_> {
     }
 }
 
+/// Returns the key of facts for `obj[key]`, where `key` is a variable.
+fn elem_fact_key(expr: &RMemberExpr) -> Option<(Name, Id)> {
+    match &expr.prop {
+        RMemberProp::Computed(RComputedPropName {
+            expr: box RExpr::Ident(key),
+            ..
+        }) => Some((Name::try_from(&*expr.obj).ok()?, key.into())),
+        _ => None,
+    }
+}
+
 fn function_has_this(expr: &RExpr) -> bool {
     match expr {
         RExpr::Fn(RFnExpr {
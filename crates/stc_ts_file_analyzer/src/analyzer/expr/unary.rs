@@ -213,8 +213,8 @@ impl Analyzer<'_, '_> {
             })
             | RExpr::Member(expr) => {
                 if self.rule().strict_null_checks {
-                    let ty = self
-                        .type_of_member_expr(expr, TypeOfMode::RValue, false)
+                    let (ty, obj) = self
+                        .type_and_obj_of_member_expr(expr, TypeOfMode::RValue, false)
                         .convert_err(|err| match err {
                             ErrorKind::ObjectIsPossiblyNull { span, .. }
                             | ErrorKind::ObjectIsPossiblyUndefined { span, .. }
@@ -222,6 +222,18 @@ impl Analyzer<'_, '_> {
                             _ => err,
                         })?;
 
+                    if self.rule().exact_optional_property_types {
+                        // Only optional properties can be deleted, even if the type of the property
+                        // contains `undefined`.
+                        if let Some(obj) = obj.filter(|_| !ty.is_any() && !ty.is_unknown() && !ty.is_never()) {
+                            match self.is_optional_property(expr.span, &obj, &expr.prop)? {
+                                Some(true) => return Ok(()),
+                                Some(false) => return Err(ErrorKind::DeleteOperandMustBeOptional { span }.into()),
+                                None => {}
+                            }
+                        }
+                    }
+
                     if !ty.is_optional() && !ty.contains_undefined() {
                         return Err(ErrorKind::DeleteOperandMustBeOptional { span }.into());
                     }
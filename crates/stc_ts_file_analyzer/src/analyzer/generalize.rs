@@ -13,7 +13,7 @@ use swc_common::{EqIgnoreSpan, Spanned};
 use swc_ecma_ast::TsKeywordTypeKind;
 use tracing::{info, trace};
 
-use crate::{analyzer::Analyzer, ty::Type};
+use crate::{analyzer::Analyzer, ty::Type, util::method_to_fn_type};
 
 impl Analyzer<'_, '_> {
     /// TODO(kdy1): Remove this.
@@ -563,28 +563,63 @@ impl Fold<Type> for Simplifier<'_> {
             }) if index_type.name == name && is_str_lit_or_union(&constraint) => {
                 let mut new_members = vec![];
 
-                for member in &members {
-                    for key in constraint.iter_union() {
-                        let key = match key.normalize() {
-                            Type::Lit(LitType { lit: RTsLit::Str(v), .. }) => v.clone(),
-                            _ => unreachable!(),
-                        };
+                for key in constraint.iter_union() {
+                    let key = match key.normalize() {
+                        Type::Lit(LitType { lit: RTsLit::Str(v), .. }) => v.clone(),
+                        _ => unreachable!(),
+                    };
+
+                    // Call and construct signatures are not selected by a key.
+                    let len = new_members.len();
+                    new_members.extend(
+                        members
+                            .iter()
+                            .filter(|member| matches!(member, TypeElement::Property(..) | TypeElement::Method(..)))
+                            .filter(|member| member.key().map_or(false, |member_key| *member_key == key.value))
+                            .cloned(),
+                    );
+                    if new_members.len() != len {
+                        continue;
+                    }
 
-                        if let Some(member_key) = member.key() {
-                            if *member_key != key.value {
-                                continue;
-                            }
-                        }
+                    // `{ [key: string]: V }["a"]` is `V`, so the index signature becomes a
+                    // property named `a`.
+                    let index = members.iter().find_map(|member| match member {
+                        TypeElement::Index(i) => match i.params.first().map(|param| param.ty.normalize()) {
+                            Some(Type::Keyword(KeywordType {
+                                kind: TsKeywordTypeKind::TsStringKeyword,
+                                ..
+                            })) => Some(i),
+                            Some(Type::Keyword(KeywordType {
+                                kind: TsKeywordTypeKind::TsNumberKeyword,
+                                ..
+                            })) if key.value.parse::<f64>().is_ok() => Some(i),
+                            _ => None,
+                        },
+                        _ => None,
+                    });
 
-                        match member {
-                            TypeElement::Call(_) => unimplemented!(),
-                            TypeElement::Constructor(_) => unimplemented!(),
-                            TypeElement::Property(p) => new_members.push(p.clone().into()),
-                            TypeElement::Method(_) => {
-                                unimplemented!("Expansion of TypeElement::Method")
-                            }
-                            TypeElement::Index(_) => unimplemented!(),
-                        }
+                    if let Some(index) = index {
+                        new_members.push(TypeElement::Property(PropertySignature {
+                            span: index.span,
+                            accessibility: None,
+                            readonly: index.readonly,
+                            key: Key::Normal {
+                                span: key.span,
+                                sym: key.value.clone(),
+                            },
+                            optional: false,
+                            params: Default::default(),
+                            type_ann: Some(
+                                index
+                                    .type_ann
+                                    .clone()
+                                    .unwrap_or_else(|| box Type::any(index.span, Default::default())),
+                            ),
+                            type_params: Default::default(),
+                            metadata: Default::default(),
+                            accessor: Default::default(),
+                        }));
                     }
                 }
 
@@ -609,36 +644,29 @@ impl Fold<Type> for Simplifier<'_> {
                 obj_type: box Type::TypeLit(TypeLit { members, .. }),
                 index_type: box Type::Lit(LitType { lit: RTsLit::Str(v), .. }),
                 ..
-            }) if members.iter().any(|element| match element.key() {
-                Some(key) => *key == v.value,
+            }) if members.iter().any(|element| match element {
+                TypeElement::Property(p) => p.key == v.value,
+                TypeElement::Method(m) => m.key == v.value,
+                // Call, construct and index signatures are not selected by a key.
                 _ => false,
             }) =>
             {
                 let el = members
                     .into_iter()
-                    .find(|element| match element.key() {
-                        Some(key) => *key == v.value,
+                    .find(|element| match element {
+                        TypeElement::Property(p) => p.key == v.value,
+                        TypeElement::Method(m) => m.key == v.value,
                         _ => false,
                     })
                     .unwrap();
 
                 match el {
-                    TypeElement::Call(_) => {
-                        unimplemented!("Generic mapped type inference involving `Call` element")
-                    }
-                    TypeElement::Constructor(_) => {
-                        unimplemented!("Generic mapped type inference involving `Constructor` element")
-                    }
                     TypeElement::Property(p) => {
                         let span = p.span;
                         return p.type_ann.map(|v| *v).unwrap_or_else(|| Type::any(span, Default::default()));
                     }
-                    TypeElement::Method(_) => {
-                        unimplemented!("Generic mapped type inference involving `Method` element")
-                    }
-                    TypeElement::Index(_) => {
-                        unimplemented!("Generic mapped type inference involving IndexSignature element")
-                    }
+                    TypeElement::Method(m) => return method_to_fn_type(m.span, m.type_params, m.params, m.ret_ty),
+                    _ => unreachable!(),
                 }
             }
 
@@ -648,8 +676,8 @@ impl Fold<Type> for Simplifier<'_> {
                 ..
             }) if def.body.iter().any(|member| match member {
                 ClassMember::Constructor(_) => false,
-                ClassMember::Method(_) => false,
-                ClassMember::Property(p) => p.key == s.value,
+                ClassMember::Method(m) => !m.is_static && m.key == s.value,
+                ClassMember::Property(p) => !p.is_static && p.key == s.value,
                 ClassMember::IndexSignature(_) => false,
             }) =>
             {
@@ -658,15 +686,15 @@ impl Fold<Type> for Simplifier<'_> {
                     .iter()
                     .find(|member| match member {
                         ClassMember::Constructor(_) => false,
-                        ClassMember::Method(_) => false,
-                        ClassMember::Property(p) => p.key == s.value,
+                        ClassMember::Method(m) => !m.is_static && m.key == s.value,
+                        ClassMember::Property(p) => !p.is_static && p.key == s.value,
                         ClassMember::IndexSignature(_) => false,
                     })
                     .unwrap()
                     .clone();
 
                 match member {
-                    ClassMember::Method(_) => unimplemented!(),
+                    ClassMember::Method(m) => return method_to_fn_type(m.span, m.type_params, m.params, Some(m.ret_ty)),
                     ClassMember::Property(p) => {
                         if let Some(value) = p.value {
                             return *value;
@@ -695,13 +723,18 @@ impl Fold<Type> for Simplifier<'_> {
                     .map(|key| {
                         let member = def.body.iter().find(|member| match member {
                             ClassMember::Constructor(_) => false,
-                            ClassMember::Method(_) => false,
-                            ClassMember::Property(p) => p.key == key.value,
+                            ClassMember::Method(m) => !m.is_static && m.key == key.value,
+                            ClassMember::Property(p) => !p.is_static && p.key == key.value,
                             ClassMember::IndexSignature(_) => false,
                         })?;
 
                         match member {
-                            ClassMember::Method(_) => unimplemented!(),
+                            ClassMember::Method(m) => Some(method_to_fn_type(
+                                m.span,
+                                m.type_params.clone(),
+                                m.params.clone(),
+                                Some(m.ret_ty.clone()),
+                            )),
                             ClassMember::Property(p) => {
                                 if let Some(value) = &p.value {
                                     return Some(*value.clone());
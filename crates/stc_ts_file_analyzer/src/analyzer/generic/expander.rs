@@ -7,8 +7,8 @@ use stc_ts_generics::{
 };
 use stc_ts_type_ops::Fix;
 use stc_ts_types::{Id, Interface, KeywordType, TypeElement, TypeParam, TypeParamDecl, TypeParamInstantiation};
-use stc_utils::{cache::Freeze, dev_span, ext::SpanExt};
-use swc_common::{Span, Spanned, TypeEq};
+use stc_utils::{cache::Freeze, dev_span, ext::SpanExt, stack, stats};
+use swc_common::{Span, Spanned, TypeEq, DUMMY_SP};
 use swc_ecma_ast::*;
 use tracing::debug;
 
@@ -101,6 +101,15 @@ impl Analyzer<'_, '_> {
             debug_assert!(param.is_clone_cheap());
         }
 
+        // Type arguments are the only things with a span we have here.
+        let span = params
+            .values()
+            .map(|ty| ty.span())
+            .find(|span| !span.is_dummy())
+            .unwrap_or(DUMMY_SP);
+        let _instantiation = stack::track_instantiation(span)?;
+        stats::record(|s| s.instantiations += 1);
+
         GENERIC_CACHE.configure(|| {
             let ty = ty
                 .fold_with(&mut GenericExpander {
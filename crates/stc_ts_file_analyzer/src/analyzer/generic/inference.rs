@@ -222,7 +222,7 @@ impl Analyzer<'_, '_> {
         let (mut temp_sources, mut temp_targets) = self.infer_from_matching_types(
             span,
             inferred,
-            &once(arg).flat_map(|v| v.iter_union()).cloned().collect_vec(),
+            &once(arg).flat_map(|v| v.iter_union()).map(|ty| ty.clone().freezed()).collect_vec(),
             &param.types,
             |this, s, t| this.is_type_or_base_identical_to(s, t),
             opts,
@@ -2,7 +2,7 @@ use std::{borrow::Cow, cmp::min, collections::hash_map::Entry, mem::take, time::
 
 use fxhash::{FxHashMap, FxHashSet};
 use itertools::{EitherOrBoth, Itertools};
-use rnode::{Fold, FoldWith, VisitMut, VisitMutWith, VisitWith};
+use rnode::{Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith};
 use stc_ts_ast_rnode::{RBindingIdent, RIdent, RNumber, RPat, RTsEntityName};
 use stc_ts_errors::{
     debug::{dump_type_as_string, force_dump_type_as_string, print_backtrace, print_type},
@@ -14,9 +14,10 @@ use stc_ts_generics::{
 };
 use stc_ts_type_ops::{generalization::prevent_generalize, Fix};
 use stc_ts_types::{
-    replace::replace_type, Array, ClassMember, FnParam, Function, Id, IdCtx, Index, IndexSignature, IndexedAccessType, Intersection, Key,
-    KeywordType, KeywordTypeMetadata, Mapped, OptionalType, PropertySignature, Readonly, Ref, Tuple, TupleElement, TupleMetadata, Type,
-    TypeElement, TypeLit, TypeOrSpread, TypeParam, TypeParamDecl, TypeParamInstantiation, TypeParamMetadata, Union, UnionMetadata,
+    replace::replace_type, Array, ClassMember, FnParam, Function, Id, IdCtx, Index, IndexSignature, IndexedAccessType, InferType,
+    Intersection, Key, KeywordType, KeywordTypeMetadata, Mapped, OptionalType, PropertySignature, Readonly, Ref, RestType, Tuple,
+    TupleElement, TupleMetadata, Type, TypeElement, TypeLit, TypeOrSpread, TypeParam, TypeParamDecl, TypeParamInstantiation,
+    TypeParamMetadata, Union, UnionMetadata,
 };
 use stc_ts_utils::MapWithMut;
 use stc_utils::{
@@ -349,6 +350,21 @@ impl Analyzer<'_, '_> {
         Ok(map)
     }
 
+    /// Infers type parameters used by the type of a `this` parameter from
+    /// `receiver`.
+    ///
+    /// This is used for `bind`, `call` and `apply` of `CallableFunction`,
+    /// where `T`, `A` and `R` of `this: (this: T, ...args: A) => R` come from
+    /// the function being called.
+    pub(super) fn infer_type_of_this_param(&mut self, span: Span, this_param: &Type, receiver: &Type) -> VResult<FxHashMap<Id, Type>> {
+        let _tracing = dev_span!("infer_type_of_this_param");
+
+        let mut inferred = InferData::default();
+        self.infer_type(span, &mut inferred, this_param, receiver, Default::default())?;
+
+        Ok(self.finalize_inference(span, &[], inferred).types)
+    }
+
     /// Handles `infer U`.
     pub(super) fn infer_ts_infer_types(
         &mut self,
@@ -363,6 +379,25 @@ impl Analyzer<'_, '_> {
         self.infer_type(span, &mut inferred, base, concrete, opts)?;
         let mut map = self.finalize_inference(span, &[], inferred);
 
+        // `ThisParameterType<(a: number) => void>` should be `unknown`, so we use the
+        // constraint or `unknown` if a function type does not provide a candidate.
+        if matches!(concrete.normalize(), Type::Function(..) | Type::Constructor(..)) {
+            let mut usage = TypeParamUsageFinder::default();
+            concrete.visit_with(&mut usage);
+
+            if usage.params.is_empty() {
+                let mut finder = InferTypeFinder::default();
+                base.visit_with(&mut finder);
+
+                for param in finder.params {
+                    map.types.entry(param.name.clone()).or_insert_with(|| match param.constraint {
+                        Some(constraint) => *constraint,
+                        None => Type::unknown(span.with_ctxt(SyntaxContext::empty()), Default::default()),
+                    });
+                }
+            }
+        }
+
         for ty in map.types.values_mut() {
             prevent_generalize(ty);
         }
@@ -2251,8 +2286,67 @@ impl Analyzer<'_, '_> {
         args: &[FnParam],
         opts: InferTypeOpts,
     ) -> VResult<()> {
-        for (param, arg) in params.iter().zip(args) {
-            self.infer_type_of_fn_param(span, inferred, param, arg, opts)?
+        // `this` parameters are matched only with `this` parameters.
+        let (params, args) = match (params.first().map(is_this_param), args.first().map(is_this_param)) {
+            (Some(true), Some(true)) => {
+                self.infer_type_of_fn_param(span, inferred, &params[0], &args[0], opts)?;
+                (&params[1..], &args[1..])
+            }
+            (Some(true), _) => (&params[1..], args),
+            (_, Some(true)) => (params, &args[1..]),
+            _ => (params, args),
+        };
+
+        for (idx, param) in params.iter().enumerate() {
+            if let RPat::Rest(..) = param.pat {
+                let rest_args = &args[min(idx, args.len())..];
+                if let (Type::Param(..) | Type::Infer(..), [arg]) = (param.ty.normalize(), rest_args) {
+                    if let RPat::Rest(..) = arg.pat {
+                        self.infer_type_of_fn_param(span, inferred, param, arg, opts)?;
+                        break;
+                    }
+                }
+
+                if let Type::Param(..) | Type::Infer(..) = param.ty.normalize() {
+                    // `(...args: A) => R` captures remaining parameters as a tuple.
+                    let rest = Type::Tuple(Tuple {
+                        span: param.ty.span(),
+                        elems: rest_args
+                            .iter()
+                            .map(|arg| TupleElement {
+                                span: arg.span,
+                                label: None,
+                                ty: match arg.pat {
+                                    RPat::Rest(..) => box Type::Rest(RestType {
+                                        span: arg.span,
+                                        ty: arg.ty.clone(),
+                                        metadata: Default::default(),
+                                        tracker: Default::default(),
+                                    }),
+                                    _ if !arg.required => box Type::Optional(OptionalType {
+                                        span: arg.span,
+                                        ty: arg.ty.clone(),
+                                        metadata: Default::default(),
+                                        tracker: Default::default(),
+                                    }),
+                                    _ => arg.ty.clone(),
+                                },
+                                tracker: Default::default(),
+                            })
+                            .collect(),
+                        metadata: Default::default(),
+                        tracker: Default::default(),
+                    })
+                    .freezed();
+
+                    self.infer_type(span, inferred, &param.ty, &rest, opts)?;
+                    break;
+                }
+            }
+
+            if let Some(arg) = args.get(idx) {
+                self.infer_type_of_fn_param(span, inferred, param, arg, opts)?
+            }
         }
 
         if params.len() > args.len() {
@@ -2312,6 +2406,29 @@ impl Analyzer<'_, '_> {
     }
 }
 
+#[derive(Default)]
+struct InferTypeFinder {
+    params: Vec<TypeParam>,
+}
+
+impl Visit<InferType> for InferTypeFinder {
+    fn visit(&mut self, ty: &InferType) {
+        ty.visit_children_with(self);
+
+        self.params.push(ty.type_param.clone());
+    }
+}
+
+fn is_this_param(param: &FnParam) -> bool {
+    matches!(
+        param.pat,
+        RPat::Ident(RBindingIdent {
+            id: RIdent { sym: js_word!("this"), .. },
+            ..
+        })
+    )
+}
+
 fn array_elem_type(t: &Type) -> Option<&Type> {
     if let Type::Array(a) = t.normalize() {
         return Some(&a.elem_type);
@@ -6,16 +6,18 @@ use stc_ts_ast_rnode::{
     RCallExpr, RCallee, RExportAll, RExpr, RImportDecl, RImportSpecifier, RLit, RModuleItem, RNamedExport, RStr, RTsExternalModuleRef,
     RTsImportType,
 };
-use stc_ts_errors::ErrorKind;
+use stc_ts_env::ModuleConfig;
+use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_file_analyzer_macros::extra_validator;
 use stc_ts_storage::Storage;
-use stc_ts_types::{Id, ModuleId, Type};
+use stc_ts_types::{Id, IdCtx, Key, ModuleId, Type};
 use stc_ts_utils::imports::find_imports_in_comments;
+use stc_utils::cache::Freeze;
 use swc_atoms::{js_word, JsWord};
 use swc_common::{comments::Comments, FileName, Span, Spanned, GLOBALS};
 
 use crate::{
-    analyzer::{scope::VarKind, util::ResultExt, Analyzer},
+    analyzer::{expr::TypeOfMode, scope::VarKind, util::ResultExt, Analyzer},
     loader::ModuleInfo,
     validator, DepInfo, VResult,
 };
@@ -49,6 +51,59 @@ impl Analyzer<'_, '_> {
         (dep_id, data)
     }
 
+    /// Loads the module imported by `require(src)`.
+    ///
+    /// Returns the type of `export =` if the dependency has one, and the type
+    /// of the module otherwise.
+    pub(crate) fn load_required_module(&mut self, span: Span, src: &JsWord) -> VResult<Type> {
+        let ctxt = self.ctx.module_id;
+        let base = self.storage.path(ctxt);
+        let dep_id = match self.loader.module_id(&base, src) {
+            Some(v) => v,
+            None => {
+                self.storage.report(ErrorKind::ModuleNotFound { span }.into());
+
+                return Ok(Type::any(span, Default::default()));
+            }
+        };
+
+        let module_ty = match self.data.imports.get(&(ctxt, dep_id)).cloned() {
+            Some(v) => v,
+            // Circular imports are not loaded by `load_normal_imports`.
+            None if self.loader.is_in_same_circular_group(&base, src) => return Ok(Type::any(span, Default::default())),
+            None => self
+                .load_import_lazily(span, &base, dep_id, src)
+                .context("tried to load a module imported by require()")?,
+        };
+
+        Ok(self.export_equals_or_module(span, module_ty, IdCtx::Var))
+    }
+
+    /// Returns the type of `export =` of `module_ty`, or `module_ty` itself if
+    /// the module does not have one.
+    ///
+    /// `export =` is stored as `default`, so `export default` is ignored if the
+    /// module is not marked as a module using `export =`.
+    pub(crate) fn export_equals_or_module(&mut self, span: Span, module_ty: Type, id_ctx: IdCtx) -> Type {
+        if !has_export_equals(&module_ty) {
+            return module_ty;
+        }
+
+        self.access_property(
+            span,
+            &module_ty,
+            &Key::Normal {
+                span,
+                sym: js_word!("default"),
+            },
+            TypeOfMode::RValue,
+            id_ctx,
+            Default::default(),
+        )
+        .unwrap_or(module_ty)
+        .freezed()
+    }
+
     pub(super) fn find_imported_var(&self, id: &Id) -> VResult<Option<Type>> {
         if let Some(ModuleInfo { module_id, data }) = self.data.imports_by_id.get(id) {
             match data.normalize() {
@@ -163,6 +218,32 @@ impl Analyzer<'_, '_> {
         Ok(ty)
     }
 
+    /// Declares `id` as `any`.
+    fn declare_unresolved_import(&mut self, span: Span, id: Id) {
+        self.data.unresolved_imports.insert(id.clone());
+
+        self.register_type(id.clone(), Type::any(span, Default::default()));
+        self.declare_var(
+            span,
+            VarKind::Import,
+            id,
+            Some(Type::any(span, Default::default())),
+            None,
+            true,
+            false,
+            false,
+            false,
+        )
+        .report(&mut self.storage);
+    }
+
+    /// Ported from `getAllowSyntheticDefaultImports` of `tsc`.
+    fn allow_synthetic_default_imports(&self) -> bool {
+        let rule = self.rule();
+
+        rule.allow_synthetic_default_imports || rule.es_module_interop || matches!(self.env.module(), ModuleConfig::System)
+    }
+
     fn handle_import(&mut self, span: Span, ctxt: ModuleId, target: ModuleId, orig: Id, id: Id) {
         let mut found_entry = false;
         let is_import_successful = ctxt != target;
@@ -195,21 +276,7 @@ impl Analyzer<'_, '_> {
         }
 
         if !found_entry {
-            self.data.unresolved_imports.insert(id.clone());
-
-            self.register_type(id.clone(), Type::any(span, Default::default()));
-            self.declare_var(
-                span,
-                VarKind::Import,
-                id.clone(),
-                Some(Type::any(span, Default::default())),
-                None,
-                true,
-                false,
-                false,
-                false,
-            )
-            .report(&mut self.storage);
+            self.declare_unresolved_import(span, id.clone());
 
             if is_import_successful {
                 // If import was successful but the entry is not found, the error should point
@@ -242,6 +309,13 @@ impl Analyzer<'_, '_> {
                     }
                 }
                 RImportSpecifier::Default(default) => {
+                    if base != dep && has_export_equals(&data) && !self.allow_synthetic_default_imports() {
+                        self.storage
+                            .report(ErrorKind::ModuleCanOnlyBeDefaultImportedUsingFlag { span: default.local.span }.into());
+                        self.declare_unresolved_import(default.span, Id::from(&default.local));
+                        continue;
+                    }
+
                     self.handle_import(default.span, base, dep, Id::word(js_word!("default")), Id::from(&default.local));
                 }
                 RImportSpecifier::Namespace(ns) => {
@@ -259,11 +333,23 @@ impl Analyzer<'_, '_> {
                             false,
                         )?;
                     } else {
+                        let ty = if has_export_equals(&data) {
+                            // Only namespaces and variables can be imported as a namespace.
+                            if export_equals_needs_default_import(&data) {
+                                self.storage
+                                    .report(ErrorKind::ModuleCanOnlyBeReferencedWithDefaultImport { span: node.src.span }.into());
+                            }
+
+                            merge_namespace_with_decl(ns.span, self.export_equals_or_module(ns.span, data.clone(), IdCtx::Var))
+                        } else {
+                            data.clone()
+                        };
+
                         self.declare_var(
                             ns.span,
                             VarKind::Import,
                             ns.local.clone().into(),
-                            Some(data.clone()),
+                            Some(ty),
                             None,
                             true,
                             false,
@@ -279,6 +365,38 @@ impl Analyzer<'_, '_> {
     }
 }
 
+/// A class, a function or an enum merged with a namespace is stored as an
+/// union of the declarations, but members of all declarations are accessible.
+fn merge_namespace_with_decl(span: Span, ty: Type) -> Type {
+    match ty.normalize() {
+        Type::Union(u)
+            if u.types
+                .iter()
+                .any(|ty| matches!(ty.normalize(), Type::Module(..) | Type::Namespace(..))) =>
+        {
+            Type::new_intersection(span, u.types.clone()).freezed()
+        }
+        _ => ty,
+    }
+}
+
+/// Returns `true` if `module` uses `export =`.
+fn has_export_equals(module: &Type) -> bool {
+    match module.normalize() {
+        Type::Module(module) => module.exports.has_export_equals,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the target of `export =` of `module` is not a variable or
+/// a namespace.
+fn export_equals_needs_default_import(module: &Type) -> bool {
+    match module.normalize() {
+        Type::Module(module) => module.exports.export_equals_needs_default_import,
+        _ => false,
+    }
+}
+
 struct ImportFinder<'a, C>
 where
     C: Comments,
@@ -364,12 +482,16 @@ where
                     .args
                     .iter()
                     .map(|v| match *v.expr {
-                        RExpr::Lit(RLit::Str(RStr { ref value, .. })) => value.clone(),
-                        _ => unimplemented!("error reporting for dynamic require"),
+                        RExpr::Lit(RLit::Str(RStr { ref value, .. })) if v.spread.is_none() => Some(value.clone()),
+                        _ => None,
                     })
                     .next()
-                    .unwrap();
-                self.to.push((self.cur_ctxt, DepInfo { span, src }));
+                    .flatten();
+
+                // Dynamic `require()` calls are typed as `any`.
+                if let Some(src) = src {
+                    self.to.push((self.cur_ctxt, DepInfo { span, src }));
+                }
             }
             RCallee::Import(import) => {
                 let src = expr.args.first().and_then(|v| match *v.expr {
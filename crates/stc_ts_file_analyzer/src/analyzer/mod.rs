@@ -19,8 +19,8 @@ use stc_ts_env::{Env, Marks, ModuleConfig, Rule, StableEnv};
 use stc_ts_errors::{debug::debugger::Debugger, DebugExt, ErrorKind};
 use stc_ts_storage::{Builtin, Info, Storage};
 use stc_ts_type_cache::TypeCache;
-use stc_ts_types::{type_id::DestructureId, Id, IdCtx, Key, ModuleId, ModuleTypeData, Namespace};
-use stc_ts_utils::StcComments;
+use stc_ts_types::{type_id::DestructureId, Id, IdCtx, ModuleId, ModuleTypeData, Namespace};
+use stc_ts_utils::{imports::find_jsx_import_source_in_comments, StcComments};
 use stc_utils::{cache::Freeze, AHashMap, AHashSet};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{FileName, SourceMap, Span, DUMMY_SP, GLOBALS};
@@ -30,6 +30,7 @@ use self::{
     control_flow::{CondFacts, Facts},
     pat::PatMode,
     props::ComputedPropMode,
+    relation::RelationCache,
     scope::{Scope, VarKind},
     util::ResultExt,
 };
@@ -67,6 +68,7 @@ mod hoisting;
 mod import;
 mod pat;
 mod props;
+mod query;
 mod relation;
 mod scope;
 mod stmt;
@@ -300,10 +302,20 @@ struct AnalyzerData {
 
     cache: TypeCache,
 
+    /// Results of [Analyzer::is_type_related_to].
+    relation_cache: RelationCache,
+
     checked_for_async_iterator: bool,
 
     /// Used to check mixed default exports.
     merged_default_exports: AHashSet<Id>,
+
+    /// `/** @jsxImportSource foo */` pragmas of modules.
+    jsx_import_sources: FxHashMap<ModuleId, JsWord>,
+
+    /// Variables of `for (const k in arr)`, where `arr` is an array. Like
+    /// `tsc`, `arr[k]` is treated as an access using a numeric key.
+    for_in_vars_with_numeric_keys: FxHashSet<Id>,
 }
 
 /// Configuration for the analyzer.
@@ -332,6 +344,19 @@ struct PerModuleData {
 //    }
 //}
 
+impl Analyzer<'_, '_> {
+    /// Stores the `@jsxImportSource` pragma of the module starting at `span`.
+    fn find_jsx_import_source(&mut self, ctxt: ModuleId, span: Span) {
+        if self.config.is_builtin {
+            return;
+        }
+
+        if let Some(source) = find_jsx_import_source_in_comments(&self.comments, span) {
+            self.data.jsx_import_sources.insert(ctxt, source);
+        }
+    }
+}
+
 fn make_module_ty(span: Span, name: RTsModuleName, exports: ModuleTypeData) -> ty::Module {
     ty::Module {
         span,
@@ -738,6 +763,11 @@ impl Analyzer<'_, '_> {
 
         let mut items = vec![];
         for m in modules {
+            if !m.body.is_empty() {
+                let ctxt = self.storage.module_id(items.len());
+                self.find_jsx_import_source(ctxt, m.span);
+            }
+
             items.extend(&m.body);
         }
         // TODO: Pass spans.
@@ -764,6 +794,7 @@ impl Analyzer<'_, '_> {
 
         let items_ref = m.body.iter().collect::<Vec<_>>();
         self.load_normal_imports(vec![(ctxt, m.span)], &items_ref);
+        self.find_jsx_import_source(ctxt, m.span);
 
         self.fill_known_type_names(&m.body);
 
@@ -885,20 +916,7 @@ impl Analyzer<'_, '_> {
                             .cloned()
                             .unwrap_or_else(|| Type::any(e.span, Default::default()));
 
-                        let module_ty = analyzer
-                            .access_property(
-                                node.span,
-                                &module_ty,
-                                &Key::Normal {
-                                    span: node.span,
-                                    sym: js_word!("default"),
-                                },
-                                expr::TypeOfMode::RValue,
-                                IdCtx::Type,
-                                Default::default(),
-                            )
-                            .unwrap_or(module_ty)
-                            .freezed();
+                        let module_ty = analyzer.export_equals_or_module(node.span, module_ty, IdCtx::Type);
 
                         (Ok(module_ty.clone()), Ok(module_ty))
                     } else {
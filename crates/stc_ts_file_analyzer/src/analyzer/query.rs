@@ -0,0 +1,69 @@
+//! Queries for embedders, which run on an analyzer which validated a module.
+
+use std::borrow::Cow;
+
+use stc_ts_types::{ModuleTypeData, Type, TypeElement};
+use swc_common::{Span, Spanned};
+
+use crate::{
+    analyzer::{assign::AssignOpts, scope::VarKind, Analyzer, ResultExt},
+    VResult,
+};
+
+impl Analyzer<'_, '_> {
+    /// Declares the variables and types of a module which is already
+    /// analyzed, so that queries can use them without validating the module
+    /// again.
+    pub fn declare_module_items(&mut self, data: &ModuleTypeData) {
+        self.ctx.module_id = self.storage.module_id(0);
+
+        for (id, types) in &data.private_types {
+            for ty in types {
+                self.register_type(id.clone(), ty.clone());
+            }
+        }
+
+        // Kinds of variables are not stored, but it does not matter for
+        // queries.
+        for (id, ty) in &data.private_vars {
+            self.declare_var(
+                ty.span(),
+                VarKind::Import,
+                id.clone(),
+                Some(ty.clone()),
+                None,
+                true,
+                true,
+                false,
+                false,
+            )
+            .report(&mut self.storage);
+        }
+    }
+
+    /// Returns `Ok(())` if `right` is assignable to `left`, or the error
+    /// describing why it's not.
+    ///
+    /// `span` should not be dummy.
+    pub fn is_assignable(&mut self, span: Span, left: &Type, right: &Type) -> VResult<()> {
+        self.assign_with_opts(
+            &mut Default::default(),
+            left,
+            right,
+            AssignOpts {
+                span,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Returns the properties, methods, call signatures and index signatures
+    /// of `ty`, or [None] if `ty` is not an object-like type.
+    ///
+    /// `span` should not be dummy.
+    pub fn type_members(&mut self, span: Span, ty: &Type) -> VResult<Option<Vec<TypeElement>>> {
+        Ok(self
+            .convert_type_to_type_lit(span, Cow::Borrowed(ty))?
+            .map(|lit| lit.into_owned().members))
+    }
+}
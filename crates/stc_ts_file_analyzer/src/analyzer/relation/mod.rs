@@ -1,10 +1,27 @@
-#![allow(unused)]
+use std::borrow::Cow;
 
-use stc_ts_types::Type;
-use swc_common::TypeEq;
-use swc_ecma_ast::TsKeywordTypeKind;
+use fxhash::FxHashMap;
+use stc_ts_ast_rnode::{RBindingIdent, RIdent, RPat, RTsEntityName, RTsEnumMemberId, RTsLit};
+use stc_ts_generics::ExpandGenericOpts;
+use stc_ts_type_cache::{cache_mode::CacheMode, NoRefInKey};
+use stc_ts_types::{
+    Array, EnumVariant, FnParam, Function, Key, KeywordType, LitType, Readonly, Ref, Tuple, Type, TypeElement, TypeLit, TypeParam,
+    TypeParamInstantiation,
+};
+use stc_utils::cache::Freeze;
+use swc_atoms::js_word;
+use swc_common::{Span, Spanned, TypeEq};
+use swc_ecma_ast::{Accessibility, TsKeywordTypeKind};
 
-use super::Analyzer;
+use super::{types::NormalizeTypeOpts, Analyzer};
+
+#[cfg(test)]
+mod tests;
+
+/// Maximum depth of nested structured comparisons.
+///
+/// Like `tsc`, types nested deeper than this are considered unrelated.
+const MAX_DEPTH: usize = 100;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum Relation {
@@ -15,17 +32,130 @@ pub(crate) enum Relation {
     StrictSubtype,
 }
 
+/// Ported from `Ternary` of `tsc`.
+///
+/// The order of variants matters, as [Ternary::and] takes the minimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Ternary {
+    False,
+    Maybe,
+    True,
+}
+
+impl Ternary {
+    #[inline]
+    fn and(self, other: Ternary) -> Ternary {
+        self.min(other)
+    }
+
+    #[inline]
+    fn is_true(self) -> bool {
+        self != Ternary::False
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignatureKind {
+    Call,
+    Construct,
+}
+
+impl From<bool> for Ternary {
+    #[inline]
+    fn from(v: bool) -> Self {
+        if v {
+            Ternary::True
+        } else {
+            Ternary::False
+        }
+    }
+}
+
+/// Cache of the results of [Analyzer::is_type_related_to].
+///
+/// Like `tsc`, which uses ids of types, pairs are keyed by the identity of
+/// frozen types. Types which are frozen only for a comparison get a new
+/// address each time, so they are not cached. Pairs containing a [Type::Ref]
+/// are never cached because a reference may resolve differently in another
+/// scope.
+#[derive(Debug, Default)]
+pub(crate) struct RelationCache {
+    /// Types are stored with the result so their addresses are not reused.
+    data: FxHashMap<(Relation, usize, usize), (Type, Type, bool)>,
+}
+
+impl RelationCache {
+    fn get(&self, relation: Relation, source: &Type, target: &Type) -> Option<bool> {
+        let key = (relation, type_id(source)?, type_id(target)?);
+
+        self.data.get(&key).map(|(.., v)| *v)
+    }
+
+    fn insert(&mut self, relation: Relation, source: &Type, target: &Type, value: bool) {
+        let key = match (type_id(source), type_id(target)) {
+            (Some(s), Some(t)) => (relation, s, t),
+            _ => return,
+        };
+
+        if !<NoRefInKey as CacheMode<Type>>::can_cache(source) || !<NoRefInKey as CacheMode<Type>>::can_cache(target) {
+            return;
+        }
+
+        self.data.insert(key, (source.clone(), target.clone(), value));
+    }
+}
+
+/// Returns the address of the shared type if `ty` is frozen.
+fn type_id(ty: &Type) -> Option<usize> {
+    match ty {
+        Type::Arc(..) => Some(ty.normalize() as *const Type as usize),
+        _ => None,
+    }
+}
+
+/// State of a single call to [Analyzer::is_type_related_to].
 #[derive(Debug, Default)]
-struct IsRelatedData {}
+struct IsRelatedData {
+    /// Pairs currently being compared. This is `maybeKeys` of `tsc`.
+    ///
+    /// If a pair is found again while comparing it, we are comparing a
+    /// recursive type and the result is assumed to be [Ternary::Maybe].
+    maybe_stack: Vec<(Type, Type)>,
+
+    /// `true` if [MAX_DEPTH] is reached. Results depend on the depth in this
+    /// case, so they are not cached.
+    overflow: bool,
+
+    /// Span of the outermost comparison, used for types with a dummy span,
+    /// like types from builtin libraries.
+    span: Span,
+}
+
+impl IsRelatedData {
+    fn span_of(&self, ty: &Type) -> Span {
+        let span = ty.span();
+        if span.is_dummy() {
+            self.span
+        } else {
+            span
+        }
+    }
+}
 
 impl Analyzer<'_, '_> {
     /// Ported from `isTypeRelatedTo` of `tsc`.
     pub(crate) fn is_type_related_to(&mut self, source: &Type, target: &Type, relation: Relation) -> bool {
-        let mut data = IsRelatedData::default();
+        let mut data = IsRelatedData {
+            span: if source.span().is_dummy() { target.span() } else { source.span() },
+            ..Default::default()
+        };
         self.is_type_related_to_inner(&mut data, source, target, relation)
     }
 
     fn is_type_related_to_inner(&mut self, data: &mut IsRelatedData, source: &Type, target: &Type, relation: Relation) -> bool {
+        let source = source.normalize();
+        let target = target.normalize();
+
         if source.type_eq(target) {
             return true;
         }
@@ -38,19 +168,23 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        if source.is_structured_or_instantiable() || target.is_structured_or_instantiable() {
-            return self.check_type_related_to(source, target, relation);
+        if is_structured(source) || is_structured(target) {
+            // Types can't be resolved without a span, and types from builtin
+            // declarations don't have one.
+            if data.span.is_dummy() {
+                return false;
+            }
+
+            return self.check_type_related_to(data, source, target, relation);
         }
 
         false
     }
 
-    /// TODO: Implement
-    ///
     /// Ported from `isSimpleTypeRelatedTo` of `tsc`.
     #[allow(clippy::nonminimal_bool)]
     fn is_simple_type_related_to(&mut self, source: &Type, target: &Type, relation: Relation) -> bool {
-        let (s, t) = (source, target);
+        let (s, t) = (source.normalize(), target.normalize());
 
         if t.is_any() || t.is_unknown() || s.is_never() {
             return true;
@@ -64,21 +198,23 @@ impl Analyzer<'_, '_> {
             return true;
         }
 
-        // TODO
-        // if (s & TypeFlags.StringLiteral && s & TypeFlags.EnumLiteral &&
-        //     t & TypeFlags.StringLiteral && !(t & TypeFlags.EnumLiteral) &&
-        //     (source as StringLiteralType).value === (target as
-        // StringLiteralType).value) return true;
+        // String enum literal to the string literal with the same value.
+        if let (Some(Type::Lit(sl)), Type::Lit(tl)) = (enum_literal(s).as_deref(), t) {
+            if matches!(sl.lit, RTsLit::Str(..)) && matches!(tl.lit, RTsLit::Str(..)) && sl.type_eq(tl) {
+                return true;
+            }
+        }
 
         if s.is_num_like() && t.is_kwd(TsKeywordTypeKind::TsNumberKeyword) {
             return true;
         }
 
-        // TODO
-        // if (s & TypeFlags.NumberLiteral && s & TypeFlags.EnumLiteral &&
-        //     t & TypeFlags.NumberLiteral && !(t & TypeFlags.EnumLiteral) &&
-        //     (source as NumberLiteralType).value === (target as
-        // NumberLiteralType).value) return true;
+        // Numeric enum literal to the number literal with the same value.
+        if let (Some(Type::Lit(sl)), Type::Lit(tl)) = (enum_literal(s).as_deref(), t) {
+            if matches!(sl.lit, RTsLit::Number(..)) && matches!(tl.lit, RTsLit::Number(..)) && sl.type_eq(tl) {
+                return true;
+            }
+        }
 
         if s.is_bigint_like() && t.is_kwd(TsKeywordTypeKind::TsBigIntKeyword) {
             return true;
@@ -92,19 +228,19 @@ impl Analyzer<'_, '_> {
             return true;
         }
 
-        // TODO
-        // if (s & TypeFlags.Enum && t & TypeFlags.Enum && source.symbol.escapedName ===
-        // target.symbol.escapedName &&     isEnumTypeRelatedTo(source.symbol,
-        // target.symbol, errorReporter)) return true;
-
-        // TODO
-        // if (s & TypeFlags.EnumLiteral && t & TypeFlags.EnumLiteral) {
-        //     if (s & TypeFlags.Union && t & TypeFlags.Union &&
-        // isEnumTypeRelatedTo(source.symbol, target.symbol, errorReporter)) return
-        // true;     if (s & TypeFlags.Literal && t & TypeFlags.Literal &&
-        // (source as LiteralType).value === (target as LiteralType).value &&
-        //         isEnumTypeRelatedTo(source.symbol, target.symbol, errorReporter))
-        // return true; }
+        // Both are the same enum, or variants of the same enum.
+        if let (Type::EnumVariant(se), Type::EnumVariant(te)) = (s, t) {
+            if is_enum_type_related_to(se, te) {
+                if te.name.is_none() || se.name == te.name {
+                    return true;
+                }
+            }
+        }
+        if let (Type::EnumVariant(se), Type::Enum(te)) = (s, t) {
+            if se.def.id == te.id {
+                return true;
+            }
+        }
 
         // In non-strictNullChecks mode, `undefined` and `null` are assignable to
         // anything except `never`. Since unions and intersections may reduce to
@@ -120,10 +256,12 @@ impl Analyzer<'_, '_> {
             return true;
         }
 
-        // TODO
-        // if (s & TypeFlags.Object && t & TypeFlags.NonPrimitive && !(relation ===
-        // strictSubtypeRelation && isEmptyAnonymousObjectType(source) &&
-        // !(getObjectFlags(source) & ObjectFlags.FreshLiteral))) return true;
+        if is_object_like(s)
+            && t.is_kwd(TsKeywordTypeKind::TsObjectKeyword)
+            && !(relation == Relation::StrictSubtype && is_empty_anonymous_object_type(s))
+        {
+            return true;
+        }
 
         if relation == Relation::Assignable || relation == Relation::Comparable {
             if s.is_any() {
@@ -136,32 +274,859 @@ impl Analyzer<'_, '_> {
             // enum literal type with a matching value. These rules
             // exist such that enums can be used for bit-flag purposes.
 
-            // TODO
-            // if (s & TypeFlags.Number && (t & TypeFlags.Enum || t &
-            // TypeFlags.NumberLiteral && t & TypeFlags.EnumLiteral)) return
-            // true;
+            if s.is_kwd(TsKeywordTypeKind::TsNumberKeyword) && is_numeric_enum(t) {
+                return true;
+            }
 
-            // TODO
-            // if (s & TypeFlags.NumberLiteral && !(s & TypeFlags.EnumLiteral)
-            // && (t & TypeFlags.Enum ||     t & TypeFlags.
-            // NumberLiteral && t & TypeFlags.EnumLiteral &&
-            //     (source as NumberLiteralType).value === (target as
-            // NumberLiteralType).value)) return true;
+            if s.is_num_lit() && is_numeric_enum(t) {
+                match enum_literal(t) {
+                    Some(tl) => {
+                        if s.type_eq(&*tl) {
+                            return true;
+                        }
+                    }
+                    None => return true,
+                }
+            }
 
             // Anything is assignable to a union containing undefined, null, and
             // {}
 
-            // TODO
-            // if (isUnknownLikeUnionType(target)) return true;
+            if self.is_unknown_like_union_type(t) {
+                return true;
+            }
         }
 
         false
     }
 
-    /// TODO: Implement
-    ///
+    /// Ported from `isUnknownLikeUnionType` of `tsc`.
+    fn is_unknown_like_union_type(&mut self, ty: &Type) -> bool {
+        if !self.rule().strict_null_checks {
+            return false;
+        }
+
+        match ty.normalize() {
+            Type::Union(u) => {
+                u.types.len() >= 3
+                    && u.types.iter().any(|ty| ty.is_undefined())
+                    && u.types.iter().any(|ty| ty.is_null())
+                    && u.types.iter().any(|ty| is_empty_anonymous_object_type(ty.normalize()))
+            }
+            _ => false,
+        }
+    }
+
     /// Ported from `checkTypeRelatedTo` of `tsc`.
-    fn check_type_related_to(&mut self, source: &Type, target: &Type, relation: Relation) -> bool {
-        false
+    fn check_type_related_to(&mut self, data: &mut IsRelatedData, source: &Type, target: &Type, relation: Relation) -> bool {
+        self.is_related_to(data, source, target, relation).is_true()
+    }
+
+    /// Ported from `isRelatedTo` of `tsc`.
+    fn is_related_to(&mut self, data: &mut IsRelatedData, source: &Type, target: &Type, relation: Relation) -> Ternary {
+        let (source, source_shared) = match self.normalize_for_relation(data.span_of(source), source) {
+            Some(v) => v,
+            None => return Ternary::False,
+        };
+        let (target, target_shared) = match self.normalize_for_relation(data.span_of(target), target) {
+            Some(v) => v,
+            None => return Ternary::False,
+        };
+        let (s, t) = (source.normalize(), target.normalize());
+
+        if s.type_eq(t) {
+            return Ternary::True;
+        }
+
+        if relation == Relation::Identity {
+            if std::mem::discriminant(s) != std::mem::discriminant(t) {
+                return Ternary::False;
+            }
+
+            if matches!(s, Type::Keyword(..) | Type::Lit(..)) {
+                return Ternary::False;
+            }
+        } else {
+            if relation == Relation::Comparable && !t.is_never() && self.is_simple_type_related_to(t, s, relation)
+                || self.is_simple_type_related_to(s, t, relation)
+            {
+                return Ternary::True;
+            }
+        }
+
+        if relation != Relation::Identity {
+            if let Type::TypeLit(sl) = s {
+                if self.has_excess_properties(data, sl, t) {
+                    return Ternary::False;
+                }
+            }
+        }
+
+        if let Type::Union(su) = s {
+            return if relation == Relation::Comparable {
+                self.some_type_related_to_type(data, &su.types, t, relation)
+            } else {
+                self.each_type_related_to_type(data, &su.types, t, relation)
+            };
+        }
+
+        if let Type::Union(tu) = t {
+            return self.type_related_to_some_type(data, s, &tu.types, relation);
+        }
+
+        if let Type::Intersection(ti) = t {
+            return self.type_related_to_each_type(data, s, &ti.types, relation);
+        }
+
+        if let Type::Intersection(si) = s {
+            if relation == Relation::Identity {
+                return Ternary::False;
+            }
+
+            // Check to see if any constituents of the intersection are immediately
+            // related to the target.
+            return self.some_type_related_to_type(data, &si.types, t, relation);
+        }
+
+        if !is_structured(s) && !is_structured(t) {
+            return Ternary::False;
+        }
+
+        self.recursive_type_related_to(data, &source, &target, relation, source_shared && target_shared)
+    }
+
+    /// Ported from `hasExcessProperties` of `tsc`.
+    ///
+    /// Only fresh object literals are checked, and a property is known if any
+    /// constituent of `target` has it.
+    fn has_excess_properties(&mut self, data: &mut IsRelatedData, source: &TypeLit, target: &Type) -> bool {
+        if source.metadata.inexact || source.metadata.specified {
+            return false;
+        }
+
+        let targets = match target.normalize() {
+            Type::Union(u) => &u.types[..],
+            Type::Intersection(i) => &i.types[..],
+            _ => std::slice::from_ref(target),
+        };
+
+        let mut known = vec![];
+        for target in targets {
+            match target.normalize() {
+                // Everything is assignable to `Object`.
+                Type::Interface(i) if i.name.sym() == "Object" => return false,
+                ty if is_object_like(ty) || needs_resolution(ty) => {}
+                _ => return false,
+            }
+
+            match self.members_of(data, target) {
+                Some(lit) if !lit.members.is_empty() && !lit.members.iter().any(|m| matches!(m, TypeElement::Index(..))) => {
+                    known.push(lit.into_owned())
+                }
+                _ => return false,
+            }
+        }
+
+        source.members.iter().any(|sm| match sm {
+            TypeElement::Property(p) => known.iter().all(|lit| find_member(&lit.members, &p.key).is_none()),
+            _ => false,
+        })
+    }
+
+    /// Expands references, aliases and queries so that the structure of a
+    /// type can be compared.
+    ///
+    /// The returned boolean is `true` if the type was already frozen, which
+    /// means that its address is stable across comparisons.
+    fn normalize_for_relation<'a>(&mut self, span: Span, ty: &'a Type) -> Option<(Cow<'a, Type>, bool)> {
+        let mut ty = self
+            .normalize(
+                Some(span),
+                Cow::Borrowed(ty),
+                NormalizeTypeOpts {
+                    preserve_global_this: true,
+                    preserve_union: true,
+                    preserve_intersection: true,
+                    ..Default::default()
+                },
+            )
+            .ok()?;
+        let shared = matches!(*ty, Type::Arc(..));
+        ty.freeze();
+        Some((ty, shared))
+    }
+
+    /// Ported from `eachTypeRelatedToType` of `tsc`.
+    fn each_type_related_to_type(&mut self, data: &mut IsRelatedData, sources: &[Type], target: &Type, relation: Relation) -> Ternary {
+        let mut result = Ternary::True;
+        for source in sources {
+            let related = self.is_related_to(data, source, target, relation);
+            if related == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(related);
+        }
+        result
+    }
+
+    /// Ported from `someTypeRelatedToType` of `tsc`.
+    fn some_type_related_to_type(&mut self, data: &mut IsRelatedData, sources: &[Type], target: &Type, relation: Relation) -> Ternary {
+        let mut result = Ternary::False;
+        for source in sources {
+            let related = self.is_related_to(data, source, target, relation);
+            if related == Ternary::True {
+                return Ternary::True;
+            }
+            result = result.max(related);
+        }
+        result
+    }
+
+    /// Ported from `typeRelatedToSomeType` of `tsc`.
+    fn type_related_to_some_type(&mut self, data: &mut IsRelatedData, source: &Type, targets: &[Type], relation: Relation) -> Ternary {
+        if targets.iter().any(|t| t.type_eq(source)) {
+            return Ternary::True;
+        }
+
+        let mut result = Ternary::False;
+        for target in targets {
+            let related = self.is_related_to(data, source, target, relation);
+            if related == Ternary::True {
+                return Ternary::True;
+            }
+            result = result.max(related);
+        }
+        result
+    }
+
+    /// Ported from `typeRelatedToEachType` of `tsc`.
+    fn type_related_to_each_type(&mut self, data: &mut IsRelatedData, source: &Type, targets: &[Type], relation: Relation) -> Ternary {
+        let mut result = Ternary::True;
+        for target in targets {
+            let related = self.is_related_to(data, source, target, relation);
+            if related == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(related);
+        }
+        result
+    }
+
+    /// Ported from `recursiveTypeRelatedTo` of `tsc`.
+    ///
+    /// Handles the cache and the `maybe` stack used to terminate comparison of
+    /// recursive types.
+    ///
+    /// The result is cached only if `cacheable` is `true`, which means that
+    /// `source` and `target` were frozen before the comparison.
+    fn recursive_type_related_to(
+        &mut self,
+        data: &mut IsRelatedData,
+        source: &Type,
+        target: &Type,
+        relation: Relation,
+        cacheable: bool,
+    ) -> Ternary {
+        if cacheable {
+            if let Some(v) = self.data.relation_cache.get(relation, source, target) {
+                return v.into();
+            }
+        }
+
+        let (s, t) = (source.normalize(), target.normalize());
+
+        if data.maybe_stack.iter().any(|(ms, mt)| ms.type_eq(s) && mt.type_eq(t)) {
+            return Ternary::Maybe;
+        }
+
+        if data.maybe_stack.len() >= MAX_DEPTH {
+            data.overflow = true;
+            return Ternary::False;
+        }
+
+        data.maybe_stack.push((source.clone().freezed(), target.clone().freezed()));
+        let result = self.structured_type_related_to(data, s, t, relation);
+        data.maybe_stack.pop();
+
+        // A `Maybe` result depends on pairs still in the stack, so it can be cached
+        // only when we are back at the outermost comparison.
+        if cacheable && !data.overflow && (result != Ternary::Maybe || data.maybe_stack.is_empty()) {
+            self.data.relation_cache.insert(relation, source, target, result.is_true());
+        }
+
+        result
+    }
+
+    /// Ported from `structuredTypeRelatedTo` of `tsc`.
+    fn structured_type_related_to(&mut self, data: &mut IsRelatedData, source: &Type, target: &Type, relation: Relation) -> Ternary {
+        match (source, target) {
+            (Type::Keyword(KeywordType { kind: sk, .. }), Type::Keyword(KeywordType { kind: tk, .. })) => return (sk == tk).into(),
+
+            (Type::Lit(..), Type::Lit(..)) => return Ternary::False,
+
+            (Type::Param(TypeParam { name: sn, .. }), Type::Param(TypeParam { name: tn, .. })) if sn == tn => return Ternary::True,
+
+            (Type::Readonly(Readonly { ty: s, .. }), Type::Readonly(Readonly { ty: t, .. })) => {
+                return self.is_related_to(data, s, t, relation);
+            }
+
+            (Type::Array(Array { elem_type: s, .. }), Type::Array(Array { elem_type: t, .. })) => {
+                return self.is_related_to(data, s, t, relation);
+            }
+
+            (Type::Tuple(Tuple { elems: s, .. }), Type::Tuple(Tuple { elems: t, .. })) => {
+                if s.len() != t.len() {
+                    return Ternary::False;
+                }
+
+                let mut result = Ternary::True;
+                for (s, t) in s.iter().zip(t.iter()) {
+                    let related = self.is_related_to(data, &s.ty, &t.ty, relation);
+                    if related == Ternary::False {
+                        return Ternary::False;
+                    }
+                    result = result.and(related);
+                }
+                return result;
+            }
+
+            _ => {}
+        }
+
+        if relation == Relation::Identity {
+            return self.identical_members(data, source, target);
+        }
+
+        match source {
+            // A type parameter is related to a target if its constraint is.
+            Type::Param(TypeParam {
+                constraint: Some(constraint),
+                ..
+            }) => {
+                let related = self.is_related_to(data, constraint, target, relation);
+                if related.is_true() {
+                    return related;
+                }
+            }
+
+            Type::Tuple(Tuple { elems, .. }) => {
+                if let Type::Array(Array { elem_type, .. }) = target {
+                    let mut result = Ternary::True;
+                    for elem in elems {
+                        let related = self.is_related_to(data, &elem.ty, elem_type, relation);
+                        if related == Ternary::False {
+                            return Ternary::False;
+                        }
+                        result = result.and(related);
+                    }
+                    return result;
+                }
+            }
+
+            _ => {}
+        }
+
+        let (source, target) = match (self.members_of(data, source), self.members_of(data, target)) {
+            (Some(s), Some(t)) => (s, t),
+            _ => return Ternary::False,
+        };
+
+        let mut result = self.properties_related_to(data, &source, &target, relation);
+        if result == Ternary::False {
+            return Ternary::False;
+        }
+        for kind in [SignatureKind::Call, SignatureKind::Construct] {
+            result = result.and(self.signatures_related_to(data, &source, &target, kind, relation));
+            if result == Ternary::False {
+                return Ternary::False;
+            }
+        }
+        result.and(self.index_signatures_related_to(data, &source, &target, relation))
+    }
+
+    /// Compares members of object types for [Relation::Identity].
+    fn identical_members(&mut self, data: &mut IsRelatedData, source: &Type, target: &Type) -> Ternary {
+        let (source, target) = match (self.members_of(data, source), self.members_of(data, target)) {
+            (Some(s), Some(t)) => (s, t),
+            _ => return Ternary::False,
+        };
+
+        if source.members.len() != target.members.len() {
+            return Ternary::False;
+        }
+
+        let mut result = Ternary::True;
+        for tm in &target.members {
+            let related = match tm {
+                TypeElement::Property(tp) => match find_member(&source.members, &tp.key) {
+                    Some(sm @ TypeElement::Property(sp)) if sp.optional == tp.optional && sp.readonly == tp.readonly => {
+                        self.is_related_to(data, &member_type(sm), &member_type(tm), Relation::Identity)
+                    }
+                    _ => Ternary::False,
+                },
+                _ => source.members.iter().any(|sm| sm.type_eq(tm)).into(),
+            };
+            if related == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(related);
+        }
+        result
+    }
+
+    /// Ported from `propertiesRelatedTo` of `tsc`.
+    ///
+    /// Methods are compared like properties with function types, but their
+    /// parameters are always compared bivariantly.
+    fn properties_related_to(&mut self, data: &mut IsRelatedData, source: &TypeLit, target: &TypeLit, relation: Relation) -> Ternary {
+        let mut result = Ternary::True;
+
+        for tm in &target.members {
+            let (key, optional) = match tm {
+                TypeElement::Property(p) => (&p.key, p.optional),
+                TypeElement::Method(m) => (&m.key, m.optional),
+                _ => continue,
+            };
+
+            let related = match find_member(&source.members, key) {
+                Some(sm) => {
+                    if relation == Relation::StrictSubtype && is_optional(sm) && !optional || !is_accessibility_related(sm, tm) {
+                        Ternary::False
+                    } else {
+                        let source_ty = member_type(sm);
+                        match tm {
+                            TypeElement::Method(..) => self.method_related_to(data, &source_ty, &member_type(tm), relation),
+                            _ => self.is_related_to(data, &source_ty, &member_type(tm), relation),
+                        }
+                    }
+                }
+                None => optional.into(),
+            };
+
+            if related == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(related);
+        }
+
+        result
+    }
+
+    /// Compares the call signatures of `source` with the signatures of the
+    /// method type `target`, without strict variance.
+    fn method_related_to(&mut self, data: &mut IsRelatedData, source: &Type, target: &Type, relation: Relation) -> Ternary {
+        let (source, target) = match (self.members_of(data, source), self.members_of(data, target)) {
+            (Some(s), Some(t)) => (s, t),
+            _ => return self.is_related_to(data, source, target, relation),
+        };
+
+        let sources = signatures_of(&source, SignatureKind::Call);
+        let targets = signatures_of(&target, SignatureKind::Call);
+
+        self.each_signature_related_to(data, &sources, &targets, relation, false)
+    }
+
+    /// Ported from `signaturesRelatedTo` of `tsc`.
+    fn signatures_related_to(
+        &mut self,
+        data: &mut IsRelatedData,
+        source: &TypeLit,
+        target: &TypeLit,
+        kind: SignatureKind,
+        relation: Relation,
+    ) -> Ternary {
+        let sources = signatures_of(source, kind);
+        let targets = signatures_of(target, kind);
+
+        // Like `tsc`, parameters of constructors are compared bivariantly.
+        let strict_variance = kind == SignatureKind::Call && self.rule().strict_function_types;
+
+        self.each_signature_related_to(data, &sources, &targets, relation, strict_variance)
+    }
+
+    /// Each signature in `targets` should be related to some signature in
+    /// `sources`.
+    fn each_signature_related_to(
+        &mut self,
+        data: &mut IsRelatedData,
+        sources: &[Function],
+        targets: &[Function],
+        relation: Relation,
+        strict_variance: bool,
+    ) -> Ternary {
+        let mut result = Ternary::True;
+        'targets: for t in targets {
+            let mut best = Ternary::False;
+            for s in sources {
+                let related = self.signature_related_to(data, s, t, relation, strict_variance);
+                if related == Ternary::True {
+                    continue 'targets;
+                }
+                best = best.max(related);
+            }
+
+            if best == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(best);
+        }
+        result
+    }
+
+    /// Ported from `signatureRelatedTo` and `compareSignaturesRelated` of
+    /// `tsc`.
+    ///
+    /// Generic signatures are erased before comparison.
+    fn signature_related_to(
+        &mut self,
+        data: &mut IsRelatedData,
+        source: &Function,
+        target: &Function,
+        relation: Relation,
+        strict_variance: bool,
+    ) -> Ternary {
+        let (source, target) = match (self.erase_signature(source), self.erase_signature(target)) {
+            (Some(s), Some(t)) => (s, t),
+            _ => return Ternary::False,
+        };
+
+        let source_params = source.params.iter().filter(|p| !is_this_param(p)).collect::<Vec<_>>();
+        let target_params = target.params.iter().filter(|p| !is_this_param(p)).collect::<Vec<_>>();
+
+        let source_has_rest = source_params.last().map_or(false, |p| is_rest_param(p));
+        let target_has_rest = target_params.last().map_or(false, |p| is_rest_param(p));
+
+        let source_min_count = source_params.iter().filter(|p| p.required && !is_rest_param(p)).count();
+        if !target_has_rest && source_min_count > target_params.len() {
+            return Ternary::False;
+        }
+
+        let count = if source_has_rest || target_has_rest {
+            source_params.len().min(target_params.len())
+        } else {
+            source_params.len().max(target_params.len())
+        };
+
+        let mut result = Ternary::True;
+        for i in 0..count {
+            let (s, t) = match (type_at_position(&source_params, i), type_at_position(&target_params, i)) {
+                (Some(s), Some(t)) => (s, t),
+                _ => continue,
+            };
+
+            let mut related = self.is_related_to(data, &t, &s, relation);
+            if related == Ternary::False && !strict_variance {
+                related = self.is_related_to(data, &s, &t, relation);
+            }
+            if related == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(related);
+        }
+
+        if target.ret_ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword) {
+            return result;
+        }
+
+        result.and(self.is_related_to(data, &source.ret_ty, &target.ret_ty, relation))
+    }
+
+    /// Ported from `getErasedSignature` of `tsc`, which replaces type
+    /// parameters with `any`.
+    fn erase_signature<'a>(&mut self, f: &'a Function) -> Option<Cow<'a, Function>> {
+        let type_params = match &f.type_params {
+            Some(v) => v,
+            None => return Some(Cow::Borrowed(f)),
+        };
+
+        let map = type_params
+            .params
+            .iter()
+            .map(|p| (p.name.clone(), Type::any(p.span, Default::default())))
+            .collect();
+
+        let f = Type::Function(Function {
+            type_params: None,
+            ..f.clone()
+        });
+
+        match self.expand_type_params(&map, f, ExpandGenericOpts { ..Default::default() }).ok()? {
+            Type::Function(f) => Some(Cow::Owned(f)),
+            _ => None,
+        }
+    }
+
+    /// Ported from `indexSignaturesRelatedTo` and `membersRelatedToIndexer`
+    /// of `tsc`.
+    fn index_signatures_related_to(&mut self, data: &mut IsRelatedData, source: &TypeLit, target: &TypeLit, relation: Relation) -> Ternary {
+        let mut result = Ternary::True;
+
+        for tm in &target.members {
+            let ti = match tm {
+                TypeElement::Index(ti) => ti,
+                _ => continue,
+            };
+            let target_ty = ti
+                .type_ann
+                .as_deref()
+                .cloned()
+                .unwrap_or_else(|| Type::any(ti.span, Default::default()));
+            let numeric = ti.params.first().map_or(false, |p| p.ty.is_kwd(TsKeywordTypeKind::TsNumberKeyword));
+
+            // Ported from `getApplicableIndexInfo`. A string index signature
+            // applies to numeric keys too.
+            let applicable = source.members.iter().find_map(|sm| match sm {
+                TypeElement::Index(si) => {
+                    let key = si.params.first().map(|p| &*p.ty);
+                    let applies = match key {
+                        Some(key) if key.is_kwd(TsKeywordTypeKind::TsStringKeyword) => true,
+                        Some(key) if key.is_kwd(TsKeywordTypeKind::TsNumberKeyword) => numeric,
+                        _ => false,
+                    };
+                    applies.then_some(si)
+                }
+                _ => None,
+            });
+
+            let related = match applicable {
+                Some(si) => match &si.type_ann {
+                    Some(s) => self.is_related_to(data, s, &target_ty, relation),
+                    None => Ternary::True,
+                },
+                None => {
+                    let mut result = Ternary::True;
+                    for sm in &source.members {
+                        let key = match sm {
+                            TypeElement::Property(p) => &p.key,
+                            TypeElement::Method(m) => &m.key,
+                            _ => continue,
+                        };
+                        if numeric && !is_numeric_key(key) {
+                            continue;
+                        }
+
+                        let related = self.is_related_to(data, &member_type(sm), &target_ty, relation);
+                        if related == Ternary::False {
+                            return Ternary::False;
+                        }
+                        result = result.and(related);
+                    }
+                    result
+                }
+            };
+
+            if related == Ternary::False {
+                return Ternary::False;
+            }
+            result = result.and(related);
+        }
+
+        result
+    }
+
+    /// Returns the members of `ty`, including the members of the apparent type
+    /// for primitives and arrays.
+    fn members_of<'a>(&mut self, data: &IsRelatedData, ty: &'a Type) -> Option<Cow<'a, TypeLit>> {
+        let span = data.span_of(ty);
+
+        let expanded = match ty.normalize() {
+            Type::Array(Array { elem_type, .. }) => Type::Ref(Ref {
+                span,
+                type_name: RTsEntityName::Ident(RIdent::new(js_word!("Array"), span)),
+                type_args: Some(box TypeParamInstantiation {
+                    span,
+                    params: vec![(**elem_type).clone()],
+                }),
+                metadata: Default::default(),
+                tracker: Default::default(),
+            }),
+            Type::Mapped(m) => self.expand_mapped(span, m).ok()??,
+            _ => return self.convert_type_to_type_lit(span, Cow::Borrowed(ty)).ok().flatten(),
+        };
+
+        self.convert_type_to_type_lit(span, Cow::Owned(expanded))
+            .ok()
+            .flatten()
+            .map(|ty| Cow::Owned(ty.into_owned()))
+    }
+}
+
+/// Finds a property or a method named `key`.
+fn find_member<'a>(members: &'a [TypeElement], key: &Key) -> Option<&'a TypeElement> {
+    members.iter().find(|m| match m {
+        TypeElement::Property(p) => p.key.type_eq(key),
+        TypeElement::Method(m) => m.key.type_eq(key),
+        _ => false,
+    })
+}
+
+fn is_optional(m: &TypeElement) -> bool {
+    match m {
+        TypeElement::Property(p) => p.optional,
+        TypeElement::Method(m) => m.optional,
+        _ => false,
+    }
+}
+
+/// Ported from the modifier checks of `propertyRelatedTo` of `tsc`.
+///
+/// Private members are related only if they come from the same declaration,
+/// and protected members are related only to protected members.
+fn is_accessibility_related(source: &TypeElement, target: &TypeElement) -> bool {
+    let accessibility = |m: &TypeElement| match m {
+        TypeElement::Property(p) => p.accessibility,
+        TypeElement::Method(m) => m.accessibility,
+        _ => None,
+    };
+
+    match (accessibility(source), accessibility(target)) {
+        (Some(Accessibility::Private), _) | (_, Some(Accessibility::Private)) => source.span() == target.span(),
+        (Some(Accessibility::Protected), t) | (t, Some(Accessibility::Protected)) => t == Some(Accessibility::Protected),
+        _ => true,
+    }
+}
+
+/// Returns the type of a property, or the function type of a method.
+fn member_type(m: &TypeElement) -> Type {
+    match m {
+        TypeElement::Property(p) => p
+            .type_ann
+            .as_deref()
+            .cloned()
+            .unwrap_or_else(|| Type::any(p.span, Default::default())),
+        TypeElement::Method(m) => Type::Function(Function {
+            span: m.span,
+            type_params: m.type_params.clone(),
+            params: m.params.clone(),
+            ret_ty: m.ret_ty.clone().unwrap_or_else(|| box Type::any(m.span, Default::default())),
+            metadata: Default::default(),
+            tracker: Default::default(),
+        }),
+        _ => Type::any(m.span(), Default::default()),
+    }
+}
+
+/// Returns call or construct signatures of `ty` as function types.
+fn signatures_of(ty: &TypeLit, kind: SignatureKind) -> Vec<Function> {
+    ty.members
+        .iter()
+        .filter_map(|m| {
+            let (span, type_params, params, ret_ty) = match (m, kind) {
+                (TypeElement::Call(c), SignatureKind::Call) => (c.span, &c.type_params, &c.params, &c.ret_ty),
+                (TypeElement::Constructor(c), SignatureKind::Construct) => (c.span, &c.type_params, &c.params, &c.ret_ty),
+                _ => return None,
+            };
+
+            Some(Function {
+                span,
+                type_params: type_params.clone(),
+                params: params.clone(),
+                ret_ty: ret_ty.clone().unwrap_or_else(|| box Type::any(span, Default::default())),
+                metadata: Default::default(),
+                tracker: Default::default(),
+            })
+        })
+        .collect()
+}
+
+fn is_this_param(p: &FnParam) -> bool {
+    matches!(
+        &p.pat,
+        RPat::Ident(RBindingIdent {
+            id: RIdent { sym: js_word!("this"), .. },
+            ..
+        })
+    )
+}
+
+fn is_rest_param(p: &FnParam) -> bool {
+    matches!(p.pat, RPat::Rest(..))
+}
+
+/// Ported from `tryGetTypeAtPosition` of `tsc`.
+fn type_at_position(params: &[&FnParam], pos: usize) -> Option<Type> {
+    let param = match params.get(pos) {
+        Some(p) if !is_rest_param(p) => return Some((*p.ty).clone()),
+        Some(p) => p,
+        None => params.last().filter(|p| is_rest_param(p))?,
+    };
+
+    match param.ty.normalize() {
+        Type::Array(Array { elem_type, .. }) => Some((**elem_type).clone()),
+        _ => None,
+    }
+}
+
+fn is_numeric_key(key: &Key) -> bool {
+    match key {
+        Key::Num(..) => true,
+        Key::Normal { sym, .. } => sym.parse::<f64>().is_ok(),
+        _ => false,
+    }
+}
+
+fn is_object_like(ty: &Type) -> bool {
+    matches!(
+        ty.normalize(),
+        Type::TypeLit(..)
+            | Type::Interface(..)
+            | Type::Class(..)
+            | Type::ClassDef(..)
+            | Type::Function(..)
+            | Type::Constructor(..)
+            | Type::Array(..)
+            | Type::Tuple(..)
+            | Type::Mapped(..)
+    )
+}
+
+/// Returns `true` if `ty` is compared using its structure.
+fn is_structured(ty: &Type) -> bool {
+    ty.is_structured_or_instantiable() || is_object_like(ty) || needs_resolution(ty)
+}
+
+/// Returns `true` if `ty` should be expanded before comparing its structure.
+fn needs_resolution(ty: &Type) -> bool {
+    matches!(
+        ty.normalize(),
+        Type::Ref(..) | Type::Query(..) | Type::Alias(..) | Type::Import(..) | Type::IndexedAccessType(..)
+    )
+}
+
+/// Ported from `isEmptyAnonymousObjectType` of `tsc`.
+fn is_empty_anonymous_object_type(ty: &Type) -> bool {
+    matches!(ty, Type::TypeLit(TypeLit { members, .. }) if members.is_empty())
+}
+
+fn is_numeric_enum(ty: &Type) -> bool {
+    match ty.normalize() {
+        Type::Enum(e) => e.has_num && !e.has_str,
+        Type::EnumVariant(EnumVariant { def, .. }) => def.has_num && !def.has_str,
+        _ => false,
+    }
+}
+
+/// Ported from `isEnumTypeRelatedTo` of `tsc`.
+///
+/// As we don't have symbols, an enum is only related to itself.
+fn is_enum_type_related_to(source: &EnumVariant, target: &EnumVariant) -> bool {
+    source.def.id == target.def.id
+}
+
+/// Returns the literal value of an enum member, if `ty` is a variant with
+/// a literal value.
+fn enum_literal(ty: &Type) -> Option<Cow<Type>> {
+    match ty.normalize() {
+        Type::EnumVariant(EnumVariant { def, name: Some(name), .. }) => def
+            .members
+            .iter()
+            .find(|m| match &m.id {
+                RTsEnumMemberId::Ident(i) => i.sym == *name,
+                RTsEnumMemberId::Str(s) => s.value == *name,
+            })
+            .map(|m| &*m.val)
+            .filter(|v| matches!(v.normalize(), Type::Lit(LitType { .. })))
+            .map(Cow::Borrowed),
+        _ => None,
     }
 }
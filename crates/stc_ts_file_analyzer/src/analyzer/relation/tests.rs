@@ -0,0 +1,156 @@
+use super::Relation;
+use crate::analyzer::tests::test_two;
+
+fn test_relation(l: &str, r: &str, relation: Relation, expected: bool) {
+    test_two(l, r, |analyzer, l, r| {
+        let res = analyzer.is_type_related_to(&l, &r, relation);
+
+        assert_eq!(
+            res,
+            expected,
+            "{:?} {} {:?} (relation: {:?})",
+            l,
+            if expected { "->" } else { "-/>" },
+            r,
+            relation
+        );
+    });
+}
+
+#[test]
+fn identity_keyword() {
+    test_relation("string", "string", Relation::Identity, true);
+    test_relation("string", "number", Relation::Identity, false);
+}
+
+#[test]
+fn identity_type_lit() {
+    test_relation("{ a: string }", "{ a: string }", Relation::Identity, true);
+    test_relation("{ a: string }", "{ a?: string }", Relation::Identity, false);
+    test_relation("{ a: string }", "{ a: string, b: number }", Relation::Identity, false);
+}
+
+#[test]
+fn assignable_literal() {
+    test_relation("'foo'", "string", Relation::Assignable, true);
+    test_relation("string", "'foo'", Relation::Assignable, false);
+}
+
+#[test]
+fn comparable_union() {
+    test_relation("'a' | 'b'", "'a'", Relation::Comparable, true);
+    test_relation("'a' | 'b'", "'c'", Relation::Comparable, false);
+}
+
+#[test]
+fn strict_subtype_optional_property() {
+    test_relation("{ a: string, b: number }", "{ a: string }", Relation::StrictSubtype, true);
+    test_relation("{ a?: string }", "{ a: string }", Relation::StrictSubtype, false);
+}
+
+#[test]
+fn recursive_type_lit() {
+    test_relation(
+        "{ next: { next: { value: string } } }",
+        "{ next: { next: { value: string | number } } }",
+        Relation::Assignable,
+        true,
+    );
+}
+
+#[test]
+fn unknown_like_union() {
+    test_relation("string", "{} | null | undefined", Relation::Assignable, true);
+}
+
+#[test]
+fn method_unknown_return() {
+    test_relation("{ f(): unknown }", "{ f(): string }", Relation::Assignable, false);
+    test_relation("{ f(): unknown }", "{ f(): string }", Relation::Comparable, true);
+}
+
+#[test]
+fn recursive_interface() {
+    test_relation(
+        "A; interface A { value: string; next: A }",
+        "B; interface B { value: string | number; next: B }",
+        Relation::Assignable,
+        true,
+    );
+    test_relation(
+        "A; interface A { value: string; next: A }",
+        "B; interface B { value: number; next: B }",
+        Relation::Assignable,
+        false,
+    );
+}
+
+#[test]
+fn recursive_alias() {
+    test_relation(
+        "{ value: string; next: L1 }; type L1 = { value: string; next: L1 }",
+        "{ value: string | number; next: L2 }; type L2 = { value: string | number; next: L2 }",
+        Relation::Assignable,
+        true,
+    );
+}
+
+#[test]
+fn function_params() {
+    test_relation("(a: string) => void", "(a: string, b: number) => void", Relation::Assignable, true);
+    test_relation("(a: string, b: number) => void", "(a: string) => void", Relation::Assignable, false);
+    test_relation("(a: string | number) => void", "(a: string) => void", Relation::Assignable, true);
+    test_relation("(a: string) => void", "(a: string | number) => void", Relation::Assignable, false);
+}
+
+#[test]
+fn method_params_are_bivariant() {
+    test_relation(
+        "{ f(a: string): void }",
+        "{ f(a: string | number): void }",
+        Relation::Assignable,
+        true,
+    );
+}
+
+#[test]
+fn function_return() {
+    test_relation("() => number", "() => string", Relation::Assignable, false);
+    test_relation("() => number", "() => void", Relation::Assignable, true);
+}
+
+#[test]
+fn generic_function() {
+    test_relation("<T>(a: T) => T", "(a: string) => string", Relation::Assignable, true);
+}
+
+#[test]
+fn construct_signature() {
+    test_relation(
+        "new () => { a: string }",
+        "new () => { a: string | number }",
+        Relation::Assignable,
+        true,
+    );
+    test_relation("new () => { a: string }", "new () => { a: number }", Relation::Assignable, false);
+    test_relation("() => { a: string }", "new () => { a: string }", Relation::Assignable, false);
+}
+
+#[test]
+fn index_signature() {
+    test_relation("{ [k: string]: number }", "{ [k: string]: string }", Relation::Assignable, false);
+    test_relation("{ a: number }", "{ [k: string]: number }", Relation::Assignable, true);
+    test_relation("{ a: number }", "{ [k: number]: string }", Relation::Assignable, true);
+    test_relation("{ 0: number }", "{ [k: number]: string }", Relation::Assignable, false);
+}
+
+#[test]
+fn array_apparent_type() {
+    test_relation("string[]", "{ length: number }", Relation::Assignable, true);
+}
+
+#[test]
+fn primitive_property() {
+    test_relation("{ a: string }", "{ a: 'foo' }", Relation::Assignable, false);
+    test_relation("{ a: 'foo' }", "{ a: string }", Relation::Assignable, true);
+}
@@ -15,7 +15,7 @@ use rnode::{Fold, FoldWith, VisitMut, VisitMutWith, VisitWith};
 use stc_ts_ast_rnode::{RObjectPat, RObjectPatProp, RPat, RTsEntityName, RTsQualifiedName};
 use stc_ts_errors::{
     debug::{dump_type_as_string, print_backtrace},
-    DebugExt, ErrorKind,
+    DebugExt, Error, ErrorKind,
 };
 use stc_ts_generics::ExpandGenericOpts;
 use stc_ts_type_ops::{expansion::ExpansionPreventer, union_finder::UnionFinder, Fix};
@@ -238,6 +238,17 @@ impl Scope<'_> {
         }
     }
 
+    pub fn get_elem_type_facts(&self, key: &(Name, Id)) -> TypeFacts {
+        if let Some(&f) = self.facts.elem_facts.get(key) {
+            return f;
+        }
+
+        match self.parent {
+            Some(parent) => parent.get_elem_type_facts(key),
+            _ => TypeFacts::None,
+        }
+    }
+
     pub fn is_declaring_fn(&self, id: &Id) -> bool {
         if let Some(d) = &self.declaring_fn {
             if *d == *id {
@@ -726,9 +737,13 @@ impl Analyzer<'_, '_> {
             expand_union: opts.expand_union,
             expand_top_level: true,
             opts,
+            instantiation_limit: None,
         };
 
         let ty = ty.foldable().fold_with(&mut v).fixed();
+        if let Some(err) = v.instantiation_limit {
+            return Err(err);
+        }
         ty.assert_valid();
 
         let new = dump_type_as_string(&ty);
@@ -2023,6 +2038,8 @@ struct Expander<'a, 'b, 'c> {
     /// Should we expand top level references?
     expand_top_level: bool,
     opts: ExpandOpts,
+    /// Set if the instantiation was too deep to finish.
+    instantiation_limit: Option<Error>,
 }
 
 impl Expander<'_, '_, '_> {
@@ -2282,6 +2299,13 @@ impl Expander<'_, '_, '_> {
             return Ok(None);
         }
 
+        // Expanding a generic type may expand the same reference again, so
+        // errors are reported at the reference.
+        let _instantiation = match type_args {
+            Some(..) => Some(stack::track_instantiation(r_span)?),
+            None => None,
+        };
+
         let mut ty = self.expand_ts_entity_name(span, &type_name, type_args.as_deref(), was_top_level, trying_primitive_expansion)?;
 
         if let Some(ty) = &mut ty {
@@ -2323,6 +2347,10 @@ impl Expander<'_, '_, '_> {
             Ok(v) => v,
             Err(..) => {
                 error!("[expander] Stack overflow: {}", dump_type_as_string(&ty));
+                if let Some(err) = stack::instantiation_limit_error() {
+                    self.instantiation_limit.get_or_insert_with(|| err.into());
+                    return Type::any(self.span, Default::default());
+                }
                 return ty;
             }
         };
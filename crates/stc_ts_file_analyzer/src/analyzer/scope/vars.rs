@@ -299,7 +299,7 @@ impl Analyzer<'_, '_> {
                             let elem_ty = ty
                                 .as_ref()
                                 .try_map(|ty| -> VResult<Type> {
-                                    let result = self.get_element_from_iterator(span, Cow::Borrowed(ty), idx).with_context(|| {
+                                    let result = self.get_element_for_array_pat(span, Cow::Borrowed(ty), idx).with_context(|| {
                                         format!(
                                             "tried to get the type of {}th element from iterator to declare vars with an array pattern",
                                             idx
@@ -2,7 +2,8 @@ use std::borrow::Cow;
 
 use rnode::VisitWith;
 use stc_ts_ast_rnode::{
-    RBool, RDoWhileStmt, RExpr, RForInStmt, RForOfStmt, RIdent, RLit, RPat, RStmt, RTsEntityName, RVarDecl, RVarDeclOrPat, RWhileStmt,
+    RBool, RDoWhileStmt, RExpr, RForInStmt, RForOfStmt, RIdent, RLit, RPat, RStmt, RTsEntityName, RVarDecl, RVarDeclOrPat, RVarDeclarator,
+    RWhileStmt,
 };
 use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_file_analyzer_macros::extra_validator;
@@ -377,6 +378,15 @@ impl Analyzer<'_, '_> {
                 }
             }
 
+            if let (ForHeadKind::In, RVarDeclOrPat::VarDecl(box RVarDecl { decls, .. })) = (kind, left) {
+                if let [RVarDeclarator { name: RPat::Ident(i), .. }] = &**decls {
+                    let rty = child.normalize(Some(span), Cow::Borrowed(&rty), Default::default());
+                    if matches!(rty, Ok(rty) if rty.is_array() || rty.is_tuple()) {
+                        child.data.for_in_vars_with_numeric_keys.insert(Id::from(&i.id));
+                    }
+                }
+            }
+
             let mut elem_ty = match kind {
                 ForHeadKind::Of { is_awaited: false } => child
                     .get_iterator_element_type(rhs.span(), Cow::Owned(rty), false, Default::default())
@@ -4,7 +4,7 @@ use rnode::VisitWith;
 use stc_ts_ast_rnode::{RBlockStmt, RBool, RDecl, RExpr, RExprStmt, RForStmt, RModuleItem, RStmt, RTsExprWithTypeArgs, RTsLit, RWithStmt};
 use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_types::{LitType, Type};
-use stc_utils::{dev_span, stack};
+use stc_utils::{dev_span, panic_context, stack};
 use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_utils::Value::Known;
 use tracing::{trace, warn};
@@ -26,6 +26,7 @@ mod var_decl;
 impl Analyzer<'_, '_> {
     fn validate(&mut self, i: &RModuleItem) {
         let _stack = stack::start(100);
+        stack::reset_instantiation_count();
 
         i.visit_children_with(self);
 
@@ -37,6 +38,7 @@ impl Analyzer<'_, '_> {
 impl Analyzer<'_, '_> {
     fn validate(&mut self, s: &RStmt) {
         let span = s.span();
+        panic_context::record_span(span);
         let line_col = self.line_col(span);
 
         let _tracing = dev_span!("Stmt", line_col = &*line_col);
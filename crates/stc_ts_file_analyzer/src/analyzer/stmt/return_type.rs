@@ -28,6 +28,7 @@ use crate::{
         Analyzer, Ctx,
     },
     ty::{Array, Type, TypeExt},
+    util::method_to_fn_type,
     validator,
     validator::ValidateWith,
     VResult,
@@ -671,30 +672,22 @@ impl Fold<Type> for KeyInliner<'_, '_, '_> {
                 if let Type::TypeLit(obj) = index_ty.foldable() {
                     let mut types: Vec<Type> = vec![];
                     for member in obj.members {
-                        match member {
-                            TypeElement::Call(_) => {
-                                unimplemented!("Call signature in S[keyof S]")
-                            }
-                            TypeElement::Constructor(_) => {
-                                unimplemented!("Constructor signature in S[keyof S]")
-                            }
-                            TypeElement::Property(p) => {
-                                if p.key.is_computed() {
-                                    unimplemented!("Computed key mixed with S[keyof S]")
-                                }
+                        let ty = match member {
+                            // Signatures are not accessible using a key.
+                            TypeElement::Call(_) | TypeElement::Constructor(_) => continue,
+                            TypeElement::Property(p) => match p.type_ann {
+                                Some(ty) => *ty,
+                                None => Type::any(p.span, Default::default()),
+                            },
+                            TypeElement::Method(m) => method_to_fn_type(m.span, m.type_params, m.params, m.ret_ty),
+                            TypeElement::Index(i) => match i.type_ann {
+                                Some(ty) => *ty,
+                                None => Type::any(i.span, Default::default()),
+                            },
+                        };
 
-                                if let Some(ty) = p.type_ann {
-                                    if types.iter().all(|previous| !previous.type_eq(&ty)) {
-                                        types.push(*ty);
-                                    }
-                                }
-                            }
-                            TypeElement::Method(_) => {
-                                unimplemented!("Method property in S[keyof S]")
-                            }
-                            TypeElement::Index(_) => {
-                                unimplemented!("Index signature in S[keyof S]")
-                            }
+                        if types.iter().all(|previous| !previous.type_eq(&ty)) {
+                            types.push(ty);
                         }
                     }
                     let ty = Type::new_union(span, types);
@@ -715,20 +708,37 @@ impl Fold<Type> for KeyInliner<'_, '_, '_> {
                     let mut types: Vec<Type> = vec![];
                     for member in obj.members {
                         match member {
-                            TypeElement::Call(_) => {
-                                unimplemented!("Call signature in T[keyof S]")
-                            }
-                            TypeElement::Constructor(_) => {
-                                unimplemented!("Constructor signature in T[keyof S]")
-                            }
+                            // Signatures are not accessible using a key.
+                            TypeElement::Call(_) | TypeElement::Constructor(_) => {}
+
+                            // `keyof { [key: string]: T }` is `string | number`.
+                            TypeElement::Index(i) => {
+                                for param in &i.params {
+                                    let mut keys = vec![*param.ty.clone()];
+                                    if param.ty.is_kwd(TsKeywordTypeKind::TsStringKeyword) {
+                                        keys.push(Type::Keyword(KeywordType {
+                                            span: param.span,
+                                            kind: TsKeywordTypeKind::TsNumberKeyword,
+                                            metadata: Default::default(),
+                                            tracker: Default::default(),
+                                        }));
+                                    }
 
-                            TypeElement::Index(_) => {
-                                unimplemented!("Index signature in T[keyof S]")
+                                    for ty in keys {
+                                        if types.iter().all(|previous| !previous.type_eq(&ty)) {
+                                            types.push(ty);
+                                        }
+                                    }
+                                }
                             }
 
                             TypeElement::Property(PropertySignature { key, .. }) | TypeElement::Method(MethodSignature { key, .. }) => {
-                                if key.is_computed() {
-                                    unimplemented!("Computed key mixed with T[keyof S]");
+                                if key.is_computed() || matches!(key, Key::Num(..) | Key::BigInt(..)) {
+                                    let ty = key.ty().into_owned();
+                                    if types.iter().all(|previous| !previous.type_eq(&ty)) {
+                                        types.push(ty);
+                                    }
+                                    continue;
                                 }
 
                                 if let Key::Normal { span: i_span, sym: key } = key {
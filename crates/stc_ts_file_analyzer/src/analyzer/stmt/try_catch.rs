@@ -1,8 +1,19 @@
+use std::borrow::Cow;
+
 use rnode::VisitWith;
 use stc_ts_ast_rnode::RCatchClause;
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::Type;
+use stc_ts_utils::PatExt;
+use swc_common::Spanned;
 
 use crate::{
-    analyzer::{pat::PatMode, scope::ScopeKind, Analyzer, Ctx},
+    analyzer::{
+        pat::PatMode,
+        scope::{vars::DeclareVarsOpts, ScopeKind, VarKind},
+        util::ResultExt,
+        Analyzer, Ctx,
+    },
     validator,
     validator::ValidateWith,
 };
@@ -17,9 +28,44 @@ impl Analyzer<'_, '_> {
         self.with_ctx(ctx)
             .with_child(ScopeKind::Block, Default::default(), |child: &mut Analyzer| {
                 match &s.param {
-                    Some(pat) => {
-                        pat.validate_with(child)?;
-                    }
+                    Some(pat) => match pat.get_ty() {
+                        Some(ty) => {
+                            // Errors of the type annotation are reported while validating the pattern.
+                            let span = ty.span();
+                            let declared = ty.validate_with(child).and_then(|declared| {
+                                child
+                                    .normalize(Some(span), Cow::Owned(declared), Default::default())
+                                    .map(Cow::into_owned)
+                            });
+                            if let Ok(declared) = declared {
+                                if !declared.is_any() && !declared.is_unknown() {
+                                    child.storage.report(ErrorKind::CatchVarTypeAnnMustBeAnyOrUnknown { span }.into());
+                                }
+                            }
+
+                            pat.validate_with(child)?;
+                        }
+
+                        // With `useUnknownInCatchVariables`, `catch (e)` is same as `catch (e: unknown)`.
+                        None if child.rule().use_unknown_in_catch_variables => {
+                            child
+                                .add_vars(
+                                    pat,
+                                    Some(Type::unknown(pat.span(), Default::default())),
+                                    None,
+                                    None,
+                                    DeclareVarsOpts {
+                                        kind: VarKind::Param,
+                                        use_iterator_for_array: false,
+                                    },
+                                )
+                                .report(&mut child.storage);
+                        }
+
+                        None => {
+                            pat.validate_with(child)?;
+                        }
+                    },
                     None => {}
                 }
 
@@ -746,16 +746,23 @@ impl Analyzer<'_, '_> {
 
                         if !self.config.is_builtin {
                             // Report error if type is not found.
-                            if let Some(ty) = &ty {
-                                self.normalize(
+                            if let Some(declared) = &ty {
+                                let res = self.normalize(
                                     Some(i.id.span),
-                                    Cow::Borrowed(ty),
+                                    Cow::Borrowed(declared),
                                     NormalizeTypeOpts {
                                         preserve_global_this: true,
                                         ..Default::default()
                                     },
-                                )
-                                .report(&mut self.storage);
+                                );
+                                if let Err(err) = res {
+                                    // Like `tsc`, a type which is too deep to instantiate is
+                                    // reported once and treated as `any`.
+                                    if matches!(&*err, ErrorKind::ExcessivelyDeepInstantiation { .. }) {
+                                        ty = Some(Type::any(i.id.span, Default::default()));
+                                    }
+                                    self.storage.report(err);
+                                }
                             }
                         }
 
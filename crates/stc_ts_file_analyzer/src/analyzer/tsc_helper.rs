@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use num_bigint::BigInt;
 use stc_ts_types::{IntrinsicKind, StringMapping, TplElem, TplType, Type};
 use swc_atoms::Atom;
-use swc_common::{Span, Spanned, TypeEq, DUMMY_SP};
+use swc_common::{Spanned, TypeEq, DUMMY_SP};
 use swc_ecma_ast::TsKeywordTypeKind;
 
 use super::{relation::Relation, Analyzer};
@@ -32,8 +32,8 @@ impl Analyzer<'_, '_> {
     }
 
     /// Ported from `isTypeAssignableTo` of `tsc`.
-    pub(crate) fn is_type_assignable_to(&mut self, span: Span, source: &Type, target: &Type) -> bool {
-        self.assign(span, &mut Default::default(), target, source).is_ok()
+    pub(crate) fn is_type_assignable_to(&mut self, source: &Type, target: &Type) -> bool {
+        self.is_type_related_to(source, target, Relation::Assignable)
     }
 
     /// Ported from `isValidNumberString` of `tsc`.
@@ -82,13 +82,13 @@ impl Analyzer<'_, '_> {
     }
 
     /// Ported from `isMemberOfStringMapping` of `tsc`.
-    pub(crate) fn is_member_of_string_mapping(&mut self, span: Span, source: &Type, target: &Type) -> VResult<bool> {
+    pub(crate) fn is_member_of_string_mapping(&mut self, source: &Type, target: &Type) -> VResult<bool> {
         if target.is_any() || target.is_kwd(TsKeywordTypeKind::TsStringKeyword) {
             return Ok(true);
         }
 
         if target.is_tpl() {
-            return Ok(self.is_type_assignable_to(span, source, target));
+            return Ok(self.is_type_assignable_to(source, target));
         }
 
         if let Type::StringMapping(StringMapping {
@@ -7,6 +7,7 @@ use stc_utils::{
     cache::{Freeze, ALLOW_DEEP_CLONE},
     dev_span,
     ext::TypeVecExt,
+    stack,
 };
 use swc_common::{Span, SyntaxContext, TypeEq};
 use tracing::{debug, info};
@@ -25,6 +26,8 @@ impl Analyzer<'_, '_> {
         opts: NormalizeTypeOpts,
     ) -> VResult<Cow<'a, Type>> {
         let span = if c.span.is_dummy() { actual_span } else { c.span };
+        let _instantiation = stack::track_instantiation(actual_span)?;
+
         // TODO(kdy1): Cleanup
         c = match self.expand_conditional_type(span, Type::Conditional(c)).foldable() {
             Type::Conditional(c) => c,
@@ -14,7 +14,7 @@ use stc_ts_types::{
 };
 use stc_utils::{
     cache::{Freeze, ALLOW_DEEP_CLONE},
-    dev_span, stack,
+    dev_span, stack, stats,
 };
 use swc_common::{Span, Spanned, SyntaxContext, TypeEq};
 use swc_ecma_ast::{TruePlusMinus, TsKeywordTypeKind};
@@ -32,15 +32,15 @@ impl Analyzer<'_, '_> {
     /// ```ts
     /// declare const a: Partial<Foo>;
     /// ```
-    ///
-    ///
-    /// TODO(kdy1): Handle index signatures.
     pub(crate) fn expand_mapped(&mut self, span: Span, m: &Mapped) -> VResult<Option<Type>> {
         let _guard = stack::track(span)?;
+        let _instantiation = stack::track_instantiation(span)?;
         let _tracing = dev_span!("expand_mapped");
 
         let orig = dump_type_as_string(&ALLOW_DEEP_CLONE.set(&(), || Type::Mapped(m.clone())));
 
+        stats::record(|s| s.mapped_expansions += 1);
+
         let ty = self.expand_mapped_inner(span, m)?;
 
         if let Some(ty) = &ty {
@@ -537,6 +537,47 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Applies the name type of a mapped type, which is `F<K>` in `{ [K in
+    /// keyof T as F<K>]: T[K] }`, to `key`.
+    fn apply_name_type_of_mapped(
+        &mut self,
+        span: Span,
+        name_type: &Type,
+        ty: &Type,
+        original_keyof_operand: &Type,
+        type_param: &TypeParam,
+        key: Type,
+    ) -> VResult<Type> {
+        let mut new_key = name_type.clone();
+
+        // Replace T with ty
+        replace_type(&mut new_key, |needle| needle.type_eq(original_keyof_operand), |_| Some(ty.clone()));
+        // Replace K with key
+        replace_type(
+            &mut new_key,
+            |needle| match needle.normalize() {
+                Type::Param(needle) => needle.name.sym() == type_param.name.sym(),
+                _ => false,
+            },
+            |_| Some(key.clone()),
+        );
+
+        Ok(self
+            .normalize(
+                Some(span),
+                Cow::Owned(new_key),
+                NormalizeTypeOpts {
+                    preserve_mapped: true,
+                    preserve_global_this: true,
+                    preserve_intersection: true,
+                    preserve_union: true,
+                    ..Default::default()
+                },
+            )?
+            .freezed()
+            .into_owned())
+    }
+
     /// Get keys of `ty` as a property name.
     pub(crate) fn get_property_names_for_mapped_type(
         &mut self,
@@ -571,52 +612,73 @@ impl Analyzer<'_, '_> {
                 .freezed();
 
             let mut new_keys = vec![];
+            let mut index_signatures = vec![];
 
             for property_name in property_names {
                 match property_name {
                     PropertyName::Key(key) => {
-                        let mut new_key = name_type.clone().into_owned();
-
-                        // Replace T with ty
-                        replace_type(&mut new_key, |needle| needle.type_eq(original_keyof_operand), |_| Some(ty.clone()));
-                        // Replace K with key
-                        replace_type(
-                            &mut new_key,
-                            |needle| match needle.normalize() {
-                                Type::Param(needle) => needle.name.sym() == type_param.name.sym(),
-                                _ => false,
-                            },
-                            |_| Some(key.ty().into_owned()),
-                        );
-
-                        let new_key = self
-                            .normalize(
-                                Some(span),
-                                Cow::Owned(new_key),
-                                NormalizeTypeOpts {
-                                    preserve_mapped: true,
-                                    preserve_global_this: true,
-                                    preserve_intersection: true,
-                                    preserve_union: true,
-                                    ..Default::default()
-                                },
-                            )?
-                            .freezed()
-                            .into_owned();
+                        let new_key = self.apply_name_type_of_mapped(
+                            span,
+                            &name_type,
+                            ty,
+                            original_keyof_operand,
+                            type_param,
+                            key.ty().into_owned(),
+                        )?;
 
                         new_keys.push(new_key);
                     }
 
-                    PropertyName::IndexSignature { span, params, readonly } => todo!(),
+                    PropertyName::IndexSignature {
+                        span: index_span,
+                        params,
+                        readonly,
+                    } => {
+                        // The type of the index parameter is used as the key.
+                        let param = match params.first() {
+                            Some(v) => v,
+                            None => continue,
+                        };
+
+                        let new_key =
+                            self.apply_name_type_of_mapped(span, &name_type, ty, original_keyof_operand, type_param, *param.ty.clone())?;
+
+                        if new_key.is_never() {
+                            continue;
+                        }
+
+                        // `[K in keyof T as `get${K}`]` where `T` is `{ [key: string]: V }`
+                        // is still an index signature.
+                        if new_key.is_kwd(TsKeywordTypeKind::TsStringKeyword)
+                            || new_key.is_kwd(TsKeywordTypeKind::TsNumberKeyword)
+                            || new_key.is_kwd(TsKeywordTypeKind::TsSymbolKeyword)
+                            || new_key.is_tpl()
+                        {
+                            index_signatures.push(PropertyName::IndexSignature {
+                                span: index_span,
+                                params: vec![FnParam {
+                                    ty: box new_key,
+                                    ..param.clone()
+                                }],
+                                readonly,
+                            });
+                        } else {
+                            new_keys.push(new_key);
+                        }
+                    }
                 }
             }
 
-            let keys = self.convert_type_to_keys_for_mapped_type(span, &Type::new_union(span, new_keys), None)?;
-            let keys = match keys {
-                Some(v) => v,
-                None => return Ok(None),
-            };
-            return Ok(Some(keys.into_iter().map(|v| v.into()).collect()));
+            if !new_keys.is_empty() {
+                let keys = self.convert_type_to_keys_for_mapped_type(span, &Type::new_union(span, new_keys), None)?;
+                let keys = match keys {
+                    Some(v) => v,
+                    None => return Ok(None),
+                };
+                index_signatures.extend(keys.into_iter().map(PropertyName::from));
+            }
+
+            return Ok(Some(index_signatures));
         }
 
         let ty = self
@@ -134,6 +134,9 @@ impl Analyzer<'_, '_> {
                 Ok(v) => v,
                 Err(err) => {
                     // print_backtrace();
+                    if let Some(err) = stack::instantiation_limit_error() {
+                        return Err(err.into());
+                    }
                     return Err(err.into());
                 }
             };
@@ -1510,6 +1513,18 @@ impl Analyzer<'_, '_> {
         self.apply_type_facts_to_type(type_facts, ty)
     }
 
+    /// Applies facts of `obj[key]`, where `key` is a variable.
+    pub(crate) fn apply_elem_type_facts(&mut self, key: &(Name, Id), ty: Type) -> Type {
+        let type_facts =
+            self.scope.get_elem_type_facts(key) | self.cur_facts.true_facts.elem_facts.get(key).copied().unwrap_or(TypeFacts::None);
+
+        if type_facts == TypeFacts::None {
+            return ty;
+        }
+
+        self.apply_type_facts_to_type(type_facts, ty)
+    }
+
     /// Collect all class members, including inherited members.
     ///
     /// # Parameters
@@ -200,6 +200,8 @@ pub trait BuiltInGen: Sized {
                                                     vars: data.vars,
                                                     private_types: Default::default(),
                                                     types: data.types,
+                                                    has_export_equals: false,
+                                                    export_equals_needs_default_import: false,
                                                 },
                                                 metadata: Default::default(),
                                                 tracker: Default::default(),
@@ -290,28 +292,73 @@ pub trait EnvFactory {
     #[allow(clippy::new_ret_no_self)]
     fn new(env: StableEnv, rule: Rule, target: EsVersion, module: ModuleConfig, builtin: Arc<BuiltIn>) -> Env;
     fn simple(rule: Rule, target: EsVersion, module: ModuleConfig, libs: &[Lib]) -> Env {
-        static STABLE_ENV: Lazy<StableEnv> = Lazy::new(Default::default);
-        static CACHE: Lazy<DashMap<Vec<Lib>, Arc<OnceCell<Arc<BuiltIn>>>, ahash::RandomState>> = Lazy::new(Default::default);
+        let mut libs = libs.to_vec();
+        libs.sort();
+        libs.dedup();
+
+        let builtin = builtin_of(rule, &libs);
+
+        Self::new(STABLE_ENV.clone(), rule, target, module, builtin).with_libs(libs)
+    }
 
-        // TODO(kdy1): Include `env` in cache
+    /// Adds libraries referenced using `/// <reference lib="..." />` to the
+    /// built-in declarations of `env`.
+    ///
+    /// If `no_default_lib` is true, libraries of `env` are replaced instead.
+    /// If `env` is not created from libraries, declarations of `libs` are
+    /// added to the existing built-in declarations, which take precedence.
+    fn with_lib_references(env: &Env, libs: &[Lib], no_default_lib: bool) -> Env {
         let mut libs = libs.to_vec();
         libs.sort();
         libs.dedup();
 
-        let cell = CACHE.entry(libs.clone()).or_default().clone();
+        if env.libs().is_empty() && !no_default_lib {
+            if libs.is_empty() {
+                return env.clone();
+            }
 
-        let builtin = {
-            let builtin = cell.get_or_init(|| {
-                let builtin = BuiltIn::from_ts_libs(&STABLE_ENV, &libs, rule.no_lib);
-                Arc::new(builtin)
-            });
-            (*builtin).clone()
+            let builtin = env.builtin().merged_with(&builtin_of(env.rule(), &libs));
+            return env.clone().with_builtin(Arc::new(builtin));
+        }
+
+        let mut new_libs = if no_default_lib { vec![] } else { env.libs().to_vec() };
+        for lib in libs {
+            if !new_libs.contains(&lib) {
+                new_libs.push(lib);
+            }
+        }
+        new_libs.sort();
+
+        if new_libs == env.libs() {
+            return env.clone();
+        }
+
+        let builtin = if new_libs.is_empty() {
+            Default::default()
+        } else {
+            builtin_of(env.rule(), &new_libs)
         };
 
-        Self::new(STABLE_ENV.clone(), rule, target, module, builtin)
+        env.clone().with_builtin(builtin).with_libs(new_libs)
     }
 }
 
+static STABLE_ENV: Lazy<StableEnv> = Lazy::new(Default::default);
+
+/// Returns the built-in declarations of `libs`, which should be sorted.
+fn builtin_of(rule: Rule, libs: &[Lib]) -> Arc<BuiltIn> {
+    static CACHE: Lazy<DashMap<Vec<Lib>, Arc<OnceCell<Arc<BuiltIn>>>, ahash::RandomState>> = Lazy::new(Default::default);
+
+    // TODO(kdy1): Include `env` in cache
+    let cell = CACHE.entry(libs.to_vec()).or_default().clone();
+
+    let builtin = cell.get_or_init(|| {
+        let builtin = BuiltIn::from_ts_libs(&STABLE_ENV, libs, rule.no_lib);
+        Arc::new(builtin)
+    });
+    (*builtin).clone()
+}
+
 impl EnvFactory for Env {
     fn new(env: StableEnv, rule: Rule, target: EsVersion, module: ModuleConfig, builtin: Arc<BuiltIn>) -> Env {
         Env::new(env, rule, target, module, builtin)
@@ -1,8 +1,9 @@
 use rnode::VisitWith;
 use stc_ts_ast_rnode::{RBlockStmt, RBool, RModuleDecl, RModuleItem, RStmt, RTsEntityName, RTsLit};
 use stc_ts_type_ops::metadata::TypeFinder;
-use stc_ts_types::{KeywordType, KeywordTypeMetadata, LitType, Ref};
+use stc_ts_types::{FnParam, Function, KeywordType, KeywordTypeMetadata, LitType, Ref, TypeParamDecl};
 use stc_utils::dev_span;
+use swc_common::Span;
 use swc_ecma_ast::*;
 
 use crate::ty::{Intersection, Type, Union};
@@ -291,6 +292,18 @@ where
     }
 }
 
+/// Type of a method when it's accessed like `T['method']`.
+pub(crate) fn method_to_fn_type(span: Span, type_params: Option<TypeParamDecl>, params: Vec<FnParam>, ret_ty: Option<Box<Type>>) -> Type {
+    Type::Function(Function {
+        span,
+        type_params,
+        params,
+        ret_ty: ret_ty.unwrap_or_else(|| box Type::any(span, Default::default())),
+        metadata: Default::default(),
+        tracker: Default::default(),
+    })
+}
+
 pub(crate) fn should_instantiate_type_ann(ty: &Type) -> bool {
     let ty = ty.normalize();
 
@@ -323,3 +336,62 @@ pub(crate) fn unwrap_builtin_with_single_arg<'a>(ty: &'a Type, wanted_ref_name:
 
     None
 }
+
+/// Converts a number to a string like `Number.prototype.toString` of
+/// JavaScript.
+pub(crate) fn js_number_to_string(v: f64) -> String {
+    if v.is_nan() {
+        return "NaN".into();
+    }
+    if v == 0.0 {
+        return "0".into();
+    }
+    if v.is_infinite() {
+        return if v > 0.0 { "Infinity".into() } else { "-Infinity".into() };
+    }
+    if v < 0.0 {
+        return format!("-{}", js_number_to_string(-v));
+    }
+
+    // The shortest representation which round-trips, like `1.5e-7`.
+    let repr = format!("{:e}", v);
+    let (mantissa, exp) = repr.split_once('e').unwrap();
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exp.parse::<i32>().unwrap() + 1;
+
+    if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat(-n as usize), digits)
+    } else {
+        let sign = if n - 1 < 0 { '-' } else { '+' };
+        if k == 1 {
+            format!("{}e{}{}", digits, sign, (n - 1).abs())
+        } else {
+            format!("{}.{}e{}{}", &digits[..1], &digits[1..], sign, (n - 1).abs())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::js_number_to_string;
+
+    #[test]
+    fn number_to_string() {
+        assert_eq!(js_number_to_string(1.0), "1");
+        assert_eq!(js_number_to_string(-0.0), "0");
+        assert_eq!(js_number_to_string(1.5), "1.5");
+        assert_eq!(js_number_to_string(100.0), "100");
+        assert_eq!(js_number_to_string(0.000001), "0.000001");
+        assert_eq!(js_number_to_string(0.0000001), "1e-7");
+        assert_eq!(js_number_to_string(1e21), "1e+21");
+        assert_eq!(js_number_to_string(1.5e300), "1.5e+300");
+        assert_eq!(js_number_to_string(123456789012345680000.0), "123456789012345680000");
+        assert_eq!(js_number_to_string(f64::INFINITY), "Infinity");
+        assert_eq!(js_number_to_string(f64::NAN), "NaN");
+    }
+}
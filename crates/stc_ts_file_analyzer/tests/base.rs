@@ -39,7 +39,20 @@ struct StcError {
     code: usize,
 }
 
-fn get_env() -> Env {
+/// Compiler options specified by `// @option: value` comments. They are
+/// passed to `tsc` as command line flags.
+fn parse_options(input: &Path) -> Vec<(String, String)> {
+    let src = read_to_string(input).unwrap();
+
+    src.lines()
+        .filter_map(|line| {
+            let (name, value) = line.trim().strip_prefix("//")?.trim().strip_prefix('@')?.split_once(':')?;
+            Some((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+fn get_env(input: &Path) -> Env {
     let mut libs = vec![];
     let ls = &[
         "es2022.full",
@@ -57,15 +70,20 @@ fn get_env() -> Env {
     libs.sort();
     libs.dedup();
 
-    Env::simple(
-        Rule {
-            strict_function_types: true,
-            ..Default::default()
-        },
-        EsVersion::latest(),
-        ModuleConfig::None,
-        &libs,
-    )
+    let mut rule = Rule {
+        strict_function_types: true,
+        ..Default::default()
+    };
+    let mut options = parse_options(input);
+    // Flags like `strictNullChecks` override `strict`.
+    options.sort_by_key(|(name, _)| !name.eq_ignore_ascii_case("strict"));
+    for (name, value) in options {
+        if let Ok(value) = value.parse() {
+            rule.set_option(&name, value);
+        }
+    }
+
+    Env::simple(rule, EsVersion::latest(), ModuleConfig::None, &libs)
 }
 
 fn validate(input: &Path) -> Vec<StcError> {
@@ -76,7 +94,7 @@ fn validate(input: &Path) -> Vec<StcError> {
 
             let fm = cm.load_file(input).unwrap();
 
-            let env = get_env();
+            let env = get_env(input);
 
             let generator = module_id::ModuleIdGenerator::default();
             let path = Arc::new(FileName::Real(input.to_path_buf()));
@@ -208,6 +226,11 @@ fn invoke_tsc(input: &Path) -> Vec<TscError> {
         .arg("--noEmit")
         .arg("--lib")
         .arg("es2020")
+        .args(
+            parse_options(input)
+                .into_iter()
+                .flat_map(|(name, value)| [format!("--{}", name), value]),
+        )
         .arg(input)
         .output()
         .expect("failed to invoke tsc");
@@ -244,7 +267,8 @@ fn run_test(file_name: PathBuf, want_error: bool) -> Option<NormalizedOutput> {
                 libs.sort();
                 libs.dedup();
 
-                let env = Env::simple(case.rule, case.target, case.module_config, &libs);
+                let env =
+                    Env::simple(case.rule, case.target, case.module_config, &libs).with_jsx_import_source(case.jsx_import_source.clone());
                 let stable_env = env.shared().clone();
                 let generator = module_id::ModuleIdGenerator::default();
                 let path = Arc::new(FileName::Real(file_name.clone()));
@@ -356,7 +380,8 @@ fn pass_only(input: PathBuf) {
         testing::run_test2(false, |cm, handler| {
             let fm = cm.load_file(&input).unwrap();
 
-            let env = Env::simple(case.rule, case.target, case.module_config, &case.libs);
+            let env =
+                Env::simple(case.rule, case.target, case.module_config, &case.libs).with_jsx_import_source(case.jsx_import_source.clone());
 
             let generator = module_id::ModuleIdGenerator::default();
             let path = Arc::new(FileName::Real(input.to_path_buf()));
@@ -1,4 +1,5 @@
 //@strict: true
+// @strictPropertyInitialization: false
 
 // Type guards involving type parameters produce intersection types
 
@@ -1,4 +1,5 @@
 //@strict: true
+// @strictPropertyInitialization: false
 // @declaration: true
 
 class A {
@@ -0,0 +1,44 @@
+// @noImplicitOverride: true
+
+class Base {
+    a = 1;
+    b = 1;
+    m() {}
+    static s() {}
+}
+
+abstract class AbstractBase {
+    abstract c: number;
+    abstract n(): void;
+}
+
+class Derived extends Base {
+    constructor(
+        public a: number,
+        override b: number,
+        public c: number,
+    ) {
+        super();
+    }
+
+    m() {}
+    static s() {}
+}
+
+class Impl extends AbstractBase {
+    constructor(public c: number) {
+        super();
+    }
+
+    n() {}
+}
+
+abstract class AbstractDerived extends AbstractBase {
+    abstract n(): void;
+}
+
+declare class Ambient extends Base {
+    m(): void;
+}
+
+export {};
@@ -0,0 +1,21 @@
+class Base {
+    a = 1;
+}
+
+class NoBase {
+    constructor(
+        override a: number,
+    ) {}
+}
+
+class Derived extends Base {
+    constructor(
+        override a: number,
+        override b: number,
+        public c: number,
+    ) {
+        super();
+    }
+}
+
+export {};
@@ -0,0 +1,42 @@
+// @strict: true
+
+declare const cond: boolean;
+
+class IfElse {
+    a: number;
+    b: number;
+    constructor() {
+        if (cond) {
+            this.a = 1;
+            this.b = 1;
+        } else {
+            this.a = 2;
+        }
+    }
+}
+
+class Conditional {
+    a: number;
+    b: number;
+    constructor() {
+        cond ? (this.a = 1) : (this.a = 2);
+        cond && (this.b = 1);
+    }
+}
+
+class Switch {
+    a: number;
+    b: number;
+    constructor(n: number) {
+        switch (n) {
+            case 1:
+                this.a = 1;
+                this.b = 1;
+                break;
+            default:
+                this.a = 2;
+        }
+    }
+}
+
+export {};
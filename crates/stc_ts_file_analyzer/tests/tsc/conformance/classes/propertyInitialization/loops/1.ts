@@ -0,0 +1,73 @@
+// @strict: true
+
+declare const cond: boolean;
+declare const items: number[];
+
+class While {
+    a: number;
+    constructor() {
+        while (cond) {
+            this.a = 1;
+        }
+    }
+}
+
+class InfiniteLoop {
+    a: number;
+    constructor() {
+        while (true) {
+            this.a = 1;
+            break;
+        }
+    }
+}
+
+class DoWhile {
+    a: number;
+    constructor() {
+        do {
+            this.a = 1;
+        } while (cond);
+    }
+}
+
+class ForOf {
+    a: number;
+    constructor() {
+        for (const item of items) {
+            this.a = item;
+        }
+    }
+}
+
+class LabeledBreak {
+    a: number;
+    b: number;
+    constructor() {
+        this.b = 1;
+        outer: for (;;) {
+            for (;;) {
+                if (cond) {
+                    break outer;
+                }
+                this.a = 1;
+                break;
+            }
+            break;
+        }
+    }
+}
+
+class LabeledBlock {
+    a: number;
+    constructor() {
+        block: {
+            if (cond) {
+                break block;
+            }
+            this.a = 1;
+        }
+    }
+}
+
+export {};
@@ -0,0 +1,38 @@
+// @strict: true
+
+declare const cond: boolean;
+
+class EarlyReturn {
+    a: number;
+    b: number;
+    constructor() {
+        this.b = 1;
+        if (cond) {
+            return;
+        }
+        this.a = 1;
+    }
+}
+
+class ReturnAfterAssignment {
+    a: number;
+    constructor() {
+        if (cond) {
+            this.a = 1;
+            return;
+        }
+        this.a = 2;
+    }
+}
+
+class Throw {
+    a: number;
+    constructor() {
+        if (cond) {
+            throw new Error();
+        }
+        this.a = 1;
+    }
+}
+
+export {};
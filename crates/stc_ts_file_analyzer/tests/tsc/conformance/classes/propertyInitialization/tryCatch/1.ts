@@ -0,0 +1,42 @@
+// @strict: true
+
+declare function f(): void;
+
+class TryCatch {
+    a: number;
+    b: number;
+    constructor() {
+        try {
+            f();
+            this.a = 1;
+            this.b = 1;
+        } catch {
+            this.a = 2;
+        }
+    }
+}
+
+class Rethrow {
+    a: number;
+    constructor() {
+        try {
+            f();
+            this.a = 1;
+        } catch (e) {
+            throw e;
+        }
+    }
+}
+
+class Finally {
+    a: number;
+    constructor() {
+        try {
+            f();
+        } finally {
+            this.a = 1;
+        }
+    }
+}
+
+export {};
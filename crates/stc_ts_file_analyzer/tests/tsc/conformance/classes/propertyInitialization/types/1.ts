@@ -0,0 +1,22 @@
+// @strict: true
+
+class Types<T> {
+    a: T;
+    b: T | undefined;
+    c: any;
+    d: unknown;
+    e?: number;
+    f!: number;
+    g: number = 1;
+    declare h: number;
+    static i: number;
+}
+
+class ParameterProperties {
+    b: number;
+    constructor(public a: number) {
+        this.b = a;
+    }
+}
+
+export {};
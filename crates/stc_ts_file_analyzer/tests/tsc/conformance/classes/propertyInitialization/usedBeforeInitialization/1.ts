@@ -0,0 +1,27 @@
+// @strict: true
+
+declare const cond: boolean;
+
+class UsedBeforeInitialization {
+    a: number;
+    b: number;
+    constructor() {
+        this.b = this.a;
+        this.a = 1;
+        this.a;
+        if (cond) {
+            this.b = 1;
+        }
+        this.b;
+    }
+}
+
+class UsedInNestedFunction {
+    a: number;
+    constructor() {
+        const f = () => this.a;
+        this.a = 1;
+    }
+}
+
+export {};
@@ -0,0 +1,22 @@
+type MyAny = any;
+type MyUnknown = unknown;
+
+try {
+} catch (e: any) {}
+
+try {
+} catch (e: unknown) {}
+
+try {
+} catch (e: MyAny) {}
+
+try {
+} catch (e: MyUnknown) {}
+
+try {
+} catch (e: Error) {}
+
+try {
+} catch (e: string | undefined) {}
+
+export {};
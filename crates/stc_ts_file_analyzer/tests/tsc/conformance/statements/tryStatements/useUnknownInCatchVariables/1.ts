@@ -0,0 +1,22 @@
+// @strict: true
+// @useUnknownInCatchVariables: true
+
+try {
+} catch (e) {
+    const a: string = e;
+
+    if (e instanceof Error) {
+        const b: string = e.message;
+    }
+
+    if (typeof e === "string") {
+        const c: string = e;
+    }
+}
+
+try {
+} catch (e: any) {
+    const a: string = e;
+}
+
+export {};
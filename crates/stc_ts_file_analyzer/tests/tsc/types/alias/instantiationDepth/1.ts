@@ -0,0 +1,6 @@
+type Nest<T> = T extends unknown ? Nest<[T]> : never;
+
+declare const x: Nest<string>;
+const y: number = x;
+
+export {};
@@ -0,0 +1,6 @@
+enum E {
+    A = 1n,
+    B = 2,
+}
+
+export {};
@@ -0,0 +1,6 @@
+const enum E {
+    A = 1n,
+    B = 2,
+}
+
+export {};
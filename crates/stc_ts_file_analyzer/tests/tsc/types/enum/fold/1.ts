@@ -0,0 +1,6 @@
+const enum E {
+    A = 1 % 0,
+    B = 2 ** 1024,
+}
+
+export {};
@@ -0,0 +1,16 @@
+enum E {
+    A = 7 % 4,
+    B = 2 ** 3,
+}
+
+enum F {
+    X = E.B + 1,
+}
+
+const a: 3 = E.A;
+const b: 8 = E.B;
+const x: 9 = F.X;
+
+const bad: 4 = E.A;
+
+export {};
@@ -0,0 +1,15 @@
+enum T {
+    A = `a${1}`,
+    B = `b${0.5}`,
+    C = `c${1e21}`,
+    D = "d" + 1e-7,
+}
+
+const a: "a1" = T.A;
+const b: "b0.5" = T.B;
+const c: "c1e+21" = T.C;
+const d: "d1e-7" = T.D;
+
+const bad: "a2" = T.A;
+
+export {};
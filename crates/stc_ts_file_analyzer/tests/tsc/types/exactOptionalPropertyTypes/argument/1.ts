@@ -0,0 +1,17 @@
+// @strict: true
+// @exactOptionalPropertyTypes: true
+
+interface A {
+    a?: string;
+}
+
+declare function f(a: A): void;
+
+declare const undef: { a: string | undefined };
+declare const num: { a: number | undefined };
+
+f(undef);
+f(num);
+f({ a: "" });
+
+export {};
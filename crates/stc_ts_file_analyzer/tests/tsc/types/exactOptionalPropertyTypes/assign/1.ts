@@ -0,0 +1,17 @@
+// @strict: true
+// @exactOptionalPropertyTypes: true
+
+interface A {
+    a?: string;
+}
+
+declare const undef: { a: string | undefined };
+declare const num: { a: number | undefined };
+declare const str: { a: string };
+
+const a1: A = undef;
+const a2: A = num;
+const a3: A = str;
+const a4: A = {};
+
+export {};
@@ -0,0 +1,16 @@
+// @strict: true
+// @exactOptionalPropertyTypes: true
+
+interface A {
+    a?: string;
+    b: string | undefined;
+    c: string;
+}
+
+declare const a: A;
+
+delete a.a;
+delete a.b;
+delete a.c;
+
+export {};
@@ -0,0 +1,20 @@
+// @strict: true
+// @exactOptionalPropertyTypes: true
+
+interface A {
+    a?: string;
+    b: string | undefined;
+}
+
+declare const a: A;
+declare const s: string | undefined;
+declare const n: number | undefined;
+
+a.a = undefined;
+a.a = s;
+a.a = n;
+a.a = "";
+a.a = 0;
+a.b = undefined;
+
+export {};
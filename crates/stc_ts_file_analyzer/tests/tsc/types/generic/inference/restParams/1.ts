@@ -0,0 +1,7 @@
+declare function params<A extends any[], R>(f: (...args: A) => R): A;
+
+export const a: [number, string] = params((a: number, b: string) => 1);
+export const b: [number, string?] = params((a: number, b?: string) => 1);
+export const c: [number, ...string[]] = params((a: number, ...rest: string[]) => 1);
+export const d: [] = params(() => 1);
+export const e: [string] = params((a: number) => 1);
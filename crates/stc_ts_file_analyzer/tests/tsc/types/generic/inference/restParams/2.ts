@@ -0,0 +1,6 @@
+declare function params<A extends any[], R>(f: (...args: A) => R): A;
+declare function thisOf<T, A extends any[]>(f: (this: T, ...args: A) => void): [T, A];
+
+export const a: [number] = params(function (this: { x: number }, a: number) {});
+export const b: [{ x: number }, [number]] = thisOf(function (this: { x: number }, a: number) {});
+export const c: [string] = params(function (this: { x: number }, a: number) {});
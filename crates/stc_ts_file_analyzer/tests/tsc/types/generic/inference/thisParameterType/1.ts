@@ -0,0 +1,9 @@
+declare function withThis(this: { x: number }, a: number): void;
+declare function withoutThis(a: number): void;
+
+export const a: { x: number } = null! as ThisParameterType<typeof withThis>;
+export const b: unknown = null! as ThisParameterType<typeof withoutThis>;
+export const c: number = null! as ThisParameterType<typeof withoutThis>;
+export const d: [a: number] = null! as Parameters<typeof withThis>;
+export const e: (a: number) => void = null! as OmitThisParameter<typeof withThis>;
+export const f: [a: string] = null! as Parameters<typeof withoutThis>;
@@ -0,0 +1,14 @@
+type Fn = (x: number) => string;
+
+declare const a: Partial<Record<string, Fn>>;
+const f: Fn | undefined = a["foo"];
+
+interface Methods {
+    foo(x: number): string;
+    [key: string]: (x: number) => string;
+}
+
+declare const b: Partial<Methods>;
+const g: ((x: number) => string) | undefined = b.foo;
+
+export {};
@@ -0,0 +1,18 @@
+interface Dict {
+    [key: string]: number;
+}
+
+interface Methods {
+    foo(): string;
+}
+
+declare const p: Pick<Dict, "a" | "b">;
+const a: number = p.a;
+const b: number = p.b;
+const c: string = p.a;
+
+type Values<T> = T[keyof T];
+declare const v: Values<Methods>;
+const f: () => string = v;
+
+export {};
@@ -0,0 +1,10 @@
+// @noImplicitAny: true
+
+declare const arr: number[];
+declare const obj: { a: number };
+declare const key: string;
+
+arr[key];
+arr[0];
+obj[key];
+obj["a"];
@@ -0,0 +1,9 @@
+// @noImplicitAny: true
+// @suppressImplicitAnyIndexErrors: true
+
+declare const arr: number[];
+declare const obj: { a: number };
+declare const key: string;
+
+arr[key];
+obj[key];
@@ -0,0 +1,8 @@
+// @noImplicitAny: true
+
+declare const objs: { a: number } | { b: number };
+declare const arrs: number[] | string[];
+declare const key: string;
+
+objs[key];
+arrs[key];
@@ -0,0 +1,17 @@
+// @noPropertyAccessFromIndexSignature: true
+
+interface Foo {
+    declared: string;
+    [key: string]: string;
+}
+
+declare const foo: Foo;
+
+foo.declared;
+foo.other;
+foo["other"];
+foo.other = "";
+
+declare const bar: { [key: string]: number } | { [key: string]: number };
+
+bar.other;
@@ -0,0 +1,9 @@
+// @noPropertyAccessFromIndexSignature: false
+
+interface Foo {
+    [key: string]: string;
+}
+
+declare const foo: Foo;
+
+export const a: string = foo.other;
@@ -0,0 +1,22 @@
+// @strict: true
+// @noUncheckedIndexedAccess: true
+
+declare const rec: { [key: string]: number };
+declare const arr: number[];
+declare const tuple: [number, string];
+declare const key: string;
+
+const a: number = rec[key];
+const b: number = rec.foo;
+const c: number = arr[0];
+const d: number = tuple[0];
+const e: number | undefined = arr[0];
+
+rec[key] = 1;
+arr[0] = 1;
+
+for (const x of arr) {
+    const f: number = x;
+}
+
+export {};
@@ -0,0 +1,25 @@
+// @strict: true
+// @noUncheckedIndexedAccess: true
+
+declare const arr: number[];
+declare const tuple: [number, string];
+declare const restTuple: [number, ...string[]];
+
+const [a] = arr;
+const n1: number = a;
+
+const [t1, t2] = tuple;
+const n2: number = t1;
+const s2: string = t2;
+
+const [r1, r2] = restTuple;
+const n3: number = r1;
+const s3: string = r2;
+
+let p: number;
+[p] = arr;
+
+let q: number;
+[q] = tuple;
+
+export {};
@@ -0,0 +1,16 @@
+// @strict: true
+// @noUncheckedIndexedAccess: true
+
+declare const arr: number[];
+declare const rec: { [key: string]: number };
+
+for (const i in arr) {
+    const n: number = arr[i];
+    const m: number | undefined = arr[i];
+}
+
+for (const k in rec) {
+    const n: number = rec[k];
+}
+
+export {};
@@ -0,0 +1,20 @@
+// @strict: true
+// @noUncheckedIndexedAccess: true
+
+declare const rec: { [key: string]: number };
+declare const key: string;
+
+if (key in rec) {
+    const n: number = rec[key];
+}
+
+if ("foo" in rec) {
+    const n: number = rec.foo;
+}
+
+const value = rec[key];
+if (value !== undefined) {
+    const n: number = value;
+}
+
+export {};
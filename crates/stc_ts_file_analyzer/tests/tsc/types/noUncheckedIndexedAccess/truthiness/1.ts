@@ -0,0 +1,27 @@
+// @strict: true
+// @noUncheckedIndexedAccess: true
+
+declare const rec: { [key: string]: number };
+declare const key: string;
+
+if (rec.foo) {
+    const n: number = rec.foo;
+}
+
+if (rec[key]) {
+    const n: number = rec[key];
+}
+
+if (rec[key] && rec.foo) {
+    const n: number = rec[key];
+    const m: number = rec.foo;
+}
+
+if (rec[key]) {
+} else {
+    const n: number = rec[key];
+}
+
+const n: number = rec[key];
+
+export {};
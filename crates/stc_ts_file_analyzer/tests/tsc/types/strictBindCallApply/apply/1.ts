@@ -0,0 +1,7 @@
+// @strictBindCallApply: true
+
+declare function foo(a: number, b: string): string;
+
+export const a: string = foo.apply(undefined, [10, "hello"]);
+foo.apply(undefined, [10, 20]);
+export const b: number = foo.apply(undefined, [10, "hello"]);
@@ -0,0 +1,8 @@
+// @strictBindCallApply: true
+
+declare function foo(a: number, b: string): string;
+
+export const f1: (b: string) => string = foo.bind(undefined, 10);
+export const f2: () => string = foo.bind(undefined, 10, "hello");
+export const f3: (a: number, b: string) => string = foo.bind(undefined);
+export const f4: (b: number) => string = foo.bind(undefined, 10);
@@ -0,0 +1,8 @@
+// @strictBindCallApply: true
+
+declare function foo(a: number, b: string): string;
+
+export const a: string = foo.call(undefined, 10, "hello");
+foo.call(undefined, 10, 20);
+foo.call(undefined, 10);
+export const b: number = foo.call(undefined, 10, "hello");
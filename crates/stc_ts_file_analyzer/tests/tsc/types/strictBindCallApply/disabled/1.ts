@@ -0,0 +1,7 @@
+// @strictBindCallApply: false
+
+declare function foo(a: number, b: string): string;
+
+// Without `strictBindCallApply`, `Function` is used and arguments are not checked.
+export const a: number = foo.call(undefined, 10, 20);
+export const b: number = foo.apply(undefined, [10, 20]);
@@ -0,0 +1,7 @@
+// @strictBindCallApply: true
+
+declare function foo(this: { x: number }, a: number): number;
+
+export const a: number = foo.call({ x: 1 }, 10);
+foo.call({ x: "" }, 10);
+export const b: number = foo.apply({ x: 1 }, [10]);
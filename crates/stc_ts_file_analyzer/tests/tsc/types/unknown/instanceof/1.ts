@@ -0,0 +1,20 @@
+// @strict: true
+
+class Foo {
+    foo = 1;
+}
+
+declare const u: unknown;
+
+if (u instanceof Error) {
+    const a: string = u.message;
+    const b: number = u;
+}
+
+if (u instanceof Foo) {
+    const c: number = u.foo;
+}
+
+const d: number = u;
+
+export {};
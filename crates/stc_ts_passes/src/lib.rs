@@ -1,7 +1,10 @@
 #![feature(specialization)]
 #![allow(incomplete_features)]
 
-pub use self::{pass::Pass, passes::*};
+pub use self::{
+    pass::{Pass, TypeQuery},
+    passes::*,
+};
 
 mod pass;
 mod passes;
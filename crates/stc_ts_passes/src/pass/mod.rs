@@ -1,9 +1,41 @@
-use rnode::Visit;
+use fxhash::FxHashMap;
+use rnode::{NodeId, Visit};
 use stc_ts_ast_rnode::{RModule, RProgram, RScript};
+use stc_ts_dts_mutations::Mutations;
 use stc_ts_errors::ErrorKind;
+use stc_ts_types::Type;
 
 pub trait Pass: Visit<RModule> + Visit<RScript> + Visit<RProgram> {
-    fn name() -> &'static str;
+    fn name() -> &'static str
+    where
+        Self: Sized;
 
     fn take_errors(&mut self) -> Vec<ErrorKind>;
 }
+
+/// Gives passes access to the types computed by the analyzer.
+pub trait TypeQuery {
+    /// Returns the type of an expression, if it's validated by the analyzer.
+    fn type_of_expr(&self, node_id: NodeId) -> Option<&Type>;
+
+    /// Returns the contextual type of an expression, if it has one.
+    fn contextual_type_of_expr(&self, _node_id: NodeId) -> Option<&Type> {
+        None
+    }
+}
+
+impl TypeQuery for FxHashMap<NodeId, Type> {
+    fn type_of_expr(&self, node_id: NodeId) -> Option<&Type> {
+        self.get(&node_id)
+    }
+}
+
+impl TypeQuery for Mutations {
+    fn type_of_expr(&self, node_id: NodeId) -> Option<&Type> {
+        self.expr_types.get(&node_id)
+    }
+
+    fn contextual_type_of_expr(&self, node_id: NodeId) -> Option<&Type> {
+        self.expr_contextual_types.get(&node_id)
+    }
+}
@@ -1 +1,57 @@
+//! Type-aware lint rules.
+//!
+//! Rules are selected by name and run by the checker after a module is
+//! analyzed.
 
+use rnode::Visit;
+use stc_ts_ast_rnode::RModule;
+use stc_ts_errors::ErrorKind;
+
+pub use self::{
+    no_floating_promises::NoFloatingPromises, no_misused_promises::NoMisusedPromises, no_unnecessary_condition::NoUnnecessaryCondition,
+    strict_boolean_expressions::StrictBooleanExpressions,
+};
+use crate::{Pass, TypeQuery};
+
+mod no_floating_promises;
+mod no_misused_promises;
+mod no_unnecessary_condition;
+mod strict_boolean_expressions;
+mod util;
+
+/// Names of all lint rules.
+pub const LINTS: &[&str] = &[
+    "no-floating-promises",
+    "no-misused-promises",
+    "strict-boolean-expressions",
+    "no-unnecessary-condition",
+];
+
+/// Creates a lint rule by name.
+///
+/// Returns [None] if `name` is not in [LINTS].
+pub fn create_lint<'a>(name: &str, types: &'a dyn TypeQuery) -> Option<Box<dyn Pass + 'a>> {
+    Some(match name {
+        "no-floating-promises" => Box::new(NoFloatingPromises::new(types)),
+        "no-misused-promises" => Box::new(NoMisusedPromises::new(types)),
+        "strict-boolean-expressions" => Box::new(StrictBooleanExpressions::new(types)),
+        "no-unnecessary-condition" => Box::new(NoUnnecessaryCondition::new(types)),
+        _ => return None,
+    })
+}
+
+/// Runs lint rules named `names` on `module`.
+///
+/// Unknown names are ignored.
+pub fn run_lints(module: &RModule, names: &[String], types: &dyn TypeQuery) -> Vec<ErrorKind> {
+    let mut errors = vec![];
+
+    for name in names {
+        if let Some(mut pass) = create_lint(name, types) {
+            Visit::<RModule>::visit(&mut *pass, module);
+            errors.extend(pass.take_errors());
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,76 @@
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{RCallee, RExpr, RExprStmt, RMemberProp};
+use stc_ts_errors::ErrorKind;
+use swc_common::Spanned;
+use swc_ecma_ast::op;
+
+use super::util::is_promise_like;
+use crate::{Pass, TypeQuery};
+
+/// Reports promises which are neither awaited nor handled.
+///
+/// A promise is considered handled if it's discarded using `void`, or if it
+/// ends with `.catch(...)` or `.then(...)` with a rejection handler.
+pub struct NoFloatingPromises<'a> {
+    types: &'a dyn TypeQuery,
+    errors: Vec<ErrorKind>,
+}
+
+impl<'a> NoFloatingPromises<'a> {
+    pub fn new(types: &'a dyn TypeQuery) -> Self {
+        Self { types, errors: vec![] }
+    }
+
+    fn is_handled(e: &RExpr) -> bool {
+        match e {
+            RExpr::Paren(e) => Self::is_handled(&e.expr),
+            RExpr::Unary(e) => e.op == op!("void"),
+            RExpr::Assign(..) | RExpr::Await(..) => true,
+            RExpr::Call(call) => match &call.callee {
+                RCallee::Expr(callee) => match &**callee {
+                    RExpr::Member(m) => match &m.prop {
+                        RMemberProp::Ident(prop) => &*prop.sym == "catch" || (&*prop.sym == "then" && call.args.len() >= 2),
+                        _ => false,
+                    },
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Pass for NoFloatingPromises<'_> {
+    fn name() -> &'static str {
+        "no-floating-promises"
+    }
+
+    fn take_errors(&mut self) -> Vec<ErrorKind> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+impl Visit<RExprStmt> for NoFloatingPromises<'_> {
+    fn visit(&mut self, s: &RExprStmt) {
+        s.visit_children_with(self);
+
+        if Self::is_handled(&s.expr) {
+            return;
+        }
+
+        let is_promise = s
+            .expr
+            .node_id()
+            .and_then(|node_id| self.types.type_of_expr(node_id))
+            .map_or(false, is_promise_like);
+
+        if is_promise {
+            self.errors.push(ErrorKind::Lint {
+                span: s.expr.span(),
+                rule: Self::name(),
+                msg: "Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.".into(),
+            });
+        }
+    }
+}
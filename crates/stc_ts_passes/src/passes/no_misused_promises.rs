@@ -0,0 +1,214 @@
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{
+    RCallExpr, RCallee, RExpr, RJSXAttr, RJSXAttrValue, RJSXExpr, RJSXExprContainer, RKeyValueProp, RModule, RObjectLit, RPat,
+    RPropOrSpread, RScript,
+};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::{FnParam, Type, TypeElement};
+use swc_common::Spanned;
+
+use super::util::{find_conditions, is_promise_like, ConditionFinder};
+use crate::{Pass, TypeQuery};
+
+/// Reports promises used in places not designed to handle them.
+///
+/// Currently, conditions, object spreads and promise-returning functions
+/// passed where a void-returning function is expected are checked.
+pub struct NoMisusedPromises<'a> {
+    types: &'a dyn TypeQuery,
+    errors: Vec<ErrorKind>,
+}
+
+impl<'a> NoMisusedPromises<'a> {
+    pub fn new(types: &'a dyn TypeQuery) -> Self {
+        Self { types, errors: vec![] }
+    }
+
+    fn check_conditions<N>(&mut self, node: &N)
+    where
+        N: VisitWith<ConditionFinder>,
+        ConditionFinder: Visit<N>,
+    {
+        for cond in find_conditions(node) {
+            if self.types.type_of_expr(cond.node_id).map_or(false, is_promise_like) {
+                self.errors.push(ErrorKind::Lint {
+                    span: cond.span,
+                    rule: Self::name(),
+                    msg: "Expected non-Promise value in a boolean conditional.".into(),
+                });
+            }
+        }
+    }
+
+    /// Reports `e` if it's a promise-returning function and `expected` is a
+    /// void-returning function type.
+    fn check_void_return(&mut self, e: &RExpr, expected: &Type, msg: &str) {
+        let returns_promise = e
+            .node_id()
+            .and_then(|node_id| self.types.type_of_expr(node_id))
+            .map_or(false, returns_promise);
+
+        if returns_promise && returns_void(expected) {
+            self.errors.push(ErrorKind::Lint {
+                span: e.span(),
+                rule: Self::name(),
+                msg: msg.into(),
+            });
+        }
+    }
+
+    /// Uses the contextual type of `e` as the expected type.
+    fn check_void_return_with_contextual_type(&mut self, e: &RExpr, msg: &str) {
+        let expected = e.node_id().and_then(|node_id| self.types.contextual_type_of_expr(node_id));
+
+        if let Some(expected) = expected {
+            self.check_void_return(e, expected, msg);
+        }
+    }
+}
+
+impl Pass for NoMisusedPromises<'_> {
+    fn name() -> &'static str {
+        "no-misused-promises"
+    }
+
+    fn take_errors(&mut self) -> Vec<ErrorKind> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+impl Visit<RModule> for NoMisusedPromises<'_> {
+    fn visit(&mut self, m: &RModule) {
+        self.check_conditions(m);
+        m.visit_children_with(self);
+    }
+}
+
+impl Visit<RScript> for NoMisusedPromises<'_> {
+    fn visit(&mut self, s: &RScript) {
+        self.check_conditions(s);
+        s.visit_children_with(self);
+    }
+}
+
+/// Spreads in JSX attributes and array literals are not checked.
+impl Visit<RObjectLit> for NoMisusedPromises<'_> {
+    fn visit(&mut self, e: &RObjectLit) {
+        e.visit_children_with(self);
+
+        for prop in &e.props {
+            let spread = match prop {
+                RPropOrSpread::Spread(spread) => spread,
+                RPropOrSpread::Prop(..) => continue,
+            };
+
+            let is_promise = spread
+                .expr
+                .node_id()
+                .and_then(|node_id| self.types.type_of_expr(node_id))
+                .map_or(false, is_promise_like);
+
+            if is_promise {
+                self.errors.push(ErrorKind::Lint {
+                    span: spread.expr.span(),
+                    rule: Self::name(),
+                    msg: "Expected a non-Promise value to be spreaded in an object.".into(),
+                });
+            }
+        }
+    }
+}
+
+impl Visit<RCallExpr> for NoMisusedPromises<'_> {
+    fn visit(&mut self, e: &RCallExpr) {
+        e.visit_children_with(self);
+
+        let callee = match &e.callee {
+            RCallee::Expr(callee) => callee,
+            _ => return,
+        };
+        let params = match callee
+            .node_id()
+            .and_then(|node_id| self.types.type_of_expr(node_id))
+            .map(Type::normalize)
+        {
+            Some(Type::Function(f)) => &f.params,
+            _ => return,
+        };
+        let params = params.iter().filter(|param| !is_this_param(param)).collect::<Vec<_>>();
+
+        for (arg, param) in e.args.iter().zip(params) {
+            // Rest parameters are not checked.
+            if arg.spread.is_some() || matches!(param.pat, RPat::Rest(..)) {
+                break;
+            }
+
+            self.check_void_return(
+                &arg.expr,
+                &param.ty,
+                "Promise returned in function argument where a void return was expected.",
+            );
+        }
+    }
+}
+
+impl Visit<RKeyValueProp> for NoMisusedPromises<'_> {
+    fn visit(&mut self, p: &RKeyValueProp) {
+        p.visit_children_with(self);
+
+        self.check_void_return_with_contextual_type(
+            &p.value,
+            "Promise-returning function provided to property where a void return was expected.",
+        );
+    }
+}
+
+impl Visit<RJSXAttr> for NoMisusedPromises<'_> {
+    fn visit(&mut self, attr: &RJSXAttr) {
+        attr.visit_children_with(self);
+
+        if let Some(RJSXAttrValue::JSXExprContainer(RJSXExprContainer {
+            expr: RJSXExpr::Expr(e), ..
+        })) = &attr.value
+        {
+            self.check_void_return_with_contextual_type(
+                e,
+                "Promise-returning function provided to attribute where a void return was expected.",
+            );
+        }
+    }
+}
+
+/// Returns `true` if `ty` is a function which returns a promise.
+fn returns_promise(ty: &Type) -> bool {
+    match ty.normalize_instance() {
+        Type::Function(f) => is_promise_like(&f.ret_ty),
+        Type::TypeLit(lit) => lit.members.iter().any(|m| match m {
+            TypeElement::Call(c) => c.ret_ty.as_deref().map_or(false, is_promise_like),
+            _ => false,
+        }),
+        Type::Union(u) => u.types.iter().any(returns_promise),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `ty` is a function which returns `void`.
+fn returns_void(ty: &Type) -> bool {
+    // Types of parameters are instances of their annotations.
+    match ty.normalize_instance() {
+        Type::Function(f) => f.ret_ty.is_void(),
+        Type::TypeLit(lit) => lit.members.iter().any(|m| match m {
+            TypeElement::Call(c) => c.ret_ty.as_deref().map_or(false, Type::is_void),
+            _ => false,
+        }),
+        Type::Union(u) => u.types.iter().any(returns_void),
+        _ => false,
+    }
+}
+
+fn is_this_param(param: &FnParam) -> bool {
+    match &param.pat {
+        RPat::Ident(i) => &*i.id.sym == "this",
+        _ => false,
+    }
+}
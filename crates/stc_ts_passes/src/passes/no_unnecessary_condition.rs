@@ -0,0 +1,61 @@
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{RModule, RScript};
+use stc_ts_errors::ErrorKind;
+
+use super::util::{find_conditions, truthiness, ConditionFinder};
+use crate::{Pass, TypeQuery};
+
+/// Reports conditions which are always truthy or always falsy according to
+/// their types.
+pub struct NoUnnecessaryCondition<'a> {
+    types: &'a dyn TypeQuery,
+    errors: Vec<ErrorKind>,
+}
+
+impl<'a> NoUnnecessaryCondition<'a> {
+    pub fn new(types: &'a dyn TypeQuery) -> Self {
+        Self { types, errors: vec![] }
+    }
+
+    fn check_conditions<N>(&mut self, node: &N)
+    where
+        N: VisitWith<ConditionFinder>,
+        ConditionFinder: Visit<N>,
+    {
+        for cond in find_conditions(node) {
+            let msg = match self.types.type_of_expr(cond.node_id).and_then(truthiness) {
+                Some(true) => "Unnecessary conditional, value is always truthy.",
+                Some(false) => "Unnecessary conditional, value is always falsy.",
+                None => continue,
+            };
+
+            self.errors.push(ErrorKind::Lint {
+                span: cond.span,
+                rule: Self::name(),
+                msg: msg.into(),
+            });
+        }
+    }
+}
+
+impl Pass for NoUnnecessaryCondition<'_> {
+    fn name() -> &'static str {
+        "no-unnecessary-condition"
+    }
+
+    fn take_errors(&mut self) -> Vec<ErrorKind> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+impl Visit<RModule> for NoUnnecessaryCondition<'_> {
+    fn visit(&mut self, m: &RModule) {
+        self.check_conditions(m);
+    }
+}
+
+impl Visit<RScript> for NoUnnecessaryCondition<'_> {
+    fn visit(&mut self, s: &RScript) {
+        self.check_conditions(s);
+    }
+}
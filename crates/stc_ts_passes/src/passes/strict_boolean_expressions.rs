@@ -0,0 +1,149 @@
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{RModule, RScript, RTsLit};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::{KeywordType, LitType, Type};
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use super::util::{find_conditions, ConditionFinder};
+use crate::{Pass, TypeQuery};
+
+/// Reports conditions of type `any` and nullable primitives, which are easy to
+/// misuse because `""`, `0` and `false` are falsy.
+///
+/// Strings, numbers and nullable objects are allowed.
+pub struct StrictBooleanExpressions<'a> {
+    types: &'a dyn TypeQuery,
+    errors: Vec<ErrorKind>,
+}
+
+#[derive(Default)]
+struct Kinds {
+    any: bool,
+    nullish: bool,
+    boolean: bool,
+    string: bool,
+    number: bool,
+    object: bool,
+    /// We can't tell the kind of the type.
+    other: bool,
+}
+
+impl Kinds {
+    fn add(&mut self, ty: &Type) {
+        match ty.normalize() {
+            Type::Keyword(KeywordType { kind, .. }) => match kind {
+                TsKeywordTypeKind::TsAnyKeyword => self.any = true,
+                TsKeywordTypeKind::TsNullKeyword | TsKeywordTypeKind::TsUndefinedKeyword | TsKeywordTypeKind::TsVoidKeyword => {
+                    self.nullish = true
+                }
+                TsKeywordTypeKind::TsBooleanKeyword => self.boolean = true,
+                TsKeywordTypeKind::TsStringKeyword => self.string = true,
+                TsKeywordTypeKind::TsNumberKeyword | TsKeywordTypeKind::TsBigIntKeyword => self.number = true,
+                TsKeywordTypeKind::TsObjectKeyword | TsKeywordTypeKind::TsSymbolKeyword => self.object = true,
+                TsKeywordTypeKind::TsNeverKeyword => {}
+                _ => self.other = true,
+            },
+            Type::Lit(LitType { lit, .. }) => match lit {
+                RTsLit::Str(..) | RTsLit::Tpl(..) => self.string = true,
+                RTsLit::Number(..) | RTsLit::BigInt(..) => self.number = true,
+                RTsLit::Bool(..) => self.boolean = true,
+            },
+            Type::Union(u) => {
+                for ty in &u.types {
+                    self.add(ty);
+                }
+            }
+            Type::TypeLit(..)
+            | Type::Interface(..)
+            | Type::Class(..)
+            | Type::ClassDef(..)
+            | Type::Function(..)
+            | Type::Constructor(..)
+            | Type::Array(..)
+            | Type::Tuple(..)
+            | Type::Symbol(..) => self.object = true,
+            _ => self.other = true,
+        }
+    }
+
+    fn error_msg(&self) -> Option<&'static str> {
+        if self.any {
+            return Some("Unexpected any value in conditional. An explicit comparison or type cast is required.");
+        }
+        if self.other {
+            return None;
+        }
+
+        if !self.nullish {
+            return None;
+        }
+
+        if !self.boolean && !self.string && !self.number && !self.object {
+            return Some("Unexpected nullish value in conditional. The condition is always false.");
+        }
+
+        if self.boolean {
+            return Some("Unexpected nullable boolean value in conditional. Please handle the nullish case explicitly.");
+        }
+        if self.string {
+            return Some("Unexpected nullable string value in conditional. Please handle the nullish/empty cases explicitly.");
+        }
+        if self.number {
+            return Some("Unexpected nullable number value in conditional. Please handle the nullish/zero/NaN cases explicitly.");
+        }
+
+        None
+    }
+}
+
+impl<'a> StrictBooleanExpressions<'a> {
+    pub fn new(types: &'a dyn TypeQuery) -> Self {
+        Self { types, errors: vec![] }
+    }
+
+    fn check_conditions<N>(&mut self, node: &N)
+    where
+        N: VisitWith<ConditionFinder>,
+        ConditionFinder: Visit<N>,
+    {
+        for cond in find_conditions(node) {
+            let ty = match self.types.type_of_expr(cond.node_id) {
+                Some(ty) => ty,
+                None => continue,
+            };
+
+            let mut kinds = Kinds::default();
+            kinds.add(ty);
+
+            if let Some(msg) = kinds.error_msg() {
+                self.errors.push(ErrorKind::Lint {
+                    span: cond.span,
+                    rule: Self::name(),
+                    msg: msg.into(),
+                });
+            }
+        }
+    }
+}
+
+impl Pass for StrictBooleanExpressions<'_> {
+    fn name() -> &'static str {
+        "strict-boolean-expressions"
+    }
+
+    fn take_errors(&mut self) -> Vec<ErrorKind> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+impl Visit<RModule> for StrictBooleanExpressions<'_> {
+    fn visit(&mut self, m: &RModule) {
+        self.check_conditions(m);
+    }
+}
+
+impl Visit<RScript> for StrictBooleanExpressions<'_> {
+    fn visit(&mut self, s: &RScript) {
+        self.check_conditions(s);
+    }
+}
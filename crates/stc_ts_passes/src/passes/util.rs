@@ -0,0 +1,169 @@
+use rnode::{NodeId, Visit, VisitWith};
+use stc_ts_ast_rnode::{RBinExpr, RCondExpr, RDoWhileStmt, RExpr, RForStmt, RIfStmt, RTsEntityName, RTsLit, RUnaryExpr, RWhileStmt};
+use stc_ts_types::{KeywordType, LitType, Type};
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{op, TsKeywordTypeKind};
+
+/// Returns `true` if `ty` is `Promise<T>` or `PromiseLike<T>`, or a union
+/// containing one of them.
+pub(super) fn is_promise_like(ty: &Type) -> bool {
+    fn is_promise_name(sym: &str) -> bool {
+        sym == "Promise" || sym == "PromiseLike"
+    }
+
+    match ty.normalize() {
+        Type::Ref(r) => match &r.type_name {
+            RTsEntityName::Ident(i) => is_promise_name(&i.sym),
+            _ => false,
+        },
+        Type::Interface(i) => is_promise_name(i.name.sym()),
+        Type::Union(u) => u.types.iter().any(is_promise_like),
+        Type::Intersection(i) => i.types.iter().any(is_promise_like),
+        _ => false,
+    }
+}
+
+/// Returns `Some(true)` if a value of `ty` is always truthy, `Some(false)` if
+/// it's always falsy and [None] if it can be both or we don't know.
+pub(super) fn truthiness(ty: &Type) -> Option<bool> {
+    match ty.normalize() {
+        Type::Keyword(KeywordType { kind, .. }) => match kind {
+            TsKeywordTypeKind::TsNullKeyword | TsKeywordTypeKind::TsUndefinedKeyword | TsKeywordTypeKind::TsVoidKeyword => Some(false),
+            TsKeywordTypeKind::TsObjectKeyword | TsKeywordTypeKind::TsSymbolKeyword => Some(true),
+            _ => None,
+        },
+        Type::Lit(LitType { lit, .. }) => match lit {
+            RTsLit::Str(s) => Some(!s.value.is_empty()),
+            RTsLit::Number(n) => Some(n.value != 0.0 && !n.value.is_nan()),
+            RTsLit::Bool(b) => Some(b.value),
+            RTsLit::BigInt(b) => Some(b.value.to_string() != "0"),
+            RTsLit::Tpl(..) => None,
+        },
+        Type::TypeLit(..)
+        | Type::Interface(..)
+        | Type::Class(..)
+        | Type::ClassDef(..)
+        | Type::Function(..)
+        | Type::Constructor(..)
+        | Type::Array(..)
+        | Type::Tuple(..)
+        | Type::Symbol(..) => Some(true),
+        Type::Union(u) => {
+            let mut types = u.types.iter().map(truthiness);
+            let first = types.next()??;
+            if types.all(|v| v == Some(first)) {
+                Some(first)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// An expression used as a condition.
+pub(super) struct Condition {
+    pub span: Span,
+    pub node_id: NodeId,
+}
+
+/// Finds expressions used as conditions.
+///
+/// Operands of `&&`, `||` and `!` are treated as conditions instead of the
+/// expression itself.
+pub(super) fn find_conditions<N>(node: &N) -> Vec<Condition>
+where
+    N: VisitWith<ConditionFinder>,
+    ConditionFinder: Visit<N>,
+{
+    let mut v = ConditionFinder { conditions: vec![] };
+    node.visit_with(&mut v);
+
+    let mut conditions = v.conditions;
+    conditions.sort_by_key(|c| c.node_id);
+    conditions.dedup_by_key(|c| c.node_id);
+    conditions
+}
+
+pub(super) struct ConditionFinder {
+    conditions: Vec<Condition>,
+}
+
+impl ConditionFinder {
+    fn add(&mut self, e: &RExpr) {
+        match e {
+            RExpr::Paren(e) => self.add(&e.expr),
+            RExpr::Bin(RBinExpr {
+                op: op!("&&") | op!("||"),
+                left,
+                right,
+                ..
+            }) => {
+                self.add(left);
+                self.add(right);
+            }
+            RExpr::Unary(RUnaryExpr { op: op!("!"), arg, .. }) => self.add(arg),
+            _ => {
+                if let Some(node_id) = e.node_id() {
+                    self.conditions.push(Condition { span: e.span(), node_id });
+                }
+            }
+        }
+    }
+}
+
+impl Visit<RIfStmt> for ConditionFinder {
+    fn visit(&mut self, s: &RIfStmt) {
+        self.add(&s.test);
+        s.visit_children_with(self);
+    }
+}
+
+impl Visit<RWhileStmt> for ConditionFinder {
+    fn visit(&mut self, s: &RWhileStmt) {
+        self.add(&s.test);
+        s.visit_children_with(self);
+    }
+}
+
+impl Visit<RDoWhileStmt> for ConditionFinder {
+    fn visit(&mut self, s: &RDoWhileStmt) {
+        self.add(&s.test);
+        s.visit_children_with(self);
+    }
+}
+
+impl Visit<RForStmt> for ConditionFinder {
+    fn visit(&mut self, s: &RForStmt) {
+        if let Some(test) = &s.test {
+            self.add(test);
+        }
+        s.visit_children_with(self);
+    }
+}
+
+impl Visit<RCondExpr> for ConditionFinder {
+    fn visit(&mut self, e: &RCondExpr) {
+        self.add(&e.test);
+        e.visit_children_with(self);
+    }
+}
+
+impl Visit<RUnaryExpr> for ConditionFinder {
+    fn visit(&mut self, e: &RUnaryExpr) {
+        if e.op == op!("!") {
+            self.add(&e.arg);
+        }
+        e.visit_children_with(self);
+    }
+}
+
+/// The left operand of `&&` and `||` is always used as a condition.
+impl Visit<RBinExpr> for ConditionFinder {
+    fn visit(&mut self, e: &RBinExpr) {
+        if matches!(e.op, op!("&&") | op!("||")) {
+            self.add(&e.left);
+        }
+        e.visit_children_with(self);
+    }
+}
@@ -39,6 +39,12 @@ pub trait TypeStore: Send + Sync {
     fn export_type(&mut self, span: Span, ctxt: ModuleId, id: JsWord, ty: Type);
     fn export_var(&mut self, span: Span, ctxt: ModuleId, id: JsWord, ty: Type);
 
+    /// Marks the module as a module using `export =`.
+    ///
+    /// `needs_default_import` should be `true` if the target of `export =` is
+    /// not a variable or a namespace.
+    fn mark_export_equals(&mut self, ctxt: ModuleId, needs_default_import: bool);
+
     fn take_info(&mut self, ctxt: ModuleId) -> ModuleTypeData;
 }
 
@@ -188,6 +194,13 @@ impl TypeStore for Single<'_> {
         // TODO(kdy1): error reporting for duplicate
         self.info.exports.vars.insert(id, ty);
     }
+
+    fn mark_export_equals(&mut self, ctxt: ModuleId, needs_default_import: bool) {
+        debug_assert_eq!(ctxt, self.id);
+
+        self.info.exports.has_export_equals = true;
+        self.info.exports.export_equals_needs_default_import = needs_default_import;
+    }
 }
 
 impl<'a> Mode for Single<'a> {
@@ -332,6 +345,12 @@ impl TypeStore for Group<'_> {
         // TODO(kdy1): Error reporting for duplicates
         self.info.entry(ctxt).or_default().vars.insert(id, ty);
     }
+
+    fn mark_export_equals(&mut self, ctxt: ModuleId, needs_default_import: bool) {
+        let info = self.info.entry(ctxt).or_default();
+        info.has_export_equals = true;
+        info.export_equals_needs_default_import = needs_default_import;
+    }
 }
 
 impl Mode for Group<'_> {
@@ -451,6 +470,8 @@ impl TypeStore for Builtin {
     fn export_type(&mut self, _: Span, _: ModuleId, _: JsWord, _: Type) {}
 
     fn export_var(&mut self, _: Span, _: ModuleId, _: JsWord, _: Type) {}
+
+    fn mark_export_equals(&mut self, _: ModuleId, _: bool) {}
 }
 
 impl Mode for Builtin {
@@ -15,8 +15,9 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rustc_hash::FxHashSet;
 use stc_ts_builtin_types::Lib;
-use stc_ts_env::{JsxMode, ModuleConfig, Rule};
+use stc_ts_env::{option_kind, JsxMode, ModuleConfig, OptionKind, Rule};
 use stc_ts_utils::StcComments;
+use swc_atoms::JsWord;
 use swc_common::{input::SourceFileInput, BytePos, Spanned};
 use swc_ecma_ast::{EsVersion, Program};
 use swc_ecma_parser::{Parser, Syntax, TsConfig};
@@ -41,6 +42,14 @@ pub struct TestSpec {
 
     /// Library types defined by `@libFiles`
     pub lib_files: Vec<PathBuf>,
+
+    /// Defined by `@jsxImportSource`
+    pub jsx_import_source: Option<JsWord>,
+
+    /// Options which change the expected errors but are not supported yet.
+    /// Tests comparing errors with `tsc` should be skipped if this is not
+    /// empty.
+    pub unsupported_options: Vec<String>,
 }
 
 fn parse_sub_files(source: &str) -> Vec<(String, String)> {
@@ -126,6 +135,8 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
         }
 
         let mut jsx_options: Vec<(_, JsxMode)> = vec![];
+        let mut jsx_import_source = None;
+        let mut rule_directives: Vec<(String, Vec<(String, bool)>)> = vec![];
         let mut libs = vec![Lib::Es5, Lib::Dom];
         let mut rule = Rule {
             allow_unreachable_code: false,
@@ -134,6 +145,7 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
         let mut module_config = vec![("".into(), ModuleConfig::None)];
         let ts_config = TsConfig::default();
         let mut lib_files = vec![];
+        let mut unsupported_options = vec![];
 
         let mut had_comment = false;
 
@@ -164,100 +176,89 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
                 err_shift_n = cm.lookup_char_pos(cmt.span.hi + BytePos(1)).line - cmt_start_line;
                 let s = &s[1..]; // '@'
 
-                if s.starts_with("target:") || s.starts_with("Target:") {
-                    let s = s["target:".len()..].trim().to_lowercase();
-                    targets = parse_targets(&s).into_iter().map(|v| (v.0, v.1, true)).collect();
-                } else if s.starts_with("strict:") {
-                    let strict = s["strict:".len()..].trim().parse().unwrap();
-                    rule.no_implicit_any = strict;
-                    rule.no_implicit_this = strict;
-                    rule.always_strict = strict;
-                    rule.strict_null_checks = strict;
-                    rule.strict_function_types = strict;
-                } else if s.starts_with("noLib:") {
-                    let v = s["noLib:".len()..].trim().parse().unwrap();
-                    rule.no_lib = v;
-                    if v {
-                        libs = vec![];
+                let (name, value) = match s.find(':') {
+                    Some(idx) => (s[..idx].trim(), s[idx + 1..].trim()),
+                    None => (s.trim(), ""),
+                };
+                let lower_name = name.to_ascii_lowercase();
+
+                match &*lower_name {
+                    "target" => {
+                        targets = parse_targets(&value.to_lowercase()).into_iter().map(|v| (v.0, v.1, true)).collect();
+                    }
+                    "module" => {
+                        module_config = parse_directive_values(value, &|s| s.parse().unwrap());
+                    }
+                    "lib" => {
+                        let mut ls = FxHashSet::<_>::default();
+                        for v in value.split(',') {
+                            ls.extend(Lib::load(&v.to_lowercase().replace("es6", "es2015")))
+                        }
+                        libs = ls.into_iter().collect()
+                    }
+                    "libfiles" => {
+                        lib_files = value
+                            .split(',')
+                            .map(|s| env::current_dir().unwrap().join("tests").join("libs").join(s))
+                            .collect();
+                        lib_files.sort();
+                    }
+                    "nolib" => {
+                        let v = value.parse().unwrap();
+                        rule.no_lib = v;
+                        if v {
+                            libs = vec![];
+                        }
                     }
-                } else if s.to_lowercase().starts_with(&"noImplicitAny:".to_ascii_lowercase()) {
-                    let v = s["noImplicitAny:".len()..].trim().parse().unwrap();
-                    rule.no_implicit_any = v;
-                } else if s.starts_with("noImplicitReturns:") {
-                    let v = s["noImplicitReturns:".len()..].trim().parse().unwrap();
-                    rule.no_implicit_returns = v;
-                } else if s.starts_with("declaration") {
-                } else if s.starts_with("stripInternal:") {
-                    // TODO(kdy1): Handle
-                } else if s.starts_with("traceResolution") {
-                    // no-op
-                } else if s.starts_with("allowUnusedLabels:") {
-                    let v = s["allowUnusedLabels:".len()..].trim().parse().unwrap();
-                    rule.allow_unused_labels = v;
-                } else if s.starts_with("noEmitHelpers") {
-                    // TODO
-                } else if s.starts_with("downlevelIteration:") {
-                    // TODO
-                } else if s.starts_with("sourceMap:") || s.starts_with("sourcemap:") {
-                    // TODO
-                } else if s.starts_with("isolatedModules:") {
-                    // TODO
-                } else if s.starts_with("lib:") {
-                    let s = s["lib:".len()..].trim();
-                    let mut ls = FxHashSet::<_>::default();
-                    for v in s.split(',') {
-                        ls.extend(Lib::load(&v.to_lowercase().replace("es6", "es2015")))
+                    "jsx" => {
+                        jsx_options = parse_directive_values(value, &|s| s.to_ascii_lowercase().parse().unwrap());
                     }
-                    libs = ls.into_iter().collect()
-                } else if s.starts_with("libFiles:") {
-                    lib_files = s["libFiles:".len()..]
-                        .trim()
-                        .split(',')
-                        .map(|s| env::current_dir().unwrap().join("tests").join("libs").join(s))
-                        .collect();
-                    lib_files.sort();
-                } else if s.starts_with("allowUnreachableCode:") {
-                    let v = s["allowUnreachableCode:".len()..].trim().parse().unwrap();
-                    rule.allow_unreachable_code = v;
-                } else if s.starts_with("strictNullChecks:") {
-                    let v = s["strictNullChecks:".len()..].trim().parse().unwrap();
-                    rule.strict_null_checks = v;
-                } else if s.starts_with("noImplicitThis:") {
-                    let v = s["noImplicitThis:".len()..].trim().parse().unwrap();
-                    rule.no_implicit_this = v;
-                } else if s.starts_with("skipDefaultLibCheck") {
-                    // TODO
-                } else if s.starts_with("suppressImplicitAnyIndexErrors:") {
-                    // TODO
-                    let v = s["suppressImplicitAnyIndexErrors:".len()..].trim().parse().unwrap();
-                    rule.suppress_implicit_any_index_errors = v;
-                } else if s.starts_with("module:") {
-                    module_config = parse_directive_values(&s["module:".len()..], &|s| s.parse().unwrap());
-                } else if s.to_lowercase().starts_with("notypesandsymbols") {
-                    // Ignored as we don't generate them.
-                } else if s.to_lowercase().starts_with("usedefineforclassfields") {
-                    rule.use_define_property_for_class_fields = true;
-                } else if s.to_lowercase().starts_with("jsx") {
-                    jsx_options = parse_directive_values(&s["jsx:".len()..], &|s| s.to_ascii_lowercase().parse().unwrap());
-                } else if s.to_lowercase().starts_with("noemit") || s.to_lowercase().starts_with("preserveconstenums") {
-                    // Ignored as we only checks type.
-                } else if s.starts_with("strict") {
-                    let strict = true;
-                    rule.no_implicit_any = strict;
-                    rule.no_implicit_this = strict;
-                    rule.always_strict = strict;
-                    rule.strict_null_checks = strict;
-                    rule.strict_function_types = strict;
-                } else if s.to_ascii_lowercase().starts_with("filename") {
-                } else if s.to_ascii_lowercase().starts_with("allowjs") || s.to_ascii_lowercase().starts_with("checkjs") {
-                    // panic!("allowJs and checkJs are not supported yet. See https://github.com/dudykr/stc/issues/702")
-                    return Err(());
-                } else {
-                    writeln!(stderr(), "Comment is not handled: {}", s).unwrap();
+                    "jsximportsource" => {
+                        jsx_import_source = Some(value.into());
+                    }
+                    "filename" => {}
+                    _ => match option_kind(name) {
+                        Some(OptionKind::Rule) => {
+                            let values = if value.is_empty() {
+                                vec![("true".into(), true)]
+                            } else {
+                                parse_directive_values(value, &|s| s.to_ascii_lowercase().parse().unwrap())
+                            };
+                            rule_directives.push((lower_name, values));
+                        }
+                        // Options stored in `Env` are handled above.
+                        Some(OptionKind::Env) | Some(OptionKind::Ignored) => {}
+                        // For `allowJs` and `checkJs`, see https://github.com/dudykr/stc/issues/702
+                        Some(OptionKind::Unsupported) => {
+                            if !value.eq_ignore_ascii_case("false") {
+                                unsupported_options.push(name.to_string());
+                            }
+                        }
+                        None => {
+                            writeln!(stderr(), "Unknown compiler option: {}", s).unwrap();
+                        }
+                    },
                 }
             }
         }
 
+        // Flags like `strictNullChecks` override `strict`, like `tsc`.
+        rule_directives.sort_by_key(|(name, _)| name != "strict");
+        // The first option with multiple values creates a test for each value.
+        let variant_idx = rule_directives.iter().position(|(_, values)| values.len() > 1);
+        let build_rule = |variant: Option<bool>| {
+            let mut rule = rule;
+            for (idx, (name, values)) in rule_directives.iter().enumerate() {
+                let value = match variant {
+                    Some(value) if Some(idx) == variant_idx => value,
+                    _ => values[0].1,
+                };
+                rule.set_option(name, value);
+            }
+            rule
+        };
+        let rule = build_rule(None);
+
         libs.sort();
 
         err_shift_n = err_shift_n.min(first_stmt_line);
@@ -284,6 +285,8 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
                         module_config: module_config[0].1,
                         sub_files: sub_files.clone(),
                         lib_files: lib_files.clone(),
+                        jsx_import_source: jsx_import_source.clone(),
+                        unsupported_options: unsupported_options.clone(),
                     }
                 })
                 .collect());
@@ -306,6 +309,8 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
                     module_config,
                     sub_files: sub_files.clone(),
                     lib_files: lib_files.clone(),
+                    jsx_import_source: jsx_import_source.clone(),
+                    unsupported_options: unsupported_options.clone(),
                 })
                 .collect());
         }
@@ -325,6 +330,33 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
                     module_config,
                     sub_files: sub_files.clone(),
                     lib_files: lib_files.clone(),
+                    jsx_import_source: jsx_import_source.clone(),
+                    unsupported_options: unsupported_options.clone(),
+                })
+                .collect());
+        }
+
+        if let Some(variant_idx) = variant_idx {
+            let (name, values) = &rule_directives[variant_idx];
+
+            return Ok(values
+                .iter()
+                .map(|(raw, value)| {
+                    let rule = build_rule(Some(*value));
+
+                    TestSpec {
+                        err_shift_n,
+                        libs: libs.clone(),
+                        rule,
+                        ts_config,
+                        target,
+                        suffix: format!("({}={})", name, raw),
+                        module_config,
+                        sub_files: sub_files.clone(),
+                        lib_files: lib_files.clone(),
+                        jsx_import_source: jsx_import_source.clone(),
+                        unsupported_options: unsupported_options.clone(),
+                    }
                 })
                 .collect());
         }
@@ -339,6 +371,8 @@ pub fn parse_conformance_test(file_name: &Path) -> Result<Vec<TestSpec>> {
             module_config,
             sub_files,
             lib_files,
+            jsx_import_source,
+            unsupported_options,
         }])
     })
     .map_err(|err| anyhow!("Failed to parse test case: {}", err))
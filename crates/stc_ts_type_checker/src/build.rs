@@ -0,0 +1,602 @@
+//! Building projects using project references, like `tsc --build`.
+//!
+//! Projects are checked in topological order, and projects which don't
+//! depend on each other are checked in parallel. A project sees the files of
+//! referenced projects as `.d.ts` files generated while checking them, or
+//! emitted by a previous build if the referenced project is up to date.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{bail, Context, Result};
+use dashmap::DashMap;
+use fxhash::{FxBuildHasher, FxHashMap};
+use rayon::prelude::*;
+use stc_ts_builtin_types::Lib;
+use stc_ts_errors::Error;
+use stc_ts_module_loader::resolvers::node::NodeResolver;
+use swc_common::{errors::Handler, FileName, SourceFile, SourceMap};
+use swc_ecma_ast::{EsVersion, Module};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{Syntax, TsConfig as ParserConfig};
+
+use crate::{
+    loader::{DefaultFileLoader, LoadFile, ModuleLoader},
+    tsconfig::{normalize, TsConfig},
+    Checker,
+};
+
+/// Name of the file storing the hash of a project, relative to the output
+/// directory.
+const STAMP_FILE: &str = "tsconfig.stcbuildinfo";
+
+#[derive(Debug)]
+pub struct Project {
+    /// Path to the `tsconfig.json` file.
+    pub config_path: PathBuf,
+    pub dir: PathBuf,
+    pub config: TsConfig,
+    /// Indices of referenced projects in [ProjectGraph::projects].
+    pub references: Vec<usize>,
+}
+
+/// Projects sorted in topological order, so referenced projects come first.
+#[derive(Debug, Default)]
+pub struct ProjectGraph {
+    pub projects: Vec<Project>,
+}
+
+impl ProjectGraph {
+    /// Loads `config_path` and referenced projects, recursively.
+    ///
+    /// `config_path` can be a directory containing `tsconfig.json`.
+    pub fn load(config_path: &Path) -> Result<Self> {
+        let mut graph = ProjectGraph::default();
+        let mut stack = vec![];
+        graph.load_project(config_path, &mut stack)?;
+        Ok(graph)
+    }
+
+    fn load_project(&mut self, path: &Path, stack: &mut Vec<PathBuf>) -> Result<usize> {
+        let config_path = if path.is_dir() {
+            path.join("tsconfig.json")
+        } else {
+            path.to_path_buf()
+        };
+        let config_path = normalize(&config_path);
+
+        if let Some(idx) = self.projects.iter().position(|p| p.config_path == config_path) {
+            return Ok(idx);
+        }
+        if stack.contains(&config_path) {
+            bail!("project references form a cycle: `{}`", config_path.display())
+        }
+
+        let config = TsConfig::load(&config_path)?;
+        let dir = config_path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        stack.push(config_path.clone());
+        let references = config
+            .references
+            .iter()
+            .map(|r| {
+                self.load_project(&dir.join(&r.path), stack)
+                    .with_context(|| format!("failed to load project referenced by `{}`", config_path.display()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        stack.pop();
+
+        self.projects.push(Project {
+            config_path,
+            dir,
+            config,
+            references,
+        });
+        Ok(self.projects.len() - 1)
+    }
+
+    /// Groups projects so that every project in a group depends only on
+    /// projects in previous groups.
+    pub fn levels(&self) -> Vec<Vec<usize>> {
+        let mut depths = vec![0; self.projects.len()];
+        // References always come first, so this is enough.
+        for (idx, project) in self.projects.iter().enumerate() {
+            depths[idx] = project.references.iter().map(|&r| depths[r] + 1).max().unwrap_or(0);
+        }
+
+        let mut levels = vec![vec![]; depths.iter().max().map_or(0, |d| d + 1)];
+        for (idx, depth) in depths.into_iter().enumerate() {
+            levels[depth].push(idx);
+        }
+        levels
+    }
+}
+
+impl Project {
+    /// Returns the path of the `.d.ts` file emitted for `file`.
+    fn dts_path(&self, file: &Path) -> Option<PathBuf> {
+        let opts = &self.config.compiler_options;
+        let root_dir = opts
+            .root_dir
+            .as_ref()
+            .map_or_else(|| self.dir.clone(), |d| normalize(&self.dir.join(d)));
+        let rel = file.strip_prefix(&root_dir).ok()?;
+
+        let out_dir = match opts.declaration_dir.as_ref().or(opts.out_dir.as_ref()) {
+            Some(d) => normalize(&self.dir.join(d)),
+            None => root_dir,
+        };
+
+        let stem = rel.file_stem()?.to_string_lossy();
+        Some(out_dir.join(rel).with_file_name(format!("{}.d.ts", stem)))
+    }
+
+    fn stamp_path(&self) -> PathBuf {
+        let opts = &self.config.compiler_options;
+        match opts.out_dir.as_ref().or(opts.declaration_dir.as_ref()) {
+            Some(d) => normalize(&self.dir.join(d)).join(STAMP_FILE),
+            None => self.dir.join(STAMP_FILE),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BuildStatus {
+    /// The project and its references are not modified since the last build.
+    UpToDate,
+    /// Files which could not be parsed are reported as
+    /// [stc_ts_errors::ErrorKind::LoadFailed].
+    Checked { errors: Vec<Error> },
+    /// The project or its files could not be read, or declarations could not
+    /// be emitted.
+    Failed(anyhow::Error),
+}
+
+#[derive(Debug)]
+pub struct BuildResult {
+    pub config_path: PathBuf,
+    pub status: BuildStatus,
+}
+
+/// Builds projects of a [ProjectGraph].
+pub struct Builder {
+    cm: Arc<SourceMap>,
+    handler: Arc<Handler>,
+    /// Used if `lib` and `target` of a project are not specified.
+    libs: Vec<Lib>,
+
+    /// Declarations of files of checked projects, keyed by the path of the
+    /// source file.
+    declarations: Arc<DashMap<PathBuf, Arc<String>, FxBuildHasher>>,
+}
+
+impl Builder {
+    /// `libs` are used for projects which do not specify `lib` or `target`.
+    pub fn new(cm: Arc<SourceMap>, handler: Arc<Handler>, libs: Vec<Lib>) -> Self {
+        Self {
+            cm,
+            handler,
+            libs,
+            declarations: Default::default(),
+        }
+    }
+
+    /// Checks all projects of `graph`. Results are in the order of
+    /// [ProjectGraph::projects].
+    pub fn build(&self, graph: &ProjectGraph) -> Vec<BuildResult> {
+        let mut stamps = FxHashMap::<usize, u64>::default();
+        let mut results = FxHashMap::<usize, BuildStatus>::default();
+
+        for level in graph.levels() {
+            let level_results = level
+                .par_iter()
+                .map(|&idx| {
+                    let project = &graph.projects[idx];
+                    let ref_stamps = project.references.iter().map(|r| stamps[r]).collect::<Vec<u64>>();
+
+                    (idx, self.build_project(project, &ref_stamps))
+                })
+                .collect::<Vec<_>>();
+
+            for (idx, (stamp, status)) in level_results {
+                stamps.insert(idx, stamp);
+                results.insert(idx, status);
+            }
+        }
+
+        graph
+            .projects
+            .iter()
+            .enumerate()
+            .map(|(idx, project)| BuildResult {
+                config_path: project.config_path.clone(),
+                status: results.remove(&idx).unwrap(),
+            })
+            .collect()
+    }
+
+    /// Returns the hash of the project and the result.
+    fn build_project(&self, project: &Project, ref_stamps: &[u64]) -> (u64, BuildStatus) {
+        let files = project.config.root_files(&project.dir);
+
+        let stamp = match hash_project(project, &files, ref_stamps) {
+            Ok(v) => v,
+            Err(err) => return (0, BuildStatus::Failed(err)),
+        };
+
+        if project.config.compiler_options.composite && self.is_up_to_date(project, &files, stamp) {
+            log::info!("Project `{}` is up to date", project.config_path.display());
+            return (stamp, BuildStatus::UpToDate);
+        }
+
+        log::info!("Checking project `{}`", project.config_path.display());
+
+        let env = match project.config.compiler_options.env(EsVersion::latest(), &self.libs) {
+            Ok(env) => env,
+            Err(err) => {
+                return (
+                    stamp,
+                    BuildStatus::Failed(err.context(format!("invalid options in `{}`", project.config_path.display()))),
+                )
+            }
+        };
+
+        for name in project.config.compiler_options.unsupported_options() {
+            self.handler
+                .struct_warn(&format!(
+                    "compiler option `{}` of `{}` is not supported yet, so errors may be wrong",
+                    name,
+                    project.config_path.display()
+                ))
+                .emit();
+        }
+
+        let mut checker = Checker::new(
+            self.cm.clone(),
+            self.handler.clone(),
+            env.clone(),
+            None,
+            ModuleLoader::new(
+                self.cm.clone(),
+                env,
+                NodeResolver,
+                ProjectFileLoader {
+                    declarations: self.declarations.clone(),
+                },
+            ),
+        );
+
+        let entries = files.iter().map(|f| Arc::new(FileName::Real(f.clone()))).collect::<Vec<_>>();
+        let ids = checker.check_all(&entries);
+        let errors = checker.take_errors();
+
+        for (file, id) in files.iter().zip(ids) {
+            let id = match id {
+                Some(id) => id,
+                None => continue,
+            };
+            if file.to_string_lossy().ends_with(".d.ts") {
+                continue;
+            }
+
+            if let Some(dts) = checker.take_dts(id) {
+                let code = match self.print(&dts) {
+                    Ok(code) => code,
+                    Err(err) => {
+                        return (
+                            stamp,
+                            BuildStatus::Failed(err.context(format!("failed to emit declarations of `{}`", file.display()))),
+                        )
+                    }
+                };
+
+                if project.config.compiler_options.composite {
+                    if let Some(path) = project.dts_path(file) {
+                        // The stamp is not written, so the project is built again.
+                        if let Err(err) = write_file(&path, &code) {
+                            return (
+                                stamp,
+                                BuildStatus::Failed(err.context(format!("failed to write `{}`", path.display()))),
+                            );
+                        }
+                    }
+                }
+
+                self.declarations.insert(file.clone(), Arc::new(code));
+            }
+        }
+
+        // A build with errors is not up to date.
+        if project.config.compiler_options.composite && errors.is_empty() {
+            let path = project.stamp_path();
+            if let Err(err) = write_file(&path, &format!("{:x}", stamp)) {
+                log::error!("Failed to write `{}`: {:?}", path.display(), err);
+            }
+        }
+
+        (stamp, BuildStatus::Checked { errors })
+    }
+
+    /// If the stamp matches, declarations emitted by the previous build are
+    /// loaded so dependant projects can use them.
+    fn is_up_to_date(&self, project: &Project, files: &[PathBuf], stamp: u64) -> bool {
+        match fs::read_to_string(project.stamp_path()) {
+            Ok(prev) if prev.trim() == format!("{:x}", stamp) => {}
+            _ => return false,
+        }
+
+        let mut declarations = vec![];
+        for file in files {
+            if file.to_string_lossy().ends_with(".d.ts") {
+                continue;
+            }
+
+            match project.dts_path(file).and_then(|path| fs::read_to_string(path).ok()) {
+                Some(code) => declarations.push((file.clone(), Arc::new(code))),
+                None => return false,
+            }
+        }
+
+        for (file, code) in declarations {
+            self.declarations.insert(file, code);
+        }
+
+        true
+    }
+
+    fn print(&self, m: &Module) -> Result<String> {
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: self.cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(self.cm.clone(), "\n", &mut buf, None)),
+            };
+
+            emitter.emit_module(m).context("failed to emit .d.ts")?;
+        }
+
+        String::from_utf8(buf).context("codegen generated invalid utf8")
+    }
+}
+
+/// Hashes the config file, the files of the project and the hashes of
+/// referenced projects.
+fn hash_project(project: &Project, files: &[PathBuf], ref_stamps: &[u64]) -> Result<u64> {
+    let mut hasher = StableHasher::default();
+
+    hasher.write(&fs::read(&project.config_path).with_context(|| format!("failed to read `{}`", project.config_path.display()))?);
+
+    for file in files {
+        hasher.write(file.to_string_lossy().as_bytes());
+        hasher.write(&fs::read(file).with_context(|| format!("failed to read `{}`", file.display()))?);
+    }
+
+    for stamp in ref_stamps {
+        hasher.write(&stamp.to_le_bytes());
+    }
+
+    Ok(hasher.0)
+}
+
+/// 64-bit FNV-1a.
+///
+/// Stamps are stored on disk, so we can't use
+/// [std::collections::hash_map::DefaultHasher], which may change between
+/// versions of Rust.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHasher {
+    /// The length is hashed too, so `["ab", "c"]` and `["a", "bc"]` have
+    /// different hashes.
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Loads declarations of files of referenced projects instead of the files
+/// themselves.
+struct ProjectFileLoader {
+    declarations: Arc<DashMap<PathBuf, Arc<String>, FxBuildHasher>>,
+}
+
+impl LoadFile for ProjectFileLoader {
+    fn load_file(&self, cm: &Arc<SourceMap>, filename: &Arc<FileName>) -> Result<(Arc<SourceFile>, Syntax)> {
+        if let FileName::Real(path) = &**filename {
+            if let Some(code) = self.declarations.get(path) {
+                let fm = cm.new_source_file((**filename).clone(), (**code).clone());
+
+                return Ok((
+                    fm,
+                    Syntax::Typescript(ParserConfig {
+                        dts: true,
+                        ..Default::default()
+                    }),
+                ));
+            }
+        }
+
+        DefaultFileLoader.load_file(cm, filename)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use super::*;
+
+    /// Temporary directory which is removed on drop.
+    struct TempDir(PathBuf);
+
+    impl Deref for TempDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Writes `files` to a new temporary directory.
+    fn write_files(name: &str, files: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir(std::env::temp_dir().join(format!("stc-build-{}-{}", name, std::process::id())));
+        let _ = fs::remove_dir_all(&*dir);
+
+        for (path, content) in files {
+            write_file(&dir.join(path), content).unwrap();
+        }
+        dir
+    }
+
+    /// Builds `dir` and returns the status of each project, with the number
+    /// of errors for checked projects.
+    fn build(dir: &Path) -> Vec<(String, Result<usize, ()>)> {
+        let graph = ProjectGraph::load(dir).unwrap();
+
+        testing::run_test2(false, |cm, handler| {
+            let builder = Builder::new(cm, Arc::new(handler), Lib::load("es2020"));
+
+            Ok(builder
+                .build(&graph)
+                .into_iter()
+                .map(|result| {
+                    let name = result
+                        .config_path
+                        .parent()
+                        .unwrap()
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string();
+                    let status = match result.status {
+                        BuildStatus::UpToDate => Err(()),
+                        BuildStatus::Checked { errors } => Ok(errors.len()),
+                        BuildStatus::Failed(err) => panic!("failed to build `{}`: {:?}", name, err),
+                    };
+                    (name, status)
+                })
+                .collect())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn levels() {
+        let dir = write_files(
+            "levels",
+            &[
+                ("a/tsconfig.json", "{}"),
+                ("b/tsconfig.json", r#"{ "references": [{ "path": "../a" }] }"#),
+                ("c/tsconfig.json", r#"{ "references": [{ "path": "../a" }] }"#),
+                (
+                    "d/tsconfig.json",
+                    r#"{ "references": [{ "path": "../b" }, { "path": "../c/tsconfig.json" }] }"#,
+                ),
+            ],
+        );
+
+        let graph = ProjectGraph::load(&dir.join("d")).unwrap();
+        let names = graph
+            .projects
+            .iter()
+            .map(|p| p.dir.file_name().unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["a", "b", "c", "d"]);
+        assert_eq!(graph.projects[3].references, vec![1, 2]);
+        assert_eq!(graph.levels(), vec![vec![0], vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn cycle() {
+        let dir = write_files(
+            "cycle",
+            &[
+                ("a/tsconfig.json", r#"{ "references": [{ "path": "../b" }] }"#),
+                ("b/tsconfig.json", r#"{ "references": [{ "path": "../a" }] }"#),
+            ],
+        );
+
+        let err = ProjectGraph::load(&dir.join("a")).unwrap_err();
+
+        assert!(format!("{:?}", err).contains("project references form a cycle"), "{:?}", err);
+    }
+
+    #[test]
+    fn up_to_date() {
+        let dir = write_files(
+            "up-to-date",
+            &[
+                (
+                    "lib/tsconfig.json",
+                    r#"{ "compilerOptions": { "composite": true, "outDir": "dist" } }"#,
+                ),
+                ("lib/a.ts", "export const a = 1;\n"),
+                (
+                    "app/tsconfig.json",
+                    r#"{ "compilerOptions": { "composite": true, "outDir": "dist" }, "references": [{ "path": "../lib" }] }"#,
+                ),
+                ("app/b.ts", "import { a } from \"../lib/a\";\nexport const b: number = a;\n"),
+            ],
+        );
+        let app = dir.join("app");
+
+        assert_eq!(build(&app), vec![("lib".into(), Ok(0)), ("app".into(), Ok(0))]);
+        assert!(dir.join("lib/dist/a.d.ts").exists());
+
+        assert_eq!(build(&app), vec![("lib".into(), Err(())), ("app".into(), Err(()))]);
+
+        // `a` is loaded from `lib/dist/a.d.ts`.
+        write_file(&app.join("b.ts"), "import { a } from \"../lib/a\";\nexport const b: string = a;\n").unwrap();
+        assert_eq!(build(&app), vec![("lib".into(), Err(())), ("app".into(), Ok(1))]);
+
+        // A build with errors is not up to date.
+        assert_eq!(build(&app), vec![("lib".into(), Err(())), ("app".into(), Ok(1))]);
+
+        // Dependants of a modified project are checked again.
+        write_file(&dir.join("lib/a.ts"), "export const a = \"\";\n").unwrap();
+        assert_eq!(build(&app), vec![("lib".into(), Ok(0)), ("app".into(), Ok(0))]);
+    }
+
+    #[test]
+    fn stable_hash() {
+        let hash = |parts: &[&str]| {
+            let mut hasher = StableHasher::default();
+            for part in parts {
+                hasher.write(part.as_bytes());
+            }
+            hasher.0
+        };
+
+        // Stamps written by previous builds should stay valid.
+        assert_eq!(hash(&["a"]), 0x529a_4ddc_8ff5_6bbf);
+        assert_ne!(hash(&["a", "bc"]), hash(&["ab", "c"]));
+    }
+}
@@ -0,0 +1,191 @@
+//! Support for `// @ts-ignore`, `// @ts-expect-error` and `// @ts-nocheck`.
+
+use fxhash::FxHashMap;
+use stc_ts_errors::{Error, ErrorKind};
+use swc_common::{
+    comments::{Comment, CommentKind},
+    sync::Lrc,
+    BytePos, SourceFile, Span, Spanned,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveKind {
+    Ignore,
+    ExpectError,
+}
+
+#[derive(Debug)]
+struct Directive {
+    kind: DirectiveKind,
+    /// Used to report TS2578.
+    span: Span,
+    used: bool,
+}
+
+/// Directives of a file.
+pub(crate) struct FileDirectives {
+    fm: Lrc<SourceFile>,
+    /// Byte offsets of the start of each line.
+    line_starts: Vec<usize>,
+    no_check: bool,
+    /// Keyed by the line of the end of the comment.
+    directives: FxHashMap<usize, Directive>,
+}
+
+impl FileDirectives {
+    /// Returns [None] if the file does not have any directive.
+    ///
+    /// `comments` should be the comments of the file which contain `@ts-`,
+    /// sorted by position. `first_stmt` is used to find `@ts-nocheck`, which
+    /// should be in the leading comments of the file.
+    pub(crate) fn new(fm: Lrc<SourceFile>, comments: &[Comment], first_stmt: Option<BytePos>) -> Option<Self> {
+        if comments.is_empty() {
+            return None;
+        }
+
+        let no_check = comments.iter().any(|c| {
+            c.kind == CommentKind::Line && first_stmt.map_or(true, |pos| c.span.hi <= pos) && c.text.trim().starts_with("@ts-nocheck")
+        });
+
+        let mut line_starts = vec![0];
+        line_starts.extend(fm.src.match_indices('\n').map(|(idx, _)| idx + 1));
+
+        let mut file = FileDirectives {
+            fm,
+            line_starts,
+            no_check,
+            directives: Default::default(),
+        };
+
+        for c in comments {
+            if let Some((kind, span)) = parse_directive(c) {
+                let line = file.line_of(c.span.hi);
+                file.directives.insert(line, Directive { kind, span, used: false });
+            }
+        }
+
+        if !file.no_check && file.directives.is_empty() {
+            return None;
+        }
+
+        Some(file)
+    }
+
+    fn contains(&self, span: Span) -> bool {
+        self.fm.start_pos <= span.lo && span.lo <= self.fm.end_pos
+    }
+
+    fn offset(&self, pos: BytePos) -> usize {
+        (pos.0 - self.fm.start_pos.0) as usize
+    }
+
+    fn line_of(&self, pos: BytePos) -> usize {
+        let offset = self.offset(pos);
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    fn line_text(&self, line: usize) -> &str {
+        let start = self.line_starts[line];
+        let end = self.line_starts.get(line + 1).copied().unwrap_or(self.fm.src.len());
+        &self.fm.src[start..end]
+    }
+
+    /// Returns `true` if the error at `span` should not be reported, marking
+    /// the directive as used.
+    ///
+    /// Like `tsc`, empty lines and line comments between the directive and
+    /// the error are skipped.
+    fn suppress(&mut self, span: Span) -> bool {
+        if self.no_check {
+            return true;
+        }
+
+        let mut line = self.line_of(span.lo);
+        while line > 0 {
+            line -= 1;
+
+            if let Some(directive) = self.directives.get_mut(&line) {
+                directive.used = true;
+                return true;
+            }
+
+            let text = self.line_text(line).trim();
+            if !text.is_empty() && !text.starts_with("//") {
+                return false;
+            }
+        }
+
+        false
+    }
+
+    fn unused_expect_errors(&self) -> impl '_ + Iterator<Item = Span> {
+        self.directives
+            .values()
+            .filter(move |d| !self.no_check && d.kind == DirectiveKind::ExpectError && !d.used)
+            .map(|d| d.span)
+    }
+}
+
+/// Parses a comment using the rules of `tsc`.
+///
+/// For block comments, only the last line is checked. The returned span
+/// starts at the comment for line comments and at the last line for block
+/// comments.
+fn parse_directive(c: &Comment) -> Option<(DirectiveKind, Span)> {
+    let text: &str = &c.text;
+    let (text, span) = match c.kind {
+        CommentKind::Line => (text.strip_prefix('/').unwrap_or(text).trim_start(), c.span),
+        CommentKind::Block => match text.rfind('\n') {
+            Some(idx) => {
+                // Skip `/*`.
+                let lo = c.span.lo + BytePos(idx as u32 + 3);
+                (trim_block_line(&text[idx + 1..]), c.span.with_lo(lo))
+            }
+            None => (trim_block_line(text), c.span),
+        },
+    };
+
+    if text.starts_with("@ts-expect-error") {
+        Some((DirectiveKind::ExpectError, span))
+    } else if text.starts_with("@ts-ignore") {
+        Some((DirectiveKind::Ignore, span))
+    } else {
+        None
+    }
+}
+
+/// Skips leading whitespaces and `*`s, like ` * @ts-ignore`.
+fn trim_block_line(s: &str) -> &str {
+    s.trim_start_matches(|c: char| c == '/' || c == '*' || c.is_whitespace())
+}
+
+/// Removes errors suppressed by directives, and reports unused
+/// `@ts-expect-error` directives.
+pub(crate) fn apply_directives(mut files: Vec<FileDirectives>, errors: Vec<Error>) -> Vec<Error> {
+    if files.is_empty() {
+        return errors;
+    }
+
+    let mut errors = ErrorKind::flatten(errors);
+    errors.retain(|err| {
+        let span = err.span();
+        // Internal errors are bugs of stc, so they are never suppressed.
+        if span.is_dummy() || matches!(&**err, ErrorKind::InternalError { .. }) {
+            return true;
+        }
+
+        match files.iter_mut().find(|file| file.contains(span)) {
+            Some(file) => !file.suppress(span),
+            None => true,
+        }
+    });
+
+    for file in &files {
+        errors.extend(
+            file.unused_expect_errors()
+                .map(|span| Error::from(ErrorKind::UnusedTsExpectError { span })),
+        );
+    }
+
+    errors
+}
@@ -1,29 +1,54 @@
 //! Full type checker with dependency support.
 #![feature(box_syntax)]
 
-use std::{mem::take, sync::Arc, time::Instant};
+use std::{
+    mem::take,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+    time::Instant,
+};
 
 use dashmap::{DashMap, DashSet, SharedValue};
 use fxhash::{FxBuildHasher, FxHashMap};
-use loader::LoadModule;
+use loader::{LoadModule, Records};
 use once_cell::sync::OnceCell;
 use parking_lot::{Mutex, RwLock};
+use rayon::prelude::*;
 use rnode::{NodeIdGenerator, RNode, VisitWith};
 use stc_ts_ast_rnode::{RModule, RStr, RTsModuleName};
+use stc_ts_builtin_types::Lib;
 use stc_ts_dts::{apply_mutations, cleanup_module_for_dts};
+use stc_ts_dts_mutations::Mutations;
 use stc_ts_env::Env;
-use stc_ts_errors::{debug::debugger::Debugger, Error};
-use stc_ts_file_analyzer::{analyzer::Analyzer, loader::Load, validator::ValidateWith, ModuleTypeData, VResult};
+use stc_ts_errors::{debug::debugger::Debugger, Error, ErrorKind, InternalErrorInfo};
+use stc_ts_file_analyzer::{analyzer::Analyzer, env::EnvFactory, loader::Load, validator::ValidateWith, ModuleTypeData, VResult};
 use stc_ts_storage::{ErrorStore, File, Group, Single};
 use stc_ts_types::{ModuleId, Type};
-use stc_utils::{cache::Freeze, early_error};
+use stc_ts_utils::imports::LibReferences;
+use stc_utils::{cache::Freeze, early_error, panic_context};
 use swc_atoms::JsWord;
-use swc_common::{errors::Handler, FileName, SourceMap, Spanned, DUMMY_SP};
+use swc_common::{errors::Handler, FileName, SourceMap, Span, Spanned, DUMMY_SP, GLOBALS};
 use swc_ecma_ast::Module;
 use tracing::{info, warn};
 
+use self::{
+    directives::{apply_directives, FileDirectives},
+    loader::ModuleRecord,
+    timings::{Phase, Timings},
+};
+pub use self::{
+    query::print_type,
+    unused_exports::{UnusedExport, UnusedExports},
+};
+
+pub mod build;
+mod directives;
 pub mod loader;
+mod query;
+pub mod timings;
+pub mod tsconfig;
 mod typings;
+mod unused_exports;
 
 /// Onc instance per swc::Compiler
 pub struct Checker<L>
@@ -47,9 +72,24 @@ where
 
     errors: Mutex<Vec<Error>>,
 
-    env: Env,
+    /// `@ts-ignore` and similar comments of analyzed files.
+    directives: Mutex<Vec<FileDirectives>>,
+
+    /// Replaced if a loaded module references a library using `/// <reference
+    /// lib="..." />`.
+    env: RwLock<Env>,
+    /// The env passed to [Checker::new], which library references are
+    /// applied to.
+    base_env: Env,
+    /// Library references applied to `env`, with sorted `libs`.
+    lib_references: Mutex<LibReferences>,
 
     debugger: Option<Debugger>,
+
+    /// Names of lint rules to run after analysis.
+    lints: Vec<String>,
+
+    timings: Option<Arc<Timings>>,
 }
 
 impl<L> Checker<L>
@@ -58,17 +98,43 @@ where
 {
     pub fn new(cm: Arc<SourceMap>, handler: Arc<Handler>, env: Env, debugger: Option<Debugger>, module_loader: L) -> Self {
         Checker {
-            env,
+            env: RwLock::new(env.clone()),
+            base_env: env,
+            lib_references: Default::default(),
             cm,
             handler,
             module_types: Default::default(),
             dts_modules: Default::default(),
             started: Default::default(),
             errors: Default::default(),
+            directives: Default::default(),
             debugger,
             declared_modules: Default::default(),
             module_loader,
+            lints: Default::default(),
+            timings: Default::default(),
+        }
+    }
+
+    /// Records the time taken to check each module group, and counters like
+    /// the number of type instantiations.
+    pub fn with_timings(mut self, timings: Arc<Timings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
+    /// Enables lint rules of [stc_ts_passes] by name.
+    ///
+    /// Unknown names are ignored with a warning.
+    pub fn with_lints(mut self, names: Vec<String>) -> Self {
+        for name in &names {
+            if !stc_ts_passes::LINTS.contains(&&**name) {
+                warn!("Unknown lint rule: `{}`", name);
+            }
         }
+
+        self.lints = names;
+        self
     }
 }
 
@@ -92,10 +158,25 @@ where
     }
 
     /// After calling this method, you can get errors using `.take_errors()`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entry` could not be loaded. Use [Checker::try_check] to
+    /// report it as [ErrorKind::LoadFailed] instead.
     pub fn check(&self, entry: Arc<FileName>) -> ModuleId {
+        match self.try_check(entry.clone()) {
+            Some(id) => id,
+            None => panic!("failed to load entry `{}`", entry),
+        }
+    }
+
+    /// Same as [Checker::check], but returns [None] if `entry` could not be
+    /// loaded. This is reported as [ErrorKind::LoadFailed].
+    pub fn try_check(&self, entry: Arc<FileName>) -> Option<ModuleId> {
         let start = Instant::now();
 
-        let modules = self.module_loader.load_module(&entry, true).expect("failed to load entry");
+        let modules = self.load_entry(&entry)?;
+        self.apply_lib_references();
 
         let end = Instant::now();
         log::debug!("Loading of `{}` and dependencies took {:?}", entry, end - start);
@@ -107,11 +188,92 @@ where
         let end = Instant::now();
         log::debug!("Analysis of `{}` and dependencies took {:?}", entry, end - start);
 
-        modules.entry.id
+        Some(modules.entry.id)
     }
 
+    /// Checks `entries` and their dependencies, analyzing module groups in
+    /// parallel.
+    ///
+    /// All entries share the module loader and the cache of module types, so
+    /// a module imported by multiple entries is analyzed only once.
+    ///
+    /// Returns module ids in the order of `entries`, or [None] for entries
+    /// which could not be loaded. After calling this method, you can get
+    /// errors using `.take_errors_by_file()`.
+    pub fn check_all(&self, entries: &[Arc<FileName>]) -> Vec<Option<ModuleId>> {
+        let start = Instant::now();
+
+        // Cycles are calculated while loading, so we load all modules before
+        // analyzing any of them.
+        let ids = entries
+            .iter()
+            .map(|entry| self.load_entry(entry).map(|modules| modules.entry.id))
+            .collect::<Vec<_>>();
+        self.apply_lib_references();
+
+        let end = Instant::now();
+        log::debug!("Loading of {} entries and dependencies took {:?}", entries.len(), end - start);
+
+        let start = Instant::now();
+
+        GLOBALS.with(|globals| {
+            entries.par_iter().zip(&ids).for_each(|(entry, id)| {
+                if id.is_some() {
+                    GLOBALS.set(globals, || self.analyze_module(None, entry.clone()));
+                }
+            });
+        });
+
+        let end = Instant::now();
+        log::debug!("Analysis of {} entries and dependencies took {:?}", entries.len(), end - start);
+
+        ids
+    }
+
+    /// Loads `entry` and its dependencies, reporting an error if it fails.
+    fn load_entry(&self, entry: &Arc<FileName>) -> Option<Records> {
+        match self.module_loader.load_module(entry, true) {
+            Ok(modules) => Some(modules),
+            Err(err) => {
+                log::error!("Failed to load `{}`: {:?}", entry, err);
+
+                self.errors.lock().push(
+                    ErrorKind::LoadFailed {
+                        span: DUMMY_SP,
+                        file: entry.clone(),
+                        msg: format!("{:?}", err),
+                    }
+                    .into(),
+                );
+                None
+            }
+        }
+    }
+
+    /// Errors suppressed by `@ts-ignore`, `@ts-expect-error` and
+    /// `@ts-nocheck` are not included.
     pub fn take_errors(&mut self) -> Vec<Error> {
-        take(self.errors.get_mut())
+        let errors = take(self.errors.get_mut());
+        apply_directives(take(self.directives.get_mut()), errors)
+    }
+
+    /// Same as `.take_errors()`, but groups errors by the file they are
+    /// reported in.
+    pub fn take_errors_by_file(&mut self) -> FxHashMap<FileName, Vec<Error>> {
+        let mut errors = FxHashMap::<_, Vec<_>>::default();
+
+        for err in self.take_errors() {
+            let span = err.span();
+            let file = match &*err {
+                ErrorKind::LoadFailed { file, .. } => (**file).clone(),
+                _ if span.is_dummy() => FileName::Anon,
+                _ => self.cm.span_to_filename(span),
+            };
+
+            errors.entry(file).or_default().push(err);
+        }
+
+        errors
     }
 
     /// Analyzes one module.
@@ -169,23 +331,37 @@ where
                     .iter()
                     .map(|record| RModule::from_orig(&mut node_id_gen, record.ast.clone()))
                     .collect::<Vec<_>>();
-                let mut mutations;
-                {
+                let module_span = modules_in_group.modules.first().map(|record| record.ast.span).unwrap_or(DUMMY_SP);
+                let res = self.catch_panic(&path, module_span, || {
                     let mut a = Analyzer::root(
-                        self.env.clone(),
+                        self.env.read().clone(),
                         self.cm.clone(),
                         modules_in_group.comments.clone(),
                         box &mut storage,
                         self,
                         self.debugger.clone(),
                     );
-                    let _ = modules.validate_with(&mut a);
-                    mutations = a.mutations.unwrap();
-                }
+                    a.mutations.as_mut().unwrap().record_expr_types = !self.lints.is_empty();
+                    let _ = timings::record(self.timings.as_deref(), Phase::Check, &path, || modules.validate_with(&mut a));
+                    a.mutations.unwrap()
+                });
+                let mut mutations = match res {
+                    Ok(mutations) => mutations,
+                    Err(err) => {
+                        self.errors.lock().push(err);
+                        Default::default()
+                    }
+                };
 
                 for (record, mut dts_module) in modules_in_group.modules.iter().zip(modules) {
+                    self.collect_directives(record);
+
                     let type_data = storage.info.entry(record.id).or_default();
 
+                    if !is_dts_file(&record.filename) {
+                        self.run_lints(&dts_module, &mutations);
+                    }
+
                     {
                         apply_mutations(&mut mutations, &mut dts_module);
                         cleanup_module_for_dts(&mut dts_module.body, type_data);
@@ -264,10 +440,7 @@ where
     fn analyze_non_circular_module(&self, module_id: ModuleId, path: Arc<FileName>) -> Type {
         let start = Instant::now();
 
-        let is_dts = match &*path {
-            FileName::Real(path) => path.to_string_lossy().ends_with(".d.ts"),
-            _ => false,
-        };
+        let is_dts = is_dts_file(&path);
 
         let mut node_id_gen = NodeIdGenerator::default();
         let records = self.module_loader.load_module(&path, false).expect("failed to load module?");
@@ -278,6 +451,7 @@ where
         );
 
         let record = records.modules.into_iter().next().unwrap();
+        self.collect_directives(&record);
 
         let mut module = RModule::from_orig(&mut node_id_gen, record.ast.clone());
 
@@ -289,25 +463,36 @@ where
             is_dts,
             info: Default::default(),
         };
-        let mut mutations;
-        {
+        let res = self.catch_panic(&path, record.ast.span, || {
             let start = Instant::now();
             let mut a = Analyzer::root(
-                self.env.clone(),
+                self.env.read().clone(),
                 self.cm.clone(),
                 records.comments,
                 box &mut storage,
                 self,
                 self.debugger.clone(),
             );
+            a.mutations.as_mut().unwrap().record_expr_types = !self.lints.is_empty();
 
-            module.visit_with(&mut a);
+            timings::record(self.timings.as_deref(), Phase::Check, &path, || module.visit_with(&mut a));
 
             let end = Instant::now();
             let dur = end - start;
             log::debug!("[Timing] Analysis of {} took {:?}", path, dur);
 
-            mutations = a.mutations.unwrap();
+            a.mutations.unwrap()
+        });
+        let mut mutations = match res {
+            Ok(mutations) => mutations,
+            Err(err) => {
+                self.errors.lock().push(err);
+                Default::default()
+            }
+        };
+
+        if !is_dts {
+            self.run_lints(&module, &mutations);
         }
 
         {
@@ -347,6 +532,117 @@ where
     }
 }
 
+impl<L> Checker<L>
+where
+    L: LoadModule,
+{
+    /// Extends the built-in declarations with libraries referenced by loaded
+    /// modules. This should be called before analyzing modules.
+    ///
+    /// The env is rebuilt only if the references are changed since the last
+    /// call.
+    fn apply_lib_references(&self) {
+        let mut refs = self.module_loader.lib_references();
+        refs.libs.sort();
+
+        {
+            let mut applied = self.lib_references.lock();
+            if *applied == refs {
+                return;
+            }
+            *applied = refs.clone();
+        }
+
+        let libs = refs.libs.iter().flat_map(|name| {
+            let libs = Lib::load(name);
+            if libs.is_empty() {
+                warn!("Unknown library: `{}`", name);
+            }
+            libs
+        });
+        let libs = libs.collect::<Vec<_>>();
+
+        *self.env.write() = Env::with_lib_references(&self.base_env, &libs, refs.no_default_lib);
+    }
+
+    fn collect_directives(&self, record: &ModuleRecord) {
+        if record.ast.span.is_dummy() {
+            return;
+        }
+
+        let fm = self.cm.lookup_char_pos(record.ast.span.lo).file;
+        let first_stmt = record.ast.body.first().map(|item| item.span().lo);
+
+        if let Some(directives) = FileDirectives::new(fm, &record.directive_comments, first_stmt) {
+            self.directives.lock().push(directives);
+        }
+    }
+
+    /// Runs the enabled lint rules on `module`, which should not be modified
+    /// by [apply_mutations] yet.
+    fn run_lints(&self, module: &RModule, mutations: &Mutations) {
+        if self.lints.is_empty() {
+            return;
+        }
+
+        let errors = stc_ts_passes::run_lints(module, &self.lints, mutations);
+        self.errors.lock().extend(errors.into_iter().map(Error::from));
+    }
+
+    /// Runs `op`, converting a panic into [ErrorKind::InternalError] so that
+    /// the rest of the project can be checked.
+    ///
+    /// The error is located at the last span being validated, or at
+    /// `module_span` if there's no such span.
+    fn catch_panic<T>(&self, path: &Arc<FileName>, module_span: Span, op: impl FnOnce() -> T) -> Result<T, Error> {
+        panic_context::install_hook();
+
+        // `op` may analyze dependencies, so we restore the span of the dependant.
+        let prev_span = panic_context::last_span();
+        panic_context::clear_span();
+
+        let res = catch_unwind(AssertUnwindSafe(op));
+
+        let err = match res {
+            Ok(v) => {
+                if let Some(span) = prev_span {
+                    panic_context::record_span(span);
+                }
+                return Ok(v);
+            }
+            Err(err) => err,
+        };
+
+        let msg = if let Some(s) = err.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = err.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        let span = panic_context::last_span().filter(|span| !span.is_dummy()).unwrap_or(module_span);
+
+        log::error!("Panic while analyzing `{}`: {}", path, msg);
+
+        Err(ErrorKind::InternalError {
+            span,
+            info: box InternalErrorInfo {
+                file: path.to_string(),
+                msg,
+                context: panic_context::take_last_panic_context(),
+            },
+        }
+        .into())
+    }
+}
+
+fn is_dts_file(path: &FileName) -> bool {
+    match path {
+        FileName::Real(path) => path.to_string_lossy().ends_with(".d.ts"),
+        _ => false,
+    }
+}
+
 impl<L> Load for Checker<L>
 where
     L: LoadModule,
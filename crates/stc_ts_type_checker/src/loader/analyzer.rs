@@ -1,4 +1,4 @@
-use stc_ts_utils::imports::find_imports_in_comments;
+use stc_ts_utils::imports::{find_imports_in_comments, find_lib_references_in_comments, LibReferences};
 use swc_atoms::JsWord;
 use swc_common::{comments::Comments, Span, Spanned};
 use swc_ecma_ast::*;
@@ -21,6 +21,27 @@ where
     (v.declared_modules, v.references, v.deps)
 }
 
+/// Returns libraries referenced by `m`.
+///
+/// Like other triple-slash directives, these are only valid at the top of a
+/// file.
+pub(crate) fn find_lib_references<C>(comments: &C, m: &Module) -> LibReferences
+where
+    C: Comments,
+{
+    let mut refs = find_lib_references_in_comments(comments, m.span);
+
+    if let Some(first) = m.body.first() {
+        if first.span().lo != m.span.lo {
+            let first = find_lib_references_in_comments(comments, first.span());
+            refs.libs.extend(first.libs);
+            refs.no_default_lib |= first.no_default_lib;
+        }
+    }
+
+    refs
+}
+
 struct DepFinder<C>
 where
     C: Comments,
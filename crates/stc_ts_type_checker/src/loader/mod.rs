@@ -8,15 +8,20 @@ use petgraph::algo::kosaraju_scc;
 use rayon::prelude::*;
 use stc_ts_env::Env;
 use stc_ts_types::{module_id::ModuleIdGenerator, ModuleId};
-use stc_ts_utils::StcComments;
-use swc_common::{input::SourceFileInput, FileName, SourceFile, SourceMap, Span, SyntaxContext, GLOBALS};
+use stc_ts_utils::{imports::LibReferences, StcComments};
+use swc_common::{
+    comments::{Comment, Comments},
+    input::SourceFileInput,
+    FileName, SourceFile, SourceMap, Span, SyntaxContext, GLOBALS,
+};
 use swc_ecma_ast::Module;
 use swc_ecma_loader::resolve::Resolve;
 use swc_ecma_parser::{Parser, Syntax, TsConfig};
 use swc_ecma_visit::VisitMutWith;
 use swc_fast_graph::digraph::FastDiGraphMap;
 
-use self::analyzer::find_modules_and_deps;
+use self::analyzer::{find_lib_references, find_modules_and_deps};
+use crate::timings::{record, Phase, Timings};
 
 mod analyzer;
 pub mod store;
@@ -27,6 +32,12 @@ pub struct ModuleRecord {
     pub filename: Arc<FileName>,
     pub top_level_ctxt: SyntaxContext,
     pub ast: Module,
+    /// Comments containing `@ts-`, sorted by position. Used for directives
+    /// like `@ts-ignore`.
+    pub directive_comments: Vec<Comment>,
+    /// `/// <reference lib="..." />` and `/// <reference
+    /// no-default-lib="true" />` of this file.
+    pub lib_references: LibReferences,
 }
 
 pub struct Records {
@@ -53,6 +64,15 @@ pub trait LoadModule: 'static + Send + Sync {
 
     /// Same constraints for [`LoadModule::load_module`] applies.
     fn load_dep(&self, base: &Arc<FileName>, module_specifier: &str) -> Result<Records>;
+
+    /// Libraries referenced by loaded modules, using `/// <reference
+    /// lib="..." />` or `/// <reference no-default-lib="true" />`.
+    ///
+    /// Like `tsc`, references of a file apply to the whole program, so
+    /// [ModuleRecord::lib_references] of all loaded modules are merged.
+    fn lib_references(&self) -> LibReferences {
+        Default::default()
+    }
 }
 
 /// **NOTE**: [FileName::Custom] is not passed to this type.
@@ -80,6 +100,8 @@ where
     ids: ModuleIdGenerator,
     parse_cache: DashMap<Arc<FileName>, (Arc<ModuleRecord>, StcComments), FxBuildHasher>,
     parsing_errors: Mutex<Vec<swc_ecma_parser::error::Error>>,
+
+    timings: Option<Arc<Timings>>,
 }
 
 impl<L, R> ModuleLoader<L, R>
@@ -101,9 +123,16 @@ where
             parse_cache: Default::default(),
             ids: Default::default(),
             parsing_errors: Default::default(),
+            timings: Default::default(),
         }
     }
 
+    /// Records the time taken to parse, resolve and bind modules.
+    pub fn with_timings(mut self, timings: Arc<Timings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
     fn load_recursively(&self, filename: &Arc<FileName>, calc_cycles: bool) -> Result<ModuleId> {
         let (id, _) = self.ids.generate(filename);
 
@@ -122,7 +151,9 @@ where
                     .chain(deps.iter().map(|v| (v, true)))
                     .map(|(dep, is_normal_dep)| {
                         GLOBALS.set(globals, || {
-                            let dep_path = Arc::new(self.resolver.resolve(filename, dep)?);
+                            let dep_path = Arc::new(record(self.timings.as_deref(), Phase::Resolve, dep, || {
+                                self.resolver.resolve(filename, dep)
+                            })?);
 
                             self.load_recursively(&dep_path, false).map(|v| (v, is_normal_dep))
                         })
@@ -135,7 +166,9 @@ where
                     .chain(deps.par_iter().map(|v| (v, true)))
                     .map(|(dep, is_normal_dep)| {
                         GLOBALS.set(globals, || {
-                            let dep_path = Arc::new(self.resolver.resolve(filename, dep)?);
+                            let dep_path = Arc::new(record(self.timings.as_deref(), Phase::Resolve, dep, || {
+                                self.resolver.resolve(filename, dep)
+                            })?);
 
                             self.load_recursively(&dep_path, false).map(|v| (v, is_normal_dep))
                         })
@@ -210,6 +243,8 @@ where
                             body: Default::default(),
                             shebang: Default::default(),
                         },
+                        directive_comments: Default::default(),
+                        lib_references: Default::default(),
                     }),
                     self.comments.clone(),
                 ));
@@ -221,15 +256,18 @@ where
                 .with_context(|| format!("failed to load module `{}`", filename))?,
         };
 
+        // Comments of all files are stored in a single map, so we parse into a
+        // separate one to find the comments of this file.
+        let file_comments = StcComments::default();
         let mut parser = Parser::new(
             Syntax::Typescript(TsConfig {
                 tsx: fm.name.to_string().contains("tsx"),
                 ..Default::default()
             }),
             SourceFileInput::from(&*fm),
-            Some(&comments),
+            Some(&file_comments),
         );
-        let result = parser.parse_module();
+        let result = record(self.timings.as_deref(), Phase::Parse, filename, || parser.parse_module());
 
         let mut ast = match result {
             Ok(v) => v,
@@ -246,14 +284,33 @@ where
             errors.extend(extra_errors);
         }
 
+        let mut directive_comments = vec![];
+        for (map, is_leading) in [(&file_comments.leading, true), (&file_comments.trailing, false)] {
+            for entry in map.iter() {
+                directive_comments.extend(entry.value().iter().filter(|c| c.text.contains("@ts-")).cloned());
+
+                if is_leading {
+                    comments.add_leading_comments(*entry.key(), entry.value().clone());
+                } else {
+                    comments.add_trailing_comments(*entry.key(), entry.value().clone());
+                }
+            }
+        }
+        directive_comments.sort_by_key(|c| c.span.lo);
+        directive_comments.dedup_by_key(|c| c.span);
+
+        let lib_references = find_lib_references(&file_comments, &ast);
+
         let (id, top_level_mark) = self.ids.generate(filename);
         let top_level_ctxt = SyntaxContext::empty().apply_mark(top_level_mark);
 
-        ast.visit_mut_with(&mut swc_ecma_transforms_base::resolver(
-            self.env.shared().marks().unresolved_mark(),
-            top_level_mark,
-            true,
-        ));
+        record(self.timings.as_deref(), Phase::Bind, filename, || {
+            ast.visit_mut_with(&mut swc_ecma_transforms_base::resolver(
+                self.env.shared().marks().unresolved_mark(),
+                top_level_mark,
+                true,
+            ))
+        });
 
         Ok((
             Arc::new(ModuleRecord {
@@ -262,6 +319,8 @@ where
                 filename: filename.clone(),
                 top_level_ctxt,
                 ast,
+                directive_comments,
+                lib_references,
             }),
             comments,
         ))
@@ -317,6 +376,22 @@ where
 
         self.load_module(&Arc::new(filename), false)
     }
+
+    fn lib_references(&self) -> LibReferences {
+        let mut records = self.parse_cache.iter().map(|entry| entry.value().0.clone()).collect::<Vec<_>>();
+        records.sort_by_key(|record| record.id);
+
+        let mut refs = LibReferences::default();
+        for record in records {
+            for lib in &record.lib_references.libs {
+                if !refs.libs.contains(lib) {
+                    refs.libs.push(lib.clone());
+                }
+            }
+            refs.no_default_lib |= record.lib_references.no_default_lib;
+        }
+        refs
+    }
 }
 
 pub struct DefaultFileLoader;
@@ -0,0 +1,174 @@
+//! Queries for embedders, like code generators.
+
+use std::sync::Arc;
+
+use rnode::RNode;
+use stc_ts_ast_rnode::RTsType;
+use stc_ts_file_analyzer::analyzer::Analyzer;
+use stc_ts_storage::Single;
+use stc_ts_types::{ModuleId, Type, TypeElement};
+use stc_utils::{cache::ALLOW_DEEP_CLONE, stack};
+use swc_atoms::JsWord;
+use swc_common::{sync::Lrc, FileName, SourceMap, Span, DUMMY_SP};
+use swc_ecma_ast::{Decl, Ident, Module, ModuleItem, Stmt, TsType, TsTypeAliasDecl};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+use crate::{loader::LoadModule, Checker};
+
+impl<L> Checker<L>
+where
+    L: LoadModule,
+{
+    /// Returns the id of `file`.
+    pub fn module_id_of(&self, file: &Arc<FileName>) -> Option<ModuleId> {
+        Some(self.module_loader.load_module(file, false).ok()?.entry.id)
+    }
+
+    /// Returns the type of an exported variable, function, class or enum of a
+    /// checked module.
+    ///
+    /// Use `default` for the default export and `export =`.
+    pub fn type_of_exported_var(&self, file: &Arc<FileName>, name: &str) -> Option<Type> {
+        match self.get_types(self.module_id_of(file)?)?.normalize() {
+            Type::Module(m) => m.exports.vars.get(&JsWord::from(name)).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Returns the declarations of an exported type of a checked module.
+    ///
+    /// There can be multiple declarations because of declaration merging.
+    pub fn exported_types(&self, file: &Arc<FileName>, name: &str) -> Vec<Type> {
+        let module = self.module_id_of(file).and_then(|id| self.get_types(id));
+
+        match module.as_ref().map(Type::normalize) {
+            Some(Type::Module(m)) => m.exports.types.get(&JsWord::from(name)).cloned().unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+
+    /// Runs `op` with a fresh [Analyzer] which declares the exported variables
+    /// and types of `file`, so that `op` can use them. Declarations which are
+    /// not exported are not declared.
+    ///
+    /// `file` should be checked, and the stored types of the module are used
+    /// instead of validating it again. Returns [None] if `file` is not
+    /// checked.
+    ///
+    /// `op` is called with the span of the module, which can be used for
+    /// queries requiring a non-dummy span. Errors reported by `op` are
+    /// ignored.
+    pub fn with_analyzer<F, Ret>(&self, file: &Arc<FileName>, op: F) -> Option<Ret>
+    where
+        F: FnOnce(&mut Analyzer<'_, '_>, Span) -> Ret,
+    {
+        let records = self.module_loader.load_module(file, false).ok()?;
+        let record = records.entry.clone();
+
+        let module = self.get_types(record.id)?;
+        let data = match module.normalize() {
+            Type::Module(m) => &m.exports,
+            _ => return None,
+        };
+
+        let mut storage = Single {
+            parent: None,
+            id: record.id,
+            top_level_ctxt: record.top_level_ctxt,
+            path: file.clone(),
+            is_dts: record.is_dts,
+            info: Default::default(),
+        };
+
+        let mut a = Analyzer::root(
+            self.env.read().clone(),
+            self.cm.clone(),
+            records.comments,
+            box &mut storage,
+            self,
+            self.debugger.clone(),
+        );
+        a.declare_module_items(data);
+
+        let _stack = stack::start(100);
+        stack::reset_instantiation_count();
+
+        Some(op(&mut a, record.ast.span))
+    }
+
+    /// Returns `true` if `right` is assignable to `left`.
+    ///
+    /// Types are resolved using the scope of `file`.
+    pub fn is_assignable(&self, file: &Arc<FileName>, left: &Type, right: &Type) -> bool {
+        self.with_analyzer(file, |a, span| a.is_assignable(span, left, right).is_ok())
+            .unwrap_or(false)
+    }
+
+    /// Returns the members of `ty`, or [None] if `ty` is not an object-like
+    /// type.
+    ///
+    /// Types are resolved using the scope of `file`.
+    pub fn type_members(&self, file: &Arc<FileName>, ty: &Type) -> Option<Vec<TypeElement>> {
+        self.with_analyzer(file, |a, span| a.type_members(span, ty).ok().flatten())
+            .flatten()
+    }
+}
+
+/// Prints `ty` in the typescript syntax.
+///
+/// Unlike the printers in [stc_ts_errors::debug], this works in release
+/// builds.
+pub fn print_type(ty: &Type) -> String {
+    let mut ty = match ty.normalize() {
+        Type::Namespace(ns) => return format!("typeof {}", ns.name.sym()),
+        _ => ALLOW_DEEP_CLONE.set(&(), || RTsType::from(ty.clone())).into_orig(),
+    };
+    // Unions are converted with parens, which are not needed at the top level.
+    while let TsType::TsParenthesizedType(p) = ty {
+        ty = *p.type_ann;
+    }
+
+    // Spans of types point to the files they came from, which are not in the
+    // source map used here.
+    let mut module = Module {
+        span: DUMMY_SP,
+        body: vec![ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(box TsTypeAliasDecl {
+            span: DUMMY_SP,
+            declare: false,
+            id: Ident::new("T".into(), DUMMY_SP),
+            type_params: None,
+            type_ann: box ty,
+        })))],
+        shebang: None,
+    };
+    module.visit_mut_with(&mut DropSpan);
+
+    let mut buf = vec![];
+    {
+        let cm = Lrc::new(SourceMap::default());
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+        };
+
+        emitter.emit_module(&module).expect("failed to print a type");
+    }
+
+    let s = String::from_utf8(buf).expect("codegen generated invalid utf8");
+    let s = s.trim();
+    let s = s.strip_prefix("type T =").unwrap_or(s);
+    let s = s.strip_suffix(';').unwrap_or(s);
+
+    s.trim().to_string()
+}
+
+struct DropSpan;
+
+impl VisitMut for DropSpan {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        *span = DUMMY_SP;
+    }
+}
@@ -0,0 +1,301 @@
+//! Timings and counters of each phase, used for `--extendedDiagnostics` and
+//! `--generateTrace`.
+
+use std::{
+    fmt::{self, Display, Write},
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use fxhash::FxHashMap;
+use parking_lot::Mutex;
+use serde::Serialize;
+use stc_utils::stats::{self, Stats};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    /// Resolution of module specifiers.
+    Resolve,
+    /// Resolution of identifiers.
+    Bind,
+    Check,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Parse => "Parse",
+            Phase::Resolve => "Resolve",
+            Phase::Bind => "Bind",
+            Phase::Check => "Check",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub phase: Phase,
+    /// Name of the file or the module group.
+    pub name: String,
+    /// Elapsed time since [Timings] is created.
+    pub start: Duration,
+    pub dur: Duration,
+    /// Index of the rayon thread.
+    pub thread: usize,
+    /// Counters recorded by this event, excluding nested events.
+    pub stats: Stats,
+}
+
+/// Collects timings of each phase.
+///
+/// As modules are processed in parallel, the time of a phase is the sum of
+/// the time taken by each thread.
+///
+/// Creating this enables the counters of [stc_utils::stats].
+pub struct Timings {
+    origin: Instant,
+    events: Mutex<Vec<Event>>,
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        stats::enable();
+
+        Self {
+            origin: Instant::now(),
+            events: Default::default(),
+        }
+    }
+}
+
+impl Timings {
+    pub fn record<T>(&self, phase: Phase, name: impl Display, op: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let (ret, stats) = stats::scope(op);
+        let end = Instant::now();
+
+        self.events.lock().push(Event {
+            phase,
+            name: name.to_string(),
+            start: start - self.origin,
+            dur: end - start,
+            thread: rayon::current_thread_index().unwrap_or(0),
+            stats,
+        });
+
+        ret
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.events.lock().clone()
+    }
+
+    /// Returns the sum of counters of all events.
+    pub fn stats(&self) -> Stats {
+        let mut total = Stats::default();
+        for e in self.events.lock().iter() {
+            total += e.stats;
+        }
+        total
+    }
+
+    /// Returns counters of each module, excluding its dependencies.
+    pub fn stats_by_module(&self) -> Vec<(String, Stats)> {
+        let mut by_module = FxHashMap::<String, Stats>::default();
+        for e in self.events.lock().iter().filter(|e| e.phase == Phase::Check) {
+            *by_module.entry(e.name.clone()).or_default() += e.stats;
+        }
+        by_module.into_iter().collect()
+    }
+
+    /// Returns the total time of each phase, counters, and the slowest files.
+    ///
+    /// Check time of a module group does not include the time taken to check
+    /// its dependencies.
+    pub fn report(&self, slowest: usize) -> String {
+        let events = self.events();
+        let total = Instant::now() - self.origin;
+
+        let check_times = self_times(&events, Phase::Check);
+
+        let mut by_phase = FxHashMap::<Phase, Duration>::default();
+        for e in &events {
+            if e.phase != Phase::Check {
+                *by_phase.entry(e.phase).or_default() += e.dur;
+            }
+        }
+        by_phase.insert(Phase::Check, check_times.iter().map(|(_, dur)| *dur).sum());
+
+        let mut s = String::new();
+        let files = events.iter().filter(|e| e.phase == Phase::Parse).count();
+        let _ = writeln!(s, "{:<20}{}", "Files:", files);
+        for phase in [Phase::Parse, Phase::Resolve, Phase::Bind, Phase::Check] {
+            let _ = writeln!(
+                s,
+                "{:<20}{:?}",
+                format!("{} time:", phase),
+                by_phase.get(&phase).copied().unwrap_or_default()
+            );
+        }
+        let _ = writeln!(s, "{:<20}{:?}", "Total time:", total);
+        let _ = writeln!(s, "\n{}", self.stats());
+
+        let mut check_times = check_times;
+        check_times.sort_by(|a, b| b.1.cmp(&a.1));
+        if !check_times.is_empty() {
+            let _ = writeln!(s, "\nSlowest files (check time):");
+            for (name, dur) in check_times.iter().take(slowest) {
+                let _ = writeln!(s, "{:>12?}  {}", dur, name);
+            }
+        }
+
+        let mut by_module = self.stats_by_module();
+        by_module.sort_by(|a, b| b.1.instantiations.cmp(&a.1.instantiations));
+        if by_module.iter().any(|(_, stats)| stats.instantiations > 0) {
+            let _ = writeln!(s, "\nMost instantiations:");
+            for (name, stats) in by_module.iter().take(slowest) {
+                let _ = writeln!(
+                    s,
+                    "{:>12}  {} ({} assignability checks, {} types)",
+                    stats.instantiations, name, stats.assign_checks, stats.types_created
+                );
+            }
+        }
+
+        s
+    }
+
+    /// Writes events in the Chrome trace event format, which can be opened
+    /// using `chrome://tracing` or Perfetto.
+    pub fn write_chrome_trace(&self, path: &Path) -> Result<()> {
+        #[derive(Serialize)]
+        struct TraceEvent<'a> {
+            name: &'a str,
+            cat: String,
+            ph: &'static str,
+            ts: u128,
+            dur: u128,
+            pid: u32,
+            tid: usize,
+        }
+
+        let events = self.events();
+        let trace_events = events
+            .iter()
+            .map(|e| TraceEvent {
+                name: &e.name,
+                cat: e.phase.to_string(),
+                ph: "X",
+                ts: e.start.as_micros(),
+                dur: e.dur.as_micros(),
+                pid: 1,
+                tid: e.thread,
+            })
+            .collect::<Vec<_>>();
+
+        fs::write(path, serde_json::to_string(&trace_events)?)?;
+
+        Ok(())
+    }
+}
+
+/// Calculates the time of each event excluding nested events of the same
+/// phase, which is the time taken by dependencies.
+fn self_times(events: &[Event], phase: Phase) -> Vec<(String, Duration)> {
+    let mut events = events.iter().filter(|e| e.phase == phase).collect::<Vec<_>>();
+    events.sort_by_key(|e| (e.thread, e.start, std::cmp::Reverse(e.dur)));
+
+    let mut times = events.iter().map(|e| (e.name.clone(), e.dur)).collect::<Vec<_>>();
+
+    // Stack of indices of events containing the current event.
+    let mut stack: Vec<usize> = vec![];
+    for (idx, e) in events.iter().enumerate() {
+        while let Some(&parent) = stack.last() {
+            let p = events[parent];
+            if p.thread == e.thread && e.start < p.start + p.dur {
+                break;
+            }
+            stack.pop();
+        }
+
+        if let Some(&parent) = stack.last() {
+            times[parent].1 = times[parent].1.saturating_sub(e.dur);
+        }
+        stack.push(idx);
+    }
+
+    times
+}
+
+/// Runs `op`, recording the time if `timings` is [Some].
+pub(crate) fn record<T>(timings: Option<&Timings>, phase: Phase, name: impl Display, op: impl FnOnce() -> T) -> T {
+    match timings {
+        Some(timings) => timings.record(phase, name, op),
+        None => op(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    fn event(phase: Phase, name: &str, thread: usize, start: u64, dur: u64) -> Event {
+        Event {
+            phase,
+            name: name.into(),
+            start: Duration::from_millis(start),
+            dur: Duration::from_millis(dur),
+            thread,
+            stats: Default::default(),
+        }
+    }
+
+    #[test]
+    fn self_times_exclude_nested_events() {
+        let events = vec![
+            event(Phase::Check, "e", 0, 12, 3),
+            event(Phase::Check, "c", 0, 3, 1),
+            event(Phase::Check, "a", 0, 0, 10),
+            event(Phase::Check, "d", 1, 1, 4),
+            event(Phase::Check, "b", 0, 2, 5),
+            event(Phase::Parse, "a", 0, 0, 100),
+        ];
+
+        let times = self_times(&events, Phase::Check)
+            .into_iter()
+            .map(|(name, dur)| (name, dur.as_millis()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            times,
+            vec![("a".into(), 5), ("b".into(), 4), ("c".into(), 1), ("e".into(), 3), ("d".into(), 4)]
+        );
+    }
+
+    #[test]
+    fn chrome_trace() {
+        let timings = Timings::default();
+        timings
+            .events
+            .lock()
+            .extend([event(Phase::Parse, "a.ts", 0, 1, 2), event(Phase::Check, "a.ts", 3, 4, 5)]);
+
+        let path = std::env::temp_dir().join(format!("stc-trace-{}.json", std::process::id()));
+        timings.write_chrome_trace(&path).unwrap();
+        let trace: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            trace,
+            serde_json::json!([
+                { "name": "a.ts", "cat": "Parse", "ph": "X", "ts": 1000, "dur": 2000, "pid": 1, "tid": 0 },
+                { "name": "a.ts", "cat": "Check", "ph": "X", "ts": 4000, "dur": 5000, "pid": 1, "tid": 3 },
+            ])
+        );
+    }
+}
@@ -0,0 +1,453 @@
+//! Support for `tsconfig.json`.
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{option_kind, Env, ModuleConfig, OptionKind, Rule};
+use stc_ts_file_analyzer::env::EnvFactory;
+use swc_ecma_ast::EsVersion;
+use walkdir::WalkDir;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TsConfig {
+    #[serde(default)]
+    pub compiler_options: CompilerOptions,
+
+    pub files: Option<Vec<String>>,
+
+    pub include: Option<Vec<String>>,
+
+    pub exclude: Option<Vec<String>>,
+
+    #[serde(default)]
+    pub references: Vec<ProjectReference>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptions {
+    #[serde(default)]
+    pub composite: bool,
+
+    pub root_dir: Option<String>,
+
+    pub out_dir: Option<String>,
+
+    pub declaration_dir: Option<String>,
+
+    /// Other options, like `strict` or `noImplicitAny`.
+    #[serde(flatten)]
+    pub options: Map<String, Value>,
+}
+
+impl CompilerOptions {
+    /// Creates a [Rule] from the options.
+    ///
+    /// Returns an error if an option is not known to `tsc`.
+    pub fn rule(&self) -> Result<Rule> {
+        let mut rule = Rule::default();
+
+        // Flags like `noImplicitAny` override `strict`.
+        let strict = self.options.iter().find(|(name, _)| name.eq_ignore_ascii_case("strict"));
+        if let Some((_, value)) = strict {
+            rule.set_option("strict", value.as_bool().context("`strict` should be a boolean")?);
+        }
+
+        for (name, value) in &self.options {
+            match option_kind(name) {
+                Some(OptionKind::Rule) => {
+                    if name.eq_ignore_ascii_case("strict") {
+                        continue;
+                    }
+
+                    let value = value.as_bool().with_context(|| format!("`{}` should be a boolean", name))?;
+                    rule.set_option(name, value);
+                }
+                // Reported by the caller. See `unsupported_options`.
+                Some(OptionKind::Env) | Some(OptionKind::Ignored) | Some(OptionKind::Unsupported) => {}
+                None => bail!("unknown compiler option `{}`", name),
+            }
+        }
+
+        Ok(rule)
+    }
+
+    /// Returns the names of the options which change the result of type
+    /// checking but are not supported yet.
+    ///
+    /// Options set to `false` or `null` are not included.
+    pub fn unsupported_options(&self) -> Vec<&str> {
+        self.options
+            .iter()
+            .filter(|(name, value)| {
+                option_kind(name) == Some(OptionKind::Unsupported) && !matches!(value, Value::Bool(false) | Value::Null)
+            })
+            .map(|(name, _)| &**name)
+            .collect()
+    }
+
+    /// Creates an [Env] from the options.
+    ///
+    /// `target` and `libs` are used if `target` and `lib` are not specified.
+    pub fn env(&self, target: EsVersion, libs: &[Lib]) -> Result<Env> {
+        let mut rule = self.rule()?;
+
+        if let Some(jsx) = self.str_option("jsx")? {
+            rule.jsx = jsx
+                .to_ascii_lowercase()
+                .parse()
+                .map_err(|()| anyhow!("unknown value `{}` for `jsx`", jsx))?;
+        }
+
+        let specified_target = self.str_option("target")?.map(parse_target).transpose()?;
+
+        let module = match self.str_option("module")? {
+            Some(module) => module
+                .to_ascii_lowercase()
+                .parse()
+                .map_err(|_| anyhow!("unknown value `{}` for `module`", module))?,
+            None => ModuleConfig::None,
+        };
+
+        let libs = match self.options.iter().find(|(name, _)| name.eq_ignore_ascii_case("lib")) {
+            Some((_, value)) => {
+                let names = value.as_array().context("`lib` should be an array")?;
+                let mut libs = vec![];
+                for name in names {
+                    let name = name.as_str().context("`lib` should be an array of strings")?;
+                    let loaded = Lib::load(&name.to_ascii_lowercase().replace("es6", "es2015"));
+                    if loaded.is_empty() {
+                        bail!("unknown library `{}`", name)
+                    }
+                    libs.extend(loaded);
+                }
+                libs
+            }
+            None if rule.no_lib => vec![],
+            None => match specified_target {
+                Some(EsVersion::Es3 | EsVersion::Es5) => Lib::load("es5.full"),
+                Some(EsVersion::Es2015) => Lib::load("es2015.full"),
+                Some(EsVersion::Es2016) => Lib::load("es2016.full"),
+                Some(EsVersion::Es2017) => Lib::load("es2017.full"),
+                Some(EsVersion::Es2018) => Lib::load("es2018.full"),
+                Some(EsVersion::Es2019) => Lib::load("es2019.full"),
+                Some(EsVersion::Es2020) => Lib::load("es2020.full"),
+                Some(EsVersion::Es2021) => Lib::load("es2021.full"),
+                Some(_) => Lib::load("es2022.full"),
+                None => libs.to_vec(),
+            },
+        };
+
+        let env = Env::simple(rule, specified_target.unwrap_or(target), module, &libs);
+
+        Ok(env.with_jsx_import_source(self.str_option("jsxImportSource")?.map(From::from)))
+    }
+
+    fn str_option(&self, name: &str) -> Result<Option<&str>> {
+        match self.options.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)) {
+            Some((_, value)) => Ok(Some(value.as_str().with_context(|| format!("`{}` should be a string", name))?)),
+            None => Ok(None),
+        }
+    }
+}
+
+fn parse_target(s: &str) -> Result<EsVersion> {
+    Ok(match &*s.to_ascii_lowercase() {
+        "es3" => EsVersion::Es3,
+        "es5" => EsVersion::Es5,
+        "es6" | "es2015" => EsVersion::Es2015,
+        "es2016" => EsVersion::Es2016,
+        "es2017" => EsVersion::Es2017,
+        "es2018" => EsVersion::Es2018,
+        "es2019" => EsVersion::Es2019,
+        "es2020" => EsVersion::Es2020,
+        "es2021" => EsVersion::Es2021,
+        "es2022" => EsVersion::Es2022,
+        "esnext" => EsVersion::EsNext,
+        _ => bail!("unknown target `{}`", s),
+    })
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectReference {
+    /// Path to a `tsconfig.json` file or a directory containing it.
+    pub path: String,
+}
+
+const DEFAULT_EXCLUDE: &[&str] = &["node_modules", "bower_components", "jspm_packages"];
+
+impl TsConfig {
+    /// Loads a `tsconfig.json` file. Comments and trailing commas are
+    /// allowed.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).with_context(|| format!("failed to read `{}`", path.display()))?;
+
+        Self::parse(&content).with_context(|| format!("failed to parse `{}`", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(serde_json::from_str(&strip_jsonc(content))?)
+    }
+
+    /// Returns the files of the project, using `files`, `include` and
+    /// `exclude`.
+    ///
+    /// `dir` is the directory containing the config file.
+    pub fn root_files(&self, dir: &Path) -> Vec<PathBuf> {
+        let mut files = self
+            .files
+            .iter()
+            .flatten()
+            .map(|file| normalize(&dir.join(file)))
+            .collect::<Vec<_>>();
+
+        let include = match (&self.files, &self.include) {
+            (_, Some(include)) => include.clone(),
+            (None, None) => vec!["**/*".into()],
+            (Some(..), None) => vec![],
+        };
+        if include.is_empty() {
+            return files;
+        }
+        let include = include.iter().map(|pattern| expand_dir_pattern(pattern)).collect::<Vec<_>>();
+
+        let mut exclude = match &self.exclude {
+            Some(exclude) => exclude.clone(),
+            None => DEFAULT_EXCLUDE.iter().map(|s| s.to_string()).collect(),
+        };
+        exclude.extend(self.compiler_options.out_dir.clone());
+        exclude.extend(self.compiler_options.declaration_dir.clone());
+        let exclude = exclude.iter().map(|pattern| expand_dir_pattern(pattern)).collect::<Vec<_>>();
+
+        for entry in WalkDir::new(dir)
+            .into_iter()
+            .filter_entry(|e| !DEFAULT_EXCLUDE.iter().any(|name| e.file_name() == *name))
+            .flatten()
+        {
+            if !entry.file_type().is_file() || !is_ts_file(entry.path()) {
+                continue;
+            }
+
+            let rel = match entry.path().strip_prefix(dir) {
+                Ok(rel) => rel.to_string_lossy().replace('\\', "/"),
+                Err(..) => continue,
+            };
+
+            if include.iter().any(|pattern| matches_glob(pattern, &rel)) && !exclude.iter().any(|pattern| matches_glob(pattern, &rel)) {
+                files.push(normalize(entry.path()));
+            }
+        }
+
+        files.sort();
+        files.dedup();
+        files
+    }
+}
+
+fn is_ts_file(path: &Path) -> bool {
+    matches!(path.extension().and_then(|ext| ext.to_str()), Some("ts" | "tsx"))
+}
+
+/// Removes `.` and `..` from `path` without touching the file system.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut buf = PathBuf::new();
+
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                buf.pop();
+            }
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+/// Patterns without wildcards or extensions in the last segment match
+/// directories, like `src`.
+fn expand_dir_pattern(pattern: &str) -> String {
+    let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+    let last = pattern.rsplit('/').next().unwrap_or(pattern);
+
+    if last.contains('*') || last.contains('?') || last.contains('.') {
+        pattern.to_string()
+    } else {
+        format!("{}/**/*", pattern)
+    }
+}
+
+/// Matches a `/`-separated relative path against a pattern supporting `**`,
+/// `*` and `?`.
+fn matches_glob(pattern: &str, path: &str) -> bool {
+    fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((&"**", rest)) => (0..=path.len()).any(|i| matches_segments(rest, &path[i..])),
+            Some((first, rest)) => match path.split_first() {
+                Some((seg, path_rest)) => matches_segment(first.as_bytes(), seg.as_bytes()) && matches_segments(rest, path_rest),
+                None => false,
+            },
+        }
+    }
+
+    fn matches_segment(pattern: &[u8], s: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => s.is_empty(),
+            Some((b'*', rest)) => (0..=s.len()).any(|i| matches_segment(rest, &s[i..])),
+            Some((b'?', rest)) => !s.is_empty() && matches_segment(rest, &s[1..]),
+            Some((c, rest)) => s.first() == Some(c) && matches_segment(rest, &s[1..]),
+        }
+    }
+
+    let pattern = pattern.split('/').filter(|s| !s.is_empty() && *s != ".").collect::<Vec<_>>();
+    let path = path.split('/').collect::<Vec<_>>();
+
+    matches_segments(&pattern, &path)
+}
+
+/// Removes comments and trailing commas so the content can be parsed as json.
+fn strip_jsonc(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    let mut in_str = false;
+
+    while let Some(c) = chars.next() {
+        if in_str {
+            buf.push(c);
+            match c {
+                '\\' => buf.extend(chars.next()),
+                '"' => in_str = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_str = true;
+                buf.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        buf.push(c);
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '}' | ']' => {
+                let trimmed = buf.trim_end().len();
+                if buf[..trimmed].ends_with(',') {
+                    buf.truncate(trimmed - 1);
+                }
+                buf.push(c);
+            }
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_jsonc() {
+        let config = TsConfig::parse(
+            r#"{
+                // Comment
+                "compilerOptions": {
+                    "composite": true, /* block comment */
+                    "outDir": "./dist",
+                },
+                "references": [{ "path": "../core" },],
+                "include": ["src/**/*", "types/*.ts"]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(config.compiler_options.composite);
+        assert_eq!(config.compiler_options.out_dir.as_deref(), Some("./dist"));
+        assert_eq!(config.references[0].path, "../core");
+        assert_eq!(config.include.unwrap()[1], "types/*.ts");
+    }
+
+    #[test]
+    fn rule() {
+        let config = TsConfig::parse(
+            r#"{
+                "compilerOptions": {
+                    "noImplicitAny": false,
+                    "strict": true,
+                    "noUnusedLocals": true,
+                    "sourceMap": true,
+                    "target": "es2020"
+                }
+            }"#,
+        )
+        .unwrap();
+        let rule = config.compiler_options.rule().unwrap();
+
+        assert!(!rule.no_implicit_any);
+        assert!(rule.strict_null_checks);
+        assert!(rule.no_unused_locals);
+
+        let config = TsConfig::parse(r#"{ "compilerOptions": { "noSuchOption": true } }"#).unwrap();
+        assert!(config.compiler_options.rule().is_err());
+    }
+
+    #[test]
+    fn unsupported_options() {
+        let config = TsConfig::parse(
+            r#"{
+                "compilerOptions": {
+                    "strict": true,
+                    "sourceMap": true,
+                    "isolatedModules": true,
+                    "experimentalDecorators": false,
+                    "importsNotUsedAsValues": "error"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert!(config.compiler_options.rule().is_ok());
+
+        let mut unsupported = config.compiler_options.unsupported_options();
+        unsupported.sort();
+        assert_eq!(unsupported, vec!["importsNotUsedAsValues", "isolatedModules"]);
+    }
+
+    #[test]
+    fn glob() {
+        assert!(matches_glob("**/*", "a.ts"));
+        assert!(matches_glob("**/*", "src/a/b.ts"));
+        assert!(matches_glob(&expand_dir_pattern("src"), "src/a/b.ts"));
+        assert!(matches_glob(&expand_dir_pattern("./src/"), "src/b.ts"));
+        assert!(matches_glob("src/*.ts", "src/b.ts"));
+        assert!(!matches_glob("src/*.ts", "src/a/b.ts"));
+        assert!(matches_glob("src/?.ts", "src/b.ts"));
+        assert!(!matches_glob(&expand_dir_pattern("dist"), "src/b.ts"));
+    }
+}
@@ -61,14 +61,24 @@ where
     ///
     /// - https://www.typescriptlang.org/tsconfig#typeRoots
     /// - https://www.typescriptlang.org/tsconfig#types
-    pub fn load_typings(&self, base: &Path, _type_roots: Option<&[PathBuf]>, types: Option<&[String]>) {
+    pub fn load_typings(&self, base: &Path, type_roots: Option<&[PathBuf]>, types: Option<&[String]>) {
+        self.load_typings_of_files(&[base.to_path_buf()], type_roots, types)
+    }
+
+    /// Same as [Checker::load_typings], but loads typings reachable from any
+    /// of `files`. Each directory is visited only once.
+    pub fn load_typings_of_files(&self, files: &[PathBuf], _type_roots: Option<&[PathBuf]>, types: Option<&[String]>) {
         let mut dirs = vec![];
 
-        let mut cur = Some(base);
-        while let Some(c) = cur {
-            dirs.push(c.to_path_buf());
-            cur = c.parent();
+        for file in files {
+            let mut cur = Some(&**file);
+            while let Some(c) = cur {
+                dirs.push(c.to_path_buf());
+                cur = c.parent();
+            }
         }
+        dirs.sort();
+        dirs.dedup();
 
         dirs.into_par_iter().for_each(|dir| {
             self.load_typings_from_dir(&dir, types);
@@ -0,0 +1,549 @@
+//! Project-wide report of unused exports and unreachable modules.
+//!
+//! This works on the import graph of modules, so it does not require the
+//! modules to be analyzed.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use fxhash::{FxHashMap, FxHashSet};
+use stc_ts_types::ModuleId;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{FileName, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{
+    loader::{LoadModule, ModuleRecord},
+    Checker,
+};
+
+/// Result of [Checker::find_unused_exports].
+#[derive(Debug, Default)]
+pub struct UnusedExports {
+    /// Exports which are never imported by modules reachable from the entry
+    /// points.
+    pub unused: Vec<UnusedExport>,
+    /// Exported values which are only imported using `import type`, so the
+    /// value itself is never used.
+    pub only_used_as_type: Vec<UnusedExport>,
+    /// Modules which are not reachable from the entry points.
+    pub unreachable_modules: Vec<Arc<FileName>>,
+}
+
+#[derive(Debug)]
+pub struct UnusedExport {
+    pub file: Arc<FileName>,
+    pub span: Span,
+    pub name: JsWord,
+}
+
+impl<L> Checker<L>
+where
+    L: LoadModule,
+{
+    /// Finds exports of `files` and their dependencies which are not used by
+    /// modules reachable from `entries`, and modules which are not reachable
+    /// from `entries`.
+    ///
+    /// If `entries` is empty, files which are not imported by any other module
+    /// are used as the entry points. In this case, modules are reported as
+    /// unreachable only if they are part of an import cycle which no entry
+    /// point depends on.
+    ///
+    /// Exports of entry points are public api, so they are not reported.
+    /// `.d.ts` files are ignored.
+    pub fn find_unused_exports(&self, files: &[Arc<FileName>], entries: &[Arc<FileName>]) -> UnusedExports {
+        let mut graph = ModuleGraph::default();
+
+        let mut queue = VecDeque::new();
+        let mut entry_ids = vec![];
+        let mut file_ids = vec![];
+        for (i, file) in entries.iter().chain(files).enumerate() {
+            let record = match self.module_loader.load_module(file, false) {
+                Ok(records) => records.entry,
+                Err(err) => {
+                    log::warn!("Failed to load `{}`: {:?}", file, err);
+                    continue;
+                }
+            };
+            if i < entries.len() {
+                entry_ids.push(record.id);
+            } else {
+                file_ids.push(record.id);
+            }
+            queue.push_back(record);
+        }
+
+        while let Some(record) = queue.pop_front() {
+            if graph.modules.contains_key(&record.id) {
+                continue;
+            }
+
+            let info = ModuleInfo::new(record.clone(), |src| {
+                let dep = self.module_loader.load_dep(&record.filename, src).ok()?.entry;
+                let id = dep.id;
+                queue.push_back(dep);
+                Some(id)
+            });
+            graph.modules.insert(record.id, info);
+        }
+
+        if entries.is_empty() {
+            entry_ids = graph.roots(&file_ids);
+        }
+
+        graph.report(&entry_ids)
+    }
+}
+
+#[derive(Default)]
+struct ModuleGraph {
+    modules: FxHashMap<ModuleId, ModuleInfo>,
+
+    /// `true` if a value is used.
+    used: FxHashMap<(ModuleId, JsWord), bool>,
+    fully_used: FxHashSet<ModuleId>,
+}
+
+impl ModuleGraph {
+    /// Returns the modules of `ids` which are not imported by other modules.
+    fn roots(&self, ids: &[ModuleId]) -> Vec<ModuleId> {
+        let imported = self
+            .modules
+            .iter()
+            .flat_map(|(id, info)| info.deps.iter().filter(move |dep| *dep != id))
+            .collect::<FxHashSet<_>>();
+
+        ids.iter().copied().filter(|id| !imported.contains(id)).collect()
+    }
+
+    fn report(mut self, entries: &[ModuleId]) -> UnusedExports {
+        let mut reachable = FxHashSet::default();
+        let mut queue = entries.to_vec();
+        while let Some(id) = queue.pop() {
+            if !reachable.insert(id) {
+                continue;
+            }
+            if let Some(info) = self.modules.get(&id) {
+                queue.extend(info.deps.iter().copied());
+            }
+        }
+
+        let usages = reachable
+            .iter()
+            .filter_map(|id| self.modules.get(id))
+            .flat_map(|info| info.usages.clone())
+            .collect::<Vec<_>>();
+        for usage in usages {
+            match usage {
+                Usage::Named { module, name, is_value } => self.mark_used(module, name, is_value),
+                Usage::All { module } => self.mark_all(module),
+            }
+        }
+
+        let mut report = UnusedExports::default();
+
+        for (id, info) in &self.modules {
+            if info.record.is_dts || !matches!(&*info.record.filename, FileName::Real(..)) {
+                continue;
+            }
+
+            if !reachable.contains(id) {
+                report.unreachable_modules.push(info.record.filename.clone());
+                continue;
+            }
+
+            if entries.contains(id) || self.fully_used.contains(id) {
+                continue;
+            }
+
+            for export in &info.exports {
+                let unused = UnusedExport {
+                    file: info.record.filename.clone(),
+                    span: export.span,
+                    name: export.name.clone(),
+                };
+
+                match self.used.get(&(*id, export.name.clone())) {
+                    None => report.unused.push(unused),
+                    Some(false) if export.is_value => report.only_used_as_type.push(unused),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        report.unused.sort_by_key(|e| e.span.lo);
+        report.only_used_as_type.sort_by_key(|e| e.span.lo);
+        report.unreachable_modules.sort_by_key(|f| f.to_string());
+
+        report
+    }
+
+    fn mark_used(&mut self, module: ModuleId, name: JsWord, is_value: bool) {
+        if self.fully_used.contains(&module) {
+            return;
+        }
+
+        match self.used.get(&(module, name.clone())) {
+            Some(&used) if used || !is_value => return,
+            _ => {
+                self.used.insert((module, name.clone()), is_value);
+            }
+        }
+
+        let info = match self.modules.get(&module) {
+            Some(info) => info,
+            None => return,
+        };
+
+        if let Some(reexport) = info.reexports.iter().find(|e| e.exported == name) {
+            match &reexport.orig {
+                Some(orig) => {
+                    let (src, orig, is_value) = (reexport.src, orig.clone(), is_value && !reexport.is_type_only);
+                    self.mark_used(src, orig, is_value);
+                }
+                None => {
+                    let src = reexport.src;
+                    self.mark_all(src);
+                }
+            }
+            return;
+        }
+
+        // `export *` does not re-export `default`.
+        if name == js_word!("default") || info.exports.iter().any(|e| e.name == name) {
+            return;
+        }
+
+        for src in info.star_reexports.clone() {
+            self.mark_used(src, name.clone(), is_value);
+        }
+    }
+
+    fn mark_all(&mut self, module: ModuleId) {
+        if !self.fully_used.insert(module) {
+            return;
+        }
+
+        let info = match self.modules.get(&module) {
+            Some(info) => info,
+            None => return,
+        };
+
+        let srcs = info
+            .reexports
+            .iter()
+            .map(|e| e.src)
+            .chain(info.star_reexports.iter().copied())
+            .collect::<Vec<_>>();
+
+        for src in srcs {
+            self.mark_all(src);
+        }
+    }
+}
+
+struct ModuleInfo {
+    record: Arc<ModuleRecord>,
+    /// Names exported by this module, including named re-exports.
+    exports: Vec<Export>,
+    /// `export { a as b } from 'foo'` and `export * as b from 'foo'`.
+    reexports: Vec<ReExport>,
+    /// `export * from 'foo'`
+    star_reexports: Vec<ModuleId>,
+    usages: Vec<Usage>,
+    deps: Vec<ModuleId>,
+}
+
+struct Export {
+    name: JsWord,
+    span: Span,
+    /// `false` if it's a type or we don't know if it's a value.
+    is_value: bool,
+}
+
+struct ReExport {
+    exported: JsWord,
+    src: ModuleId,
+    /// [None] for `export * as foo from 'foo'`.
+    orig: Option<JsWord>,
+    is_type_only: bool,
+}
+
+#[derive(Clone)]
+enum Usage {
+    Named {
+        module: ModuleId,
+        name: JsWord,
+        is_value: bool,
+    },
+    /// Namespace imports, `import foo = require('foo')` and dynamic imports.
+    All {
+        module: ModuleId,
+    },
+}
+
+impl ModuleInfo {
+    fn new(record: Arc<ModuleRecord>, mut resolve_src: impl FnMut(&JsWord) -> Option<ModuleId>) -> Self {
+        let mut info = ModuleInfo {
+            record: record.clone(),
+            exports: Default::default(),
+            reexports: Default::default(),
+            star_reexports: Default::default(),
+            usages: Default::default(),
+            deps: Default::default(),
+        };
+
+        let mut resolve = |src: &Str| {
+            let id = resolve_src(&src.value)?;
+            info.deps.push(id);
+            Some(id)
+        };
+
+        let local_values = local_values(&record.ast);
+
+        let mut exports = vec![];
+        let mut reexports = vec![];
+        let mut star_reexports = vec![];
+        let mut usages = vec![];
+
+        for item in &record.ast.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(decl) => decl,
+                ModuleItem::Stmt(..) => continue,
+            };
+
+            match decl {
+                ModuleDecl::Import(import) => {
+                    let module = match resolve(&import.src) {
+                        Some(v) => v,
+                        None => continue,
+                    };
+
+                    for s in &import.specifiers {
+                        usages.push(match s {
+                            ImportSpecifier::Named(s) => Usage::Named {
+                                module,
+                                name: s.imported.as_ref().map(export_name).unwrap_or_else(|| s.local.sym.clone()),
+                                is_value: !import.type_only && !s.is_type_only,
+                            },
+                            ImportSpecifier::Default(..) => Usage::Named {
+                                module,
+                                name: js_word!("default"),
+                                is_value: !import.type_only,
+                            },
+                            ImportSpecifier::Namespace(..) => Usage::All { module },
+                        });
+                    }
+                }
+
+                ModuleDecl::ExportDecl(export) => match &export.decl {
+                    Decl::Class(c) => exports.push(Export::value(&c.ident)),
+                    Decl::Fn(f) => exports.push(Export::value(&f.ident)),
+                    Decl::Var(v) => {
+                        for decl in &v.decls {
+                            let mut finder = BindingFinder { exports: &mut exports };
+                            decl.name.visit_with(&mut finder);
+                        }
+                    }
+                    Decl::TsInterface(i) => exports.push(Export::ty(&i.id)),
+                    Decl::TsTypeAlias(a) => exports.push(Export::ty(&a.id)),
+                    Decl::TsEnum(e) => exports.push(Export::value(&e.id)),
+                    Decl::TsModule(m) => {
+                        if let TsModuleName::Ident(id) = &m.id {
+                            exports.push(Export::ty(id));
+                        }
+                    }
+                },
+
+                ModuleDecl::ExportNamed(export) => {
+                    let src = match &export.src {
+                        Some(src) => match resolve(src) {
+                            Some(v) => Some(v),
+                            None => continue,
+                        },
+                        None => None,
+                    };
+
+                    for s in &export.specifiers {
+                        match s {
+                            ExportSpecifier::Named(s) => {
+                                let orig = export_name(&s.orig);
+                                let exported = s.exported.as_ref().map(export_name).unwrap_or_else(|| orig.clone());
+
+                                exports.push(Export {
+                                    name: exported.clone(),
+                                    span: s.span,
+                                    is_value: src.is_none() && !export.type_only && !s.is_type_only && local_values.contains(&orig),
+                                });
+                                if let Some(src) = src {
+                                    reexports.push(ReExport {
+                                        exported,
+                                        src,
+                                        orig: Some(orig),
+                                        is_type_only: export.type_only || s.is_type_only,
+                                    });
+                                }
+                            }
+                            ExportSpecifier::Namespace(s) => {
+                                let exported = export_name(&s.name);
+
+                                exports.push(Export {
+                                    name: exported.clone(),
+                                    span: s.span,
+                                    is_value: false,
+                                });
+                                if let Some(src) = src {
+                                    reexports.push(ReExport {
+                                        exported,
+                                        src,
+                                        orig: None,
+                                        is_type_only: export.type_only,
+                                    });
+                                }
+                            }
+                            ExportSpecifier::Default(..) => {}
+                        }
+                    }
+                }
+
+                ModuleDecl::ExportAll(export) => {
+                    if let Some(src) = resolve(&export.src) {
+                        star_reexports.push(src);
+                    }
+                }
+
+                ModuleDecl::ExportDefaultDecl(export) => exports.push(Export {
+                    name: js_word!("default"),
+                    span: export.span,
+                    is_value: !matches!(export.decl, DefaultDecl::TsInterfaceDecl(..)),
+                }),
+
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { span, .. })
+                | ModuleDecl::TsExportAssignment(TsExportAssignment { span, .. }) => exports.push(Export {
+                    name: js_word!("default"),
+                    span: *span,
+                    is_value: true,
+                }),
+
+                ModuleDecl::TsImportEquals(import) => {
+                    if let TsModuleRef::TsExternalModuleRef(r) = &import.module_ref {
+                        if let Some(module) = resolve(&r.expr) {
+                            usages.push(Usage::All { module });
+                        }
+                    }
+                }
+
+                ModuleDecl::TsNamespaceExport(..) => {}
+            }
+        }
+
+        {
+            let mut v = DynamicImportFinder { srcs: vec![] };
+            record.ast.visit_with(&mut v);
+
+            for src in v.srcs {
+                if let Some(module) = resolve(&src) {
+                    usages.push(Usage::All { module });
+                }
+            }
+        }
+
+        info.exports = exports;
+        info.reexports = reexports;
+        info.star_reexports = star_reexports;
+        info.usages = usages;
+        info
+    }
+}
+
+impl Export {
+    fn value(i: &Ident) -> Self {
+        Export {
+            name: i.sym.clone(),
+            span: i.span,
+            is_value: true,
+        }
+    }
+
+    fn ty(i: &Ident) -> Self {
+        Export {
+            name: i.sym.clone(),
+            span: i.span,
+            is_value: false,
+        }
+    }
+}
+
+/// Returns the names of values declared at the top level of `m`.
+///
+/// Imported bindings are not included, as we don't know if they are values.
+fn local_values(m: &Module) -> FxHashSet<JsWord> {
+    let mut values = vec![];
+
+    for item in &m.body {
+        let decl = match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+            _ => continue,
+        };
+
+        match decl {
+            Decl::Class(c) => values.push(Export::value(&c.ident)),
+            Decl::Fn(f) => values.push(Export::value(&f.ident)),
+            Decl::Var(v) => {
+                for decl in &v.decls {
+                    let mut finder = BindingFinder { exports: &mut values };
+                    decl.name.visit_with(&mut finder);
+                }
+            }
+            Decl::TsEnum(e) => values.push(Export::value(&e.id)),
+            Decl::TsInterface(..) | Decl::TsTypeAlias(..) | Decl::TsModule(..) => {}
+        }
+    }
+
+    values.into_iter().map(|v| v.name).collect()
+}
+
+fn export_name(n: &ModuleExportName) -> JsWord {
+    match n {
+        ModuleExportName::Ident(i) => i.sym.clone(),
+        ModuleExportName::Str(s) => s.value.clone(),
+    }
+}
+
+struct BindingFinder<'a> {
+    exports: &'a mut Vec<Export>,
+}
+
+impl Visit for BindingFinder<'_> {
+    fn visit_binding_ident(&mut self, i: &BindingIdent) {
+        self.exports.push(Export::value(&i.id));
+    }
+
+    /// Default values are not bindings.
+    fn visit_expr(&mut self, _: &Expr) {}
+}
+
+/// Finds `import('foo')` and `require('foo')`.
+struct DynamicImportFinder {
+    srcs: Vec<Str>,
+}
+
+impl Visit for DynamicImportFinder {
+    fn visit_call_expr(&mut self, e: &CallExpr) {
+        e.visit_children_with(self);
+
+        let is_import = match &e.callee {
+            Callee::Import(..) => true,
+            Callee::Expr(callee) => matches!(&**callee, Expr::Ident(i) if &*i.sym == "require"),
+            Callee::Super(..) => false,
+        };
+
+        if is_import {
+            if let Some(Expr::Lit(Lit::Str(src))) = e.args.first().map(|arg| &*arg.expr) {
+                self.srcs.push(src.clone());
+            }
+        }
+    }
+}
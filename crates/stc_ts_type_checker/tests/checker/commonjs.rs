@@ -0,0 +1,68 @@
+use crate::check;
+
+#[test]
+fn require_module_exports() {
+    let errors = check(
+        Default::default(),
+        &[
+            ("a.js", "module.exports = { foo: 1 };\n"),
+            ("b.js", "const a = require(\"./a\");\na.foo.toFixed();\na.bar;\n"),
+        ],
+        &["b.js"],
+    );
+
+    assert_eq!(errors, vec![("b.js".into(), 3, 2339)]);
+}
+
+#[test]
+fn require_named_exports() {
+    let errors = check(
+        Default::default(),
+        &[
+            ("a.js", "exports.foo = 1;\nmodule.exports.bar = \"\";\n"),
+            ("b.js", "const a = require(\"./a\");\na.foo.toFixed();\na.bar.toFixed();\n"),
+        ],
+        &["b.js"],
+    );
+
+    assert_eq!(errors, vec![("b.js".into(), 3, 2339)]);
+}
+
+#[test]
+fn require_es_module_with_default_export() {
+    let errors = check(
+        Default::default(),
+        &[
+            ("a.ts", "export default 1;\nexport const foo = \"\";\n"),
+            ("b.js", "const a = require(\"./a\");\na.foo.toUpperCase();\na.default.toFixed();\n"),
+        ],
+        &["b.js"],
+    );
+
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn export_assignment_targets_are_not_checked() {
+    let errors = check(
+        Default::default(),
+        &[(
+            "a.js",
+            "module.exports = { foo: 1 };
+exports.bar = \"\";
+module.exports.baz = () => {};
+module.exports[\"qux\"] = 1;
+",
+        )],
+        &["a.js"],
+    );
+
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn export_assignment_is_validated() {
+    let errors = check(Default::default(), &[("a.js", "exports.foo = missing;\n")], &["a.js"]);
+
+    assert_eq!(errors, vec![("a.js".into(), 1, 2304)]);
+}
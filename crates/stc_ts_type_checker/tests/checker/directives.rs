@@ -0,0 +1,88 @@
+use crate::check;
+
+fn check_file(code: &str) -> Vec<(usize, usize)> {
+    check(Default::default(), &[("a.ts", code)], &["a.ts"])
+        .into_iter()
+        .map(|(_, line, code)| (line, code))
+        .collect()
+}
+
+#[test]
+fn ts_ignore() {
+    let errors = check_file(
+        "// @ts-ignore
+let a: string = 1;
+
+// @ts-ignore: comments after the directive are allowed
+
+// Empty lines and line comments are skipped.
+let b: string = 1;
+/* @ts-ignore */
+let c: string = 1;
+let d: string = 1;
+",
+    );
+
+    assert_eq!(errors, vec![(10, 2322)]);
+}
+
+#[test]
+fn ts_expect_error() {
+    let errors = check_file(
+        "// @ts-expect-error
+let a: string = 1;
+// @ts-expect-error
+let b: string = \"\";
+/**
+ * Only the last line of a block comment is checked.
+ * @ts-expect-error
+ */
+let c: string = 1;
+/*
+ * @ts-expect-error */
+let d: string = 1;
+",
+    );
+
+    assert_eq!(errors, vec![(3, 2578), (9, 2322)]);
+}
+
+#[test]
+fn ts_nocheck() {
+    let errors = check_file(
+        "// @ts-nocheck
+let a: string = 1;
+// @ts-expect-error
+let b: string = \"\";
+",
+    );
+
+    assert_eq!(errors, vec![]);
+}
+
+#[test]
+fn ts_nocheck_after_statement() {
+    let errors = check_file(
+        "let a: string = 1;
+// @ts-nocheck
+let b: string = 1;
+",
+    );
+
+    assert_eq!(errors, vec![(1, 2322), (3, 2322)]);
+}
+
+/// Directives only apply to the file they are in.
+#[test]
+fn other_file() {
+    let errors = check(
+        Default::default(),
+        &[
+            ("a.ts", "// @ts-nocheck\nimport { b } from \"./b\";\nlet a: string = 1;\n"),
+            ("b.ts", "// @ts-ignore\nexport const b = 1;\nlet c: string = 1;\n"),
+        ],
+        &["a.ts"],
+    );
+
+    assert_eq!(errors, vec![("b.ts".into(), 3, 2322)]);
+}
@@ -0,0 +1,60 @@
+use stc_ts_env::Rule;
+
+use crate::check;
+
+const FN: &str = "declare const f: () => void;\nexport = f;\n";
+
+const CLASS: &str = "declare class C {}\nexport = C;\n";
+
+#[test]
+fn default_import_requires_flag() {
+    let files = [("a.ts", FN), ("b.ts", "import f from \"./a\";\nf();\n")];
+
+    assert_eq!(check(Default::default(), &files, &["b.ts"]), vec![("b.ts".into(), 1, 1259)]);
+
+    let rule = Rule {
+        es_module_interop: true,
+        ..Default::default()
+    };
+    let errors = check(rule, &files, &["b.ts"]);
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn namespace_import_of_variable() {
+    let errors = check(
+        Default::default(),
+        &[("a.ts", FN), ("b.ts", "import * as f from \"./a\";\nf();\n")],
+        &["b.ts"],
+    );
+
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn namespace_import_of_class() {
+    let errors = check(
+        Default::default(),
+        &[("a.ts", CLASS), ("b.ts", "import * as C from \"./a\";\n")],
+        &["b.ts"],
+    );
+
+    assert_eq!(errors, vec![("b.ts".into(), 1, 2497)]);
+}
+
+#[test]
+fn namespace_import_of_class_merged_with_namespace() {
+    let errors = check(
+        Default::default(),
+        &[
+            (
+                "a.ts",
+                "declare class C {}\ndeclare namespace C {\n    const x: number;\n}\nexport = C;\n",
+            ),
+            ("b.ts", "import * as C from \"./a\";\nC.x.toFixed();\n"),
+        ],
+        &["b.ts"],
+    );
+
+    assert!(errors.is_empty(), "{:?}", errors);
+}
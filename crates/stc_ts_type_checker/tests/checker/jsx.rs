@@ -0,0 +1,166 @@
+use stc_ts_env::{JsxMode, Rule};
+
+use crate::check;
+
+/// Checks `code` after the declaration of the `JSX` namespace, which contains
+/// `extra`.
+///
+/// Returns `(line, code)` of the errors, where `line` is relative to `code`.
+fn check_tsx(extra: &str, code: &str) -> Vec<(usize, usize)> {
+    let jsx = format!(
+        "declare namespace JSX {{
+    interface Element {{}}
+    interface ElementAttributesProperty {{
+        props: {{}};
+    }}
+    interface ElementChildrenAttribute {{
+        children: {{}};
+    }}
+    interface IntrinsicElements {{
+        div: {{ id?: string }};
+    }}
+{}}}
+",
+        extra
+    );
+    let offset = jsx.lines().count();
+
+    let rule = Rule {
+        jsx: JsxMode::React,
+        ..Default::default()
+    };
+
+    check(rule, &[("a.tsx", &format!("{}{}", jsx, code))], &["a.tsx"])
+        .into_iter()
+        .map(|(_, line, code)| (line - offset, code))
+        .collect()
+}
+
+#[test]
+fn component_props() {
+    let errors = check_tsx(
+        "",
+        "function Foo(props: { x: number }) {
+    return <div />;
+}
+<Foo x={1} />;
+<Foo x=\"a\" />;
+",
+    );
+
+    assert_eq!(errors, vec![(5, 2322)]);
+}
+
+#[test]
+fn children() {
+    let errors = check_tsx(
+        "",
+        "declare function Foo(props: { children: string }): JSX.Element;
+<Foo>hello</Foo>;
+<Foo>
+    <div />
+</Foo>;
+",
+    );
+
+    assert_eq!(errors, vec![(3, 2322)]);
+}
+
+#[test]
+fn element_attributes_property() {
+    let errors = check_tsx(
+        "",
+        "declare class Comp {
+    props: { x: number };
+    render(): JSX.Element;
+}
+<Comp x={1} />;
+<Comp x=\"\" />;
+",
+    );
+
+    assert_eq!(errors, vec![(6, 2322)]);
+}
+
+#[test]
+fn library_managed_attributes() {
+    let code = "declare function Foo(props: { x: number }): JSX.Element;
+<Foo />;
+";
+
+    // TS2741 is normalized to TS2322.
+    assert_eq!(check_tsx("", code), vec![(2, 2322)]);
+
+    let errors = check_tsx("    type LibraryManagedAttributes<C, P> = Partial<P>;\n", code);
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn overloads() {
+    let errors = check_tsx(
+        "",
+        "declare function Foo(props: { x: number }): JSX.Element;
+declare function Foo(props: { y: string }): JSX.Element;
+<Foo x={1} />;
+<Foo y=\"\" />;
+<Foo x=\"\" />;
+",
+    );
+
+    assert_eq!(errors, vec![(5, 2769)]);
+}
+
+#[test]
+fn generic_components() {
+    let errors = check_tsx(
+        "",
+        "declare function List<T>(props: { items: T[]; render: (item: T) => string }): JSX.Element;
+<List items={[1]} render={(item: number) => item.toFixed()} />;
+<List items={[1]} render={(item: number) => item} />;
+<List<string> items={[1]} render={(item: string) => item} />;
+",
+    );
+
+    assert_eq!(errors, vec![(3, 2322), (4, 2322)]);
+}
+
+#[test]
+fn hyphenated_attributes() {
+    let errors = check_tsx(
+        "",
+        "declare function Foo(props: { x: number }): JSX.Element;
+<div data-foo=\"a\" />;
+<Foo x={1} data-foo={1} />;
+",
+    );
+
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn jsx_import_source_pragma() {
+    let rule = Rule {
+        jsx: JsxMode::ReactJsx,
+        ..Default::default()
+    };
+
+    let errors = check(
+        rule,
+        &[
+            (
+                "preact/jsx-runtime.d.ts",
+                "export namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        div: { id?: string };
+    }
+}
+",
+            ),
+            ("a.tsx", "/** @jsxImportSource preact */\n<div id={1} />;\nexport {};\n"),
+        ],
+        &["a.tsx"],
+    );
+
+    assert_eq!(errors, vec![("a.tsx".into(), 2, 2322)]);
+}
@@ -0,0 +1,77 @@
+use crate::{check, run};
+
+/// Returns the lines of errors in `files`, which are all used as entries.
+fn error_lines(files: &[(&str, &str)]) -> Vec<(String, usize)> {
+    let entries = files.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+
+    run(Default::default(), files, &entries, &[], |_| Some(()))
+        .into_iter()
+        .map(|(file, line, _)| (file, line))
+        .collect()
+}
+
+#[test]
+fn without_reference() {
+    let errors = check(Default::default(), &[("a.ts", "\"\".at(0);\n")], &["a.ts"]);
+
+    assert_eq!(errors, vec![("a.ts".into(), 1, 2339)]);
+}
+
+#[test]
+fn reference_lib() {
+    let errors = check(
+        Default::default(),
+        &[("a.ts", "/// <reference lib=\"es2022\" />\n\"\".at(0);\n")],
+        &["a.ts"],
+    );
+
+    assert_eq!(errors, vec![]);
+}
+
+/// Like `tsc`, a library referenced by a file is available to all files.
+#[test]
+fn reference_lib_in_another_file() {
+    let errors = check(
+        Default::default(),
+        &[
+            ("a.ts", "import \"./b\";\n\"\".at(0);\n"),
+            ("b.ts", "/// <reference lib=\"ES2022\" />\nexport {};\n"),
+        ],
+        &["a.ts"],
+    );
+
+    assert_eq!(errors, vec![]);
+}
+
+#[test]
+fn no_default_lib() {
+    let errors = error_lines(&[(
+        "a.ts",
+        "/// <reference no-default-lib=\"true\" />\n/// <reference lib=\"es5\" />\nlet a: Array<number>;\nlet m: Map<string, number>;\n",
+    )]);
+
+    assert_eq!(errors, vec![("a.ts".into(), 4)]);
+}
+
+#[test]
+fn reference_lib_keeps_default_libs() {
+    let errors = error_lines(&[(
+        "a.ts",
+        "/// <reference lib=\"es5\" />\nlet a: Array<number>;\nlet m: Map<string, number>;\n",
+    )]);
+
+    assert_eq!(errors, vec![]);
+}
+
+/// References are only valid at the top of a file.
+#[test]
+fn reference_lib_after_statement() {
+    let errors = check(
+        Default::default(),
+        &[("a.ts", "\"\".at(0);\n/// <reference lib=\"es2022\" />\n\"\".at(0);\n")],
+        &["a.ts"],
+    );
+
+    assert_eq!(errors, vec![("a.ts".into(), 1, 2339), ("a.ts".into(), 3, 2339)]);
+}
+
@@ -0,0 +1,209 @@
+use crate::check_lints;
+
+/// Checks `a.ts` with a single lint enabled and returns `(line, rule)` of the
+/// lint errors.
+fn lint(name: &str, code: &str) -> Vec<(usize, &'static str)> {
+    check_lints(&[("a.ts", code)], &["a.ts"], &[name])
+        .into_iter()
+        .map(|(_, line, rule)| (line, rule))
+        .collect()
+}
+
+#[test]
+fn no_floating_promises() {
+    let errors = lint(
+        "no-floating-promises",
+        "declare function f(): Promise<number>;
+f();
+void f();
+f().catch(() => {});
+f().then(() => {});
+f().then(() => {}, () => {});
+async function g() {
+    await f();
+    f();
+}
+",
+    );
+
+    assert_eq!(
+        errors,
+        vec![
+            (2, "no-floating-promises"),
+            (5, "no-floating-promises"),
+            (9, "no-floating-promises")
+        ]
+    );
+}
+
+#[test]
+fn no_misused_promises_condition() {
+    let errors = lint(
+        "no-misused-promises",
+        "declare const p: Promise<boolean>;
+if (p) {}
+while (!p) {}
+const a = p ? 1 : 2;
+const b = p && 1;
+if (Math.random()) {}
+",
+    );
+
+    assert_eq!(
+        errors,
+        vec![
+            (2, "no-misused-promises"),
+            (3, "no-misused-promises"),
+            (4, "no-misused-promises"),
+            (5, "no-misused-promises"),
+        ]
+    );
+}
+
+#[test]
+fn no_misused_promises_object_spread() {
+    let errors = lint(
+        "no-misused-promises",
+        "declare const p: Promise<{ a: number }>;
+declare const o: { a: number };
+const a = { ...p };
+const b = { ...o };
+",
+    );
+
+    assert_eq!(errors, vec![(3, "no-misused-promises")]);
+}
+
+#[test]
+fn no_misused_promises_ignores_other_spreads() {
+    let errors = check_lints(
+        &[(
+            "a.tsx",
+            "declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        div: any;
+    }
+}
+declare const p: Promise<any>;
+declare const ps: Promise<number>[];
+const a = <div {...p} />;
+const b = [...ps];
+f(...ps);
+function f(...args: Promise<number>[]) {}
+",
+        )],
+        &["a.tsx"],
+        &["no-misused-promises"],
+    );
+
+    assert_eq!(errors, vec![]);
+}
+
+#[test]
+fn no_misused_promises_void_return() {
+    let errors = lint(
+        "no-misused-promises",
+        "declare function run(cb: () => void): void;
+declare function runAsync(cb: () => Promise<void>): void;
+declare function f(): Promise<number>;
+run(async () => {});
+run(f);
+run(() => {});
+runAsync(async () => {});
+const o: { cb: () => void } = { cb: async () => {} };
+const p: { cb: () => Promise<void> } = { cb: async () => {} };
+",
+    );
+
+    assert_eq!(
+        errors,
+        vec![(4, "no-misused-promises"), (5, "no-misused-promises"), (8, "no-misused-promises")]
+    );
+}
+
+#[test]
+fn no_misused_promises_void_return_attribute() {
+    let errors = check_lints(
+        &[(
+            "a.tsx",
+            "declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        button: { onClick: () => void };
+    }
+}
+const a = <button onClick={async () => {}} />;
+const b = <button onClick={() => {}} />;
+",
+        )],
+        &["a.tsx"],
+        &["no-misused-promises"],
+    );
+
+    assert_eq!(
+        errors.into_iter().map(|(_, line, rule)| (line, rule)).collect::<Vec<_>>(),
+        vec![(7, "no-misused-promises")]
+    );
+}
+
+#[test]
+fn strict_boolean_expressions() {
+    let errors = lint(
+        "strict-boolean-expressions",
+        "declare const any: any;
+declare const str: string | undefined;
+declare const num: number | null;
+declare const bool: boolean | undefined;
+declare const obj: { a: number } | undefined;
+declare const nil: null;
+if (any) {}
+if (str) {}
+if (num) {}
+if (bool) {}
+if (obj) {}
+if (nil) {}
+if (\"\" || 0 || false) {}
+",
+    );
+
+    assert_eq!(
+        errors,
+        vec![
+            (7, "strict-boolean-expressions"),
+            (8, "strict-boolean-expressions"),
+            (9, "strict-boolean-expressions"),
+            (10, "strict-boolean-expressions"),
+            (12, "strict-boolean-expressions"),
+        ]
+    );
+}
+
+#[test]
+fn no_unnecessary_condition() {
+    let errors = lint(
+        "no-unnecessary-condition",
+        "declare const obj: { a: number };
+declare const nil: null | undefined;
+declare const maybe: { a: number } | undefined;
+declare const b: boolean;
+if (obj) {}
+while (nil) {}
+const a = maybe ? 1 : 2;
+const c = b && obj;
+",
+    );
+
+    assert_eq!(errors, vec![(5, "no-unnecessary-condition"), (6, "no-unnecessary-condition")]);
+}
+
+#[test]
+fn lints_are_disabled_by_default() {
+    let errors = check_lints(
+        &[("a.ts", "declare function f(): Promise<number>;\nf();\nif (f()) {}\n")],
+        &["a.ts"],
+        &[],
+    );
+
+    assert_eq!(errors, vec![]);
+}
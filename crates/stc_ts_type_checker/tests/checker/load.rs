@@ -0,0 +1,43 @@
+use stc_ts_errors::ErrorKind;
+
+use crate::{file_names, new_checker};
+
+/// Checks `entries` and returns whether each entry is loaded, and `(file,
+/// code)` of the errors, sorted.
+fn check_entries(files: &[(&str, &str)], entries: &[&str]) -> (Vec<bool>, Vec<(String, usize)>) {
+    testing::run_test2(false, |cm, handler| {
+        let mut checker = new_checker(&cm, handler, Default::default(), files);
+
+        let ids = checker.check_all(&file_names(entries));
+
+        let mut errors = checker
+            .take_errors_by_file()
+            .into_iter()
+            .flat_map(|(file, errors)| {
+                ErrorKind::flatten(errors)
+                    .into_iter()
+                    .map(move |err| (file.to_string(), ErrorKind::normalize_error_code(err.code())))
+            })
+            .collect::<Vec<_>>();
+        errors.sort();
+
+        Ok((ids.iter().map(Option::is_some).collect(), errors))
+    })
+    .unwrap()
+}
+
+#[test]
+fn missing_entry() {
+    let (ids, errors) = check_entries(&[("b.ts", "let b: string = 1;\n")], &["a.ts", "b.ts"]);
+
+    assert_eq!(ids, vec![false, true]);
+    assert_eq!(errors, vec![("a.ts".into(), 0), ("b.ts".into(), 2322)]);
+}
+
+#[test]
+fn entry_with_syntax_error() {
+    let (ids, errors) = check_entries(&[("a.ts", "let a = ;\n"), ("b.ts", "let b: string = 1;\n")], &["a.ts", "b.ts"]);
+
+    assert_eq!(ids, vec![false, true]);
+    assert_eq!(errors, vec![("a.ts".into(), 0), ("b.ts".into(), 2322)]);
+}
@@ -0,0 +1,137 @@
+//! Tests for checking multiple files using [Checker].
+//!
+//! Files are stored in memory, and the errors are compared using the name of
+//! the file, the line number and the error code.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Error, Result};
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{Env, ModuleConfig, Rule};
+use stc_ts_errors::ErrorKind;
+use stc_ts_file_analyzer::env::EnvFactory;
+use stc_ts_type_checker::{
+    loader::{LoadFile, ModuleLoader},
+    Checker,
+};
+use swc_common::{errors::Handler, FileName, SourceFile, SourceMap, Spanned};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_loader::resolve::Resolve;
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+
+mod commonjs;
+mod directives;
+mod export_equals;
+mod jsx;
+mod lib_references;
+mod lints;
+mod load;
+mod query;
+mod unused_exports;
+
+/// Files of a test, stored in memory.
+#[derive(Clone)]
+struct Files(Arc<Vec<(String, String)>>);
+
+impl Resolve for Files {
+    fn resolve(&self, _: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+        let name = module_specifier.trim_start_matches("./");
+
+        ["", ".ts", ".tsx", ".d.ts", ".js"]
+            .iter()
+            .map(|ext| format!("{}{}", name, ext))
+            .find(|file| self.0.iter().any(|(f, _)| f == file))
+            .map(|file| FileName::Real(file.into()))
+            .with_context(|| format!("failed to resolve `{}`", module_specifier))
+    }
+}
+
+impl LoadFile for Files {
+    fn load_file(&self, cm: &Arc<SourceMap>, filename: &Arc<FileName>) -> Result<(Arc<SourceFile>, Syntax)> {
+        let name = filename.to_string();
+        let (_, src) = self
+            .0
+            .iter()
+            .find(|(f, _)| *f == name)
+            .with_context(|| format!("failed to load `{}`", name))?;
+
+        let syntax = if name.ends_with(".js") {
+            Syntax::Es(EsConfig::default())
+        } else {
+            Syntax::Typescript(TsConfig {
+                tsx: name.ends_with(".tsx"),
+                dts: name.ends_with(".d.ts"),
+                ..Default::default()
+            })
+        };
+
+        Ok((cm.new_source_file((**filename).clone(), src.clone()), syntax))
+    }
+}
+
+/// Checks `entries` and returns `(file, line, code)` of the errors, sorted.
+fn check(rule: Rule, files: &[(&str, &str)], entries: &[&str]) -> Vec<(String, usize, usize)> {
+    run(rule, files, entries, &[], |err| Some(ErrorKind::normalize_error_code(err.code())))
+}
+
+/// Checks `entries` with `lints` enabled and returns `(file, line, rule)` of
+/// the lint errors, sorted.
+fn check_lints(files: &[(&str, &str)], entries: &[&str], lints: &[&str]) -> Vec<(String, usize, &'static str)> {
+    run(Default::default(), files, entries, lints, |err| match err {
+        ErrorKind::Lint { rule, .. } => Some(*rule),
+        _ => None,
+    })
+}
+
+/// Checks `entries` and returns `(file, line, key)` of the errors for which
+/// `key` returns a value, sorted.
+fn run<K>(
+    rule: Rule,
+    files: &[(&str, &str)],
+    entries: &[&str],
+    lints: &[&str],
+    key: impl Fn(&ErrorKind) -> Option<K>,
+) -> Vec<(String, usize, K)>
+where
+    K: Ord,
+{
+    testing::run_test2(false, |cm, handler| {
+        let mut checker = new_checker(&cm, handler, rule, files).with_lints(lints.iter().map(|name| name.to_string()).collect());
+
+        checker.check_all(&file_names(entries));
+
+        let mut errors = ErrorKind::flatten(checker.take_errors())
+            .into_iter()
+            .filter_map(|err| {
+                let key = key(&err)?;
+                let loc = cm.lookup_char_pos(err.span().lo);
+
+                Some((loc.file.name.to_string(), loc.line, key))
+            })
+            .collect::<Vec<_>>();
+        errors.sort();
+
+        Ok(errors)
+    })
+    .unwrap()
+}
+
+/// Creates a checker which loads `files` from memory.
+fn new_checker(cm: &Arc<SourceMap>, handler: Handler, rule: Rule, files: &[(&str, &str)]) -> Checker<ModuleLoader<Files, Files>> {
+    let env = Env::simple(rule, EsVersion::latest(), ModuleConfig::None, &Lib::load("es2020"));
+    let files = Files(Arc::new(
+        files.iter().map(|(name, src)| (name.to_string(), src.to_string())).collect(),
+    ));
+
+    Checker::new(
+        cm.clone(),
+        Arc::new(handler),
+        env.clone(),
+        None,
+        ModuleLoader::new(cm.clone(), env, files.clone(), files),
+    )
+}
+
+fn file_names(names: &[&str]) -> Vec<Arc<FileName>> {
+    names.iter().map(|name| Arc::new(FileName::Real(name.into()))).collect()
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use stc_ts_type_checker::print_type;
+use swc_common::FileName;
+
+use crate::{file_names, new_checker};
+
+const LIB: &str = "interface Point {
+    x: number;
+    y: number;
+}
+export const p: Point = { x: 1, y: 2 };
+export const s = \"\";
+export interface Named {
+    name: string;
+}
+export interface Named {
+    id: number;
+}
+";
+
+#[test]
+fn query() {
+    testing::run_test2(false, |cm, handler| {
+        let checker = new_checker(
+            &cm,
+            handler,
+            Default::default(),
+            &[("lib.ts", LIB), ("unchecked.ts", "export const a = 1;\n")],
+        );
+        checker.check_all(&file_names(&["lib.ts"]));
+
+        let lib = Arc::new(FileName::Real("lib.ts".into()));
+        let unchecked = Arc::new(FileName::Real("unchecked.ts".into()));
+
+        let p = checker.type_of_exported_var(&lib, "p").unwrap();
+        let s = checker.type_of_exported_var(&lib, "s").unwrap();
+        assert!(checker.type_of_exported_var(&lib, "Point").is_none());
+        assert!(print_type(&p).contains("Point"), "{}", print_type(&p));
+
+        // `Point` is not exported, so it's resolved using the stored types of
+        // the module.
+        let members = checker.type_members(&lib, &p).unwrap();
+        assert_eq!(members.len(), 2);
+
+        assert!(checker.is_assignable(&lib, &p, &p));
+        assert!(!checker.is_assignable(&lib, &p, &s));
+
+        assert_eq!(checker.exported_types(&lib, "Named").len(), 2);
+        assert!(checker.exported_types(&lib, "Point").is_empty());
+
+        assert!(checker.with_analyzer(&unchecked, |_, _| ()).is_none());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Unlike the printers used for debugging, [print_type] should work without
+/// `debug_assertions`.
+#[test]
+fn print_types() {
+    testing::run_test2(false, |cm, handler| {
+        let checker = new_checker(
+            &cm,
+            handler,
+            Default::default(),
+            &[(
+                "lib.ts",
+                "interface Point {
+    x: number;
+}
+export declare const p: Point;
+export declare const u: number | string[];
+export declare const f: (a: number) => void;
+",
+            )],
+        );
+        checker.check_all(&file_names(&["lib.ts"]));
+
+        let lib = Arc::new(FileName::Real("lib.ts".into()));
+        let print = |name: &str| print_type(&checker.type_of_exported_var(&lib, name).unwrap());
+
+        assert_eq!(print("p"), "Point");
+        assert_eq!(print("u"), "number | string[]");
+        assert_eq!(print("f"), "(a: number) => void");
+
+        Ok(())
+    })
+    .unwrap();
+}
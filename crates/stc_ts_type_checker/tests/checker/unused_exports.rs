@@ -0,0 +1,176 @@
+use stc_ts_type_checker::UnusedExport;
+
+use crate::{file_names, new_checker};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Report {
+    unused: Vec<(String, String)>,
+    only_used_as_type: Vec<(String, String)>,
+    unreachable_modules: Vec<String>,
+}
+
+fn find(files: &[(&str, &str)], entries: &[&str]) -> Report {
+    testing::run_test2(false, |cm, handler| {
+        let checker = new_checker(&cm, handler, Default::default(), files);
+
+        let names = files.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let report = checker.find_unused_exports(&file_names(&names), &file_names(entries));
+
+        let exports = |exports: &[UnusedExport]| {
+            let mut v = exports.iter().map(|e| (e.file.to_string(), e.name.to_string())).collect::<Vec<_>>();
+            v.sort();
+            v
+        };
+
+        Ok(Report {
+            unused: exports(&report.unused),
+            only_used_as_type: exports(&report.only_used_as_type),
+            unreachable_modules: report.unreachable_modules.iter().map(|f| f.to_string()).collect(),
+        })
+    })
+    .unwrap()
+}
+
+fn names(v: &[(&str, &str)]) -> Vec<(String, String)> {
+    v.iter().map(|(file, name)| (file.to_string(), name.to_string())).collect()
+}
+
+#[test]
+fn unused_named_export() {
+    let report = find(
+        &[
+            ("main.ts", "import { a } from \"./lib\";\nexport const unused = a;\n"),
+            ("lib.ts", "export const a = 1;\nexport function b() {}\nexport interface C {}\n"),
+            ("unreachable.ts", "export const d = 1;\n"),
+        ],
+        &["main.ts"],
+    );
+
+    assert_eq!(
+        report,
+        Report {
+            unused: names(&[("lib.ts", "C"), ("lib.ts", "b")]),
+            only_used_as_type: vec![],
+            unreachable_modules: vec!["unreachable.ts".into()],
+        }
+    );
+}
+
+#[test]
+fn value_only_used_as_type() {
+    let report = find(
+        &[
+            (
+                "main.ts",
+                "import type { A } from \"./lib\";\nimport { type B, C } from \"./lib\";\nlet v: A | B | C;\n",
+            ),
+            ("lib.ts", "export class A {}\nexport class B {}\nexport class C {}\n"),
+        ],
+        &["main.ts"],
+    );
+
+    assert_eq!(
+        report,
+        Report {
+            unused: vec![],
+            only_used_as_type: names(&[("lib.ts", "A"), ("lib.ts", "B")]),
+            unreachable_modules: vec![],
+        }
+    );
+}
+
+#[test]
+fn local_export_specifier_only_used_as_type() {
+    let report = find(
+        &[
+            ("main.ts", "import type { a, B } from \"./lib\";\nlet v: typeof a | B;\n"),
+            ("lib.ts", "const a = 1;\ninterface B {}\nexport { a, B };\n"),
+        ],
+        &["main.ts"],
+    );
+
+    assert_eq!(
+        report,
+        Report {
+            unused: vec![],
+            only_used_as_type: names(&[("lib.ts", "a")]),
+            unreachable_modules: vec![],
+        }
+    );
+}
+
+#[test]
+fn reexports() {
+    let report = find(
+        &[
+            ("main.ts", "import { a, renamed } from \"./index\";\n"),
+            (
+                "index.ts",
+                "export * from \"./a\";\nexport { b as renamed, c as unused } from \"./b\";\n",
+            ),
+            ("a.ts", "export const a = 1;\nexport const a2 = 1;\nexport default 1;\n"),
+            ("b.ts", "export const b = 1;\nexport const c = 1;\nexport const d = 1;\n"),
+        ],
+        &["main.ts"],
+    );
+
+    assert_eq!(
+        report,
+        Report {
+            unused: names(&[
+                ("a.ts", "a2"),
+                ("a.ts", "default"),
+                ("b.ts", "c"),
+                ("b.ts", "d"),
+                ("index.ts", "unused"),
+            ]),
+            only_used_as_type: vec![],
+            unreachable_modules: vec![],
+        }
+    );
+}
+
+#[test]
+fn namespace_and_dynamic_imports_use_all_exports() {
+    let report = find(
+        &[
+            ("main.ts", "import * as a from \"./a\";\nimport(\"./b\");\n"),
+            ("a.ts", "export const a = 1;\nexport const a2 = 1;\n"),
+            ("b.ts", "export const b = 1;\n"),
+        ],
+        &["main.ts"],
+    );
+
+    assert_eq!(
+        report,
+        Report {
+            unused: vec![],
+            only_used_as_type: vec![],
+            unreachable_modules: vec![],
+        }
+    );
+}
+
+/// Without entry points, files which are not imported by other files are used
+/// as the entry points.
+#[test]
+fn default_entries() {
+    let report = find(
+        &[
+            ("main.ts", "import { a } from \"./a\";\nexport const main = 1;\n"),
+            ("a.ts", "export const a = 1;\nexport const unused = 1;\n"),
+            ("cycle1.ts", "import { c2 } from \"./cycle2\";\nexport const c1 = 1;\n"),
+            ("cycle2.ts", "import { c1 } from \"./cycle1\";\nexport const c2 = 1;\n"),
+        ],
+        &[],
+    );
+
+    assert_eq!(
+        report,
+        Report {
+            unused: names(&[("a.ts", "unused")]),
+            only_used_as_type: vec![],
+            unreachable_modules: vec!["cycle1.ts".into(), "cycle2.ts".into()],
+        }
+    );
+}
@@ -83,6 +83,15 @@ pub struct ModuleTypeData {
 
     pub private_types: FxHashMap<Id, Vec<Type>>,
     pub types: FxHashMap<JsWord, Vec<Type>>,
+
+    /// `true` if the module uses `export =`.
+    ///
+    /// The type of `export =` is stored as `default`.
+    pub has_export_equals: bool,
+
+    /// `true` if the target of `export =` is not a variable or a namespace,
+    /// so the module can't be imported using `import * as`.
+    pub export_equals_needs_default_import: bool,
 }
 
 impl Visitable for ModuleTypeData {}
@@ -122,6 +131,9 @@ impl AddAssign for ModuleTypeData {
 
         self.vars.extend(other.vars);
         self.private_vars.extend(other.private_vars);
+
+        self.has_export_equals |= other.has_export_equals;
+        self.export_equals_needs_default_import |= other.export_equals_needs_default_import;
     }
 }
 
@@ -2970,6 +2982,7 @@ impl VisitMut<Type> for Freezer {
             }),
         );
 
+        stc_utils::stats::record(|s| s.freezes += 1);
         *ty = Type::Arc(Freezed { ty: Arc::new(new_ty) })
     }
 }
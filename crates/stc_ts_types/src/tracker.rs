@@ -38,6 +38,8 @@ impl<const N: &'static str> Default for Tracker<N> {
             trace!("Creating `{}` from {}", N, loc);
         }
 
+        stc_utils::stats::record(|s| s.types_created += 1);
+
         Self { _priv: () }
     }
 }
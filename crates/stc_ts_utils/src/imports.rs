@@ -25,7 +25,77 @@ impl ImportRef {
     }
 }
 
+/// Libraries referenced using triple-slash directives.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LibReferences {
+    /// `/// <reference lib="es2018" />`
+    pub libs: Vec<JsWord>,
+    /// `/// <reference no-default-lib="true" />`
+    pub no_default_lib: bool,
+}
+
 pub fn find_imports_in_comments<C>(comments: C, span: Span) -> Vec<ImportRef>
+where
+    C: Comments,
+{
+    let mut deps = vec![];
+
+    for_each_reference(comments, span, |attrs| {
+        if let Some(path) = attr(attrs, "path") {
+            deps.push(ImportRef::Path(path.into()));
+        } else if let Some(path) = attr(attrs, "types") {
+            deps.push(ImportRef::Types(path.into()));
+        }
+    });
+
+    deps
+}
+
+pub fn find_lib_references_in_comments<C>(comments: C, span: Span) -> LibReferences
+where
+    C: Comments,
+{
+    let mut refs = LibReferences::default();
+
+    for_each_reference(comments, span, |attrs| {
+        if let Some(lib) = attr(attrs, "lib") {
+            refs.libs.push(lib.to_ascii_lowercase().into());
+        } else if attr(attrs, "no-default-lib") == Some("true") {
+            refs.no_default_lib = true;
+        }
+    });
+
+    refs
+}
+
+/// Returns `foo` of a `/** @jsxImportSource foo */` pragma in the leading
+/// comments of `span`.
+pub fn find_jsx_import_source_in_comments<C>(comments: C, span: Span) -> Option<JsWord>
+where
+    C: Comments,
+{
+    if span.is_dummy() {
+        return None;
+    }
+
+    let mut source = None;
+
+    comments.with_leading(span.lo, |comments| {
+        for c in comments {
+            if let Some((_, rest)) = c.text.split_once("@jsxImportSource") {
+                if let Some(value) = rest.split_whitespace().next() {
+                    source = Some(value.into());
+                }
+            }
+        }
+    });
+
+    source
+}
+
+/// Calls `op` with the attributes of each `/// <reference ... />` in the
+/// leading comments of `span`.
+fn for_each_reference<C>(comments: C, span: Span, mut op: impl FnMut(&str))
 where
     C: Comments,
 {
@@ -36,32 +106,120 @@ where
         span
     );
 
-    let mut deps = vec![];
-
     comments.with_leading(span.lo, |comments| {
         for c in comments {
             if c.kind != CommentKind::Line {
                 continue;
             }
-            if let Some(cmt_text) = c
+            if let Some(attrs) = c
                 .text
                 .trim()
                 .strip_prefix('/')
                 .map(|s| s.trim())
                 .and_then(|s| s.strip_prefix("<reference"))
-                .and_then(|s| s.strip_suffix("\" />"))
+                .and_then(|s| s.strip_suffix("/>"))
                 .map(|s| s.trim())
             {
-                if let Some(path) = cmt_text.strip_prefix("path=\"") {
-                    deps.push(ImportRef::Path(path.into()));
-                } else if let Some(path) = cmt_text.strip_prefix("types=\"") {
-                    deps.push(ImportRef::Types(path.into()));
-                } else {
-                    // TODO: Handle lib, types
-                }
+                op(attrs)
             }
         }
     });
+}
 
-    deps
+/// Returns the value of the attribute if `attrs` starts with `name`.
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let value = attrs
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start()
+        .strip_prefix('"')?;
+
+    value.split('"').next()
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::{
+        comments::{Comment, SingleThreadedComments},
+        DUMMY_SP,
+    };
+
+    use super::*;
+
+    /// Returns comments with `texts` as the leading comments of `BytePos(1)`,
+    /// and the span to use with them.
+    fn leading_comments(texts: &[(CommentKind, &str)]) -> (SingleThreadedComments, Span) {
+        let comments = SingleThreadedComments::default();
+        for &(kind, text) in texts {
+            comments.add_leading(
+                BytePos(1),
+                Comment {
+                    kind,
+                    span: DUMMY_SP,
+                    text: text.into(),
+                },
+            );
+        }
+
+        (comments, Span::new(BytePos(1), BytePos(10), Default::default()))
+    }
+
+    #[test]
+    fn imports_in_comments() {
+        let (comments, span) = leading_comments(&[
+            (CommentKind::Line, "/ <reference path=\"./a.ts\" />"),
+            (CommentKind::Line, "/<reference types=\"node\"/>"),
+            (CommentKind::Line, "/ <reference lib=\"es2022\" />"),
+            (CommentKind::Line, " <reference path=\"./not-triple-slash.ts\" />"),
+            (CommentKind::Block, "/ <reference path=\"./block.ts\" />"),
+        ]);
+
+        assert_eq!(
+            find_imports_in_comments(&comments, span),
+            vec![ImportRef::Path("./a.ts".into()), ImportRef::Types("node".into())]
+        );
+    }
+
+    #[test]
+    fn imports_in_other_comments() {
+        let (comments, _) = leading_comments(&[(CommentKind::Line, "/ <reference path=\"./a.ts\" />")]);
+
+        let span = Span::new(BytePos(2), BytePos(10), Default::default());
+        assert_eq!(find_imports_in_comments(&comments, span), vec![]);
+    }
+
+    #[test]
+    fn lib_references_in_comments() {
+        let (comments, span) = leading_comments(&[
+            (CommentKind::Line, "/ <reference lib=\"ES2022\" />"),
+            (CommentKind::Line, "/ <reference lib=\"dom\" />"),
+            (CommentKind::Line, "/ <reference path=\"./a.ts\" />"),
+        ]);
+
+        assert_eq!(
+            find_lib_references_in_comments(&comments, span),
+            LibReferences {
+                libs: vec!["es2022".into(), "dom".into()],
+                no_default_lib: false,
+            }
+        );
+    }
+
+    #[test]
+    fn no_default_lib_in_comments() {
+        let (comments, span) = leading_comments(&[(CommentKind::Line, "/ <reference no-default-lib=\"true\" />")]);
+
+        assert_eq!(
+            find_lib_references_in_comments(&comments, span),
+            LibReferences {
+                libs: vec![],
+                no_default_lib: true,
+            }
+        );
+
+        let (comments, span) = leading_comments(&[(CommentKind::Line, "/ <reference no-default-lib=\"false\" />")]);
+
+        assert_eq!(find_lib_references_in_comments(&comments, span), LibReferences::default());
+    }
 }
@@ -123,6 +123,26 @@ macro_rules! try_cache {
             };
             let v = $cache.insert(key, v);
 
+            v
+        }
+    }};
+    ($cache:expr, $key:expr, stats = $stats:ident, $default_op:expr) => {{
+        let key = $key;
+
+        let cached = if $cache.can_cache(&key) { $cache.get(&key) } else { None };
+
+        if let Some(v) = cached {
+            $crate::stats::record(|s| s.$stats.hits += 1);
+            v
+        } else {
+            $crate::stats::record(|s| s.$stats.misses += 1);
+            let v: Result<_, _> = (|| $default_op)();
+            let v = match v {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+            let v = $cache.insert(key, v);
+
             v
         }
     }};
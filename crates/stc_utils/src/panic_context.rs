@@ -1,4 +1,8 @@
-use std::{cell::RefCell, panic, sync::Once};
+use std::cell::{Cell, RefCell};
+#[cfg(debug_assertions)]
+use std::{panic, sync::Once};
+
+use swc_common::Span;
 
 #[macro_export]
 macro_rules! panic_ctx {
@@ -17,13 +21,57 @@ pub fn new(_: String) -> () {}
 
 #[cfg(debug_assertions)]
 pub fn new(context: String) -> PanicContext {
-    static ONCE: Once = Once::new();
-    ONCE.call_once(PanicContext::init);
+    install_hook();
 
     with_ctx(|ctx| ctx.push(context));
     PanicContext { _priv: () }
 }
 
+/// Installs the panic hook which prints and records the panic context.
+///
+/// This is called by [new], but it should be called before [catch_unwind]
+/// if [take_last_panic_context] is used, because [new] may not be called at
+/// all before a panic.
+///
+/// [catch_unwind]: std::panic::catch_unwind
+pub fn install_hook() {
+    #[cfg(debug_assertions)]
+    {
+        static ONCE: Once = Once::new();
+        ONCE.call_once(PanicContext::init);
+    }
+}
+
+/// Returns the panic context of the last panic on the current thread.
+///
+/// The context is captured by the panic hook, as the guards are dropped
+/// while unwinding. This is always empty in release builds.
+pub fn take_last_panic_context() -> Vec<String> {
+    with_last(std::mem::take)
+}
+
+/// Records `span` as the span being validated.
+///
+/// The recorded span is used to locate an internal error if a panic occurs.
+#[inline]
+pub fn record_span(span: Span) {
+    LAST_SPAN.with(|last| last.set(Some(span)))
+}
+
+/// Returns the span recorded by [record_span], if any.
+pub fn last_span() -> Option<Span> {
+    LAST_SPAN.with(|last| last.get())
+}
+
+/// Clears the span recorded by [record_span].
+pub fn clear_span() {
+    LAST_SPAN.with(|last| last.set(None))
+}
+
+thread_local! {
+    static LAST_SPAN: Cell<Option<Span>> = Cell::new(None);
+}
+
 #[must_use]
 #[cfg(debug_assertions)]
 pub struct PanicContext {
@@ -42,6 +90,7 @@ impl PanicContext {
                         eprintln!("> {}\n", frame)
                     }
                 }
+                with_last(|last| *last = ctx.clone());
                 default_hook(panic_info)
             })
         };
@@ -63,3 +112,10 @@ fn with_ctx(f: impl FnOnce(&mut Vec<String>)) {
     }
     CTX.with(|ctx| f(&mut ctx.borrow_mut()))
 }
+
+fn with_last<T>(f: impl FnOnce(&mut Vec<String>) -> T) -> T {
+    thread_local! {
+        static LAST: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    }
+    LAST.with(|last| f(&mut last.borrow_mut()))
+}
@@ -2,11 +2,27 @@ use std::{cell::RefCell, mem::replace};
 
 use swc_common::Span;
 
+/// Maximum depth of nested type instantiations. Same as `tsc`.
+pub const MAX_INSTANTIATION_DEPTH: usize = 100;
+
+/// Maximum number of type instantiations while validating a statement. Same
+/// as `tsc`.
+pub const MAX_INSTANTIATION_COUNT: usize = 5_000_000;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StackOverflowError {
     pub span: Span,
 }
 
+/// Returned by [track_instantiation] if the limit of instantiation depth or
+/// count is reached.
+///
+/// This should be reported as `TS2589`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstantiationLimitError {
+    pub span: Span,
+}
+
 pub struct StartGuard {
     /// Previous value.
     prev: usize,
@@ -19,7 +35,7 @@ impl Drop for StartGuard {
 }
 
 /// Start tracking for stack overflows. [track] will return error on `max`-th
-/// call. (Currently it's noop)
+/// nested call.
 pub fn start(max: usize) -> StartGuard {
     let prev = with_ctx(|v| replace(v, max));
     StartGuard { prev }
@@ -56,3 +72,76 @@ fn with_ctx<T>(f: impl FnOnce(&mut usize) -> T) -> T {
     }
     CTX.with(|ctx| f(&mut ctx.borrow_mut()))
 }
+
+#[derive(Debug, Default)]
+struct InstantiationCtx {
+    depth: usize,
+    count: usize,
+    /// Span of the outermost instantiation.
+    span: Option<Span>,
+}
+
+pub struct InstantiationGuard {
+    _priv: (),
+}
+
+impl Drop for InstantiationGuard {
+    fn drop(&mut self) {
+        with_instantiation_ctx(|ctx| {
+            ctx.depth -= 1;
+            if ctx.depth == 0 {
+                ctx.span = None;
+            }
+        })
+    }
+}
+
+/// Resets the number of instantiations.
+///
+/// `tsc` does this for each statement and expression it checks, so the count
+/// limit applies to a single statement.
+pub fn reset_instantiation_count() {
+    with_instantiation_ctx(|ctx| ctx.count = 0)
+}
+
+/// Should be stored as a variable like `let _instantiation =
+/// stack::track_instantiation(span)?;`.
+///
+/// Ported from `instantiateTypeWithAlias` of `tsc`.
+pub fn track_instantiation(span: Span) -> Result<InstantiationGuard, InstantiationLimitError> {
+    with_instantiation_ctx(|ctx| {
+        if ctx.depth >= MAX_INSTANTIATION_DEPTH || ctx.count >= MAX_INSTANTIATION_COUNT {
+            tracing::error!(
+                "Type instantiation is excessively deep: {:?} (depth = {}, count = {})",
+                span,
+                ctx.depth,
+                ctx.count
+            );
+            return Err(InstantiationLimitError { span });
+        }
+
+        if ctx.depth == 0 {
+            ctx.span = Some(span);
+        }
+        ctx.depth += 1;
+        ctx.count += 1;
+
+        Ok(InstantiationGuard { _priv: () })
+    })
+}
+
+/// Returns the error for the outermost instantiation if types are being
+/// instantiated.
+///
+/// Running out of stack while instantiating types means the instantiation is
+/// too deep, so it should be reported as `TS2589` instead of a stack overflow.
+pub fn instantiation_limit_error() -> Option<InstantiationLimitError> {
+    with_instantiation_ctx(|ctx| ctx.span.map(|span| InstantiationLimitError { span }))
+}
+
+fn with_instantiation_ctx<T>(f: impl FnOnce(&mut InstantiationCtx) -> T) -> T {
+    thread_local! {
+        static CTX: RefCell<InstantiationCtx> = Default::default();
+    }
+    CTX.with(|ctx| f(&mut ctx.borrow_mut()))
+}
@@ -0,0 +1,122 @@
+//! Counters used by `--extendedDiagnostics`.
+//!
+//! Counters are thread-local, so modules checked in parallel don't affect
+//! each other. Nothing is recorded unless [enable] is called, because types
+//! are created very frequently.
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Display},
+    mem::{replace, take},
+    ops::AddAssign,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Returns [None] if the cache is not used at all.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return None;
+        }
+
+        Some(self.hits as f64 / total as f64)
+    }
+}
+
+impl AddAssign for CacheStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.hits += rhs.hits;
+        self.misses += rhs.misses;
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.hit_rate() {
+            Some(rate) => write!(f, "{:.1}% ({} / {})", rate * 100.0, self.hits, self.hits + self.misses),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of type structs created.
+    pub types_created: u64,
+    /// Number of types converted to `Type::Arc`.
+    pub freezes: u64,
+    /// Number of generic instantiations.
+    pub instantiations: u64,
+    /// Number of assignability checks.
+    pub assign_checks: u64,
+
+    /// Number of expanded mapped types.
+    pub mapped_expansions: u64,
+    /// Cache of `keyof` of type literals.
+    pub keyof: CacheStats,
+}
+
+impl AddAssign for Stats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.types_created += rhs.types_created;
+        self.freezes += rhs.freezes;
+        self.instantiations += rhs.instantiations;
+        self.assign_checks += rhs.assign_checks;
+        self.mapped_expansions += rhs.mapped_expansions;
+        self.keyof += rhs.keyof;
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<28}{}", "Types:", self.types_created)?;
+        writeln!(f, "{:<28}{}", "Freezes:", self.freezes)?;
+        writeln!(f, "{:<28}{}", "Instantiations:", self.instantiations)?;
+        writeln!(f, "{:<28}{}", "Assignability checks:", self.assign_checks)?;
+        writeln!(f, "{:<28}{}", "Mapped type expansions:", self.mapped_expansions)?;
+        write!(f, "{:<28}{}", "Cache hits (keyof):", self.keyof)
+    }
+}
+
+/// Enables recording of counters, for all threads.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Updates the counters of the current thread, if enabled.
+#[inline]
+pub fn record(f: impl FnOnce(&mut Stats)) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    with_stats(|stats| f(stats))
+}
+
+/// Runs `op` with fresh counters and returns what `op` recorded.
+///
+/// The counters of the caller are restored afterwards, so nested calls are
+/// not counted twice.
+pub fn scope<T>(op: impl FnOnce() -> T) -> (T, Stats) {
+    let prev = with_stats(take);
+    let ret = op();
+    let stats = with_stats(|stats| replace(stats, prev));
+
+    (ret, stats)
+}
+
+fn with_stats<T>(f: impl FnOnce(&mut Stats) -> T) -> T {
+    thread_local! {
+        static STATS: RefCell<Stats> = Default::default();
+    }
+    STATS.with(|stats| f(&mut stats.borrow_mut()))
+}
@@ -1,18 +1,125 @@
+use std::path::{Path, PathBuf};
+
 use clap::Args;
+use stc_ts_type_checker::tsconfig::TsConfig;
 
 /// Perform type checking, but this command is not public api and is only used
 /// for testing.
 #[derive(Debug, Args)]
 #[clap(rename_all = "camel-case")]
 pub struct TestCommand {
-    #[clap(name = "file")]
-    pub file: String,
+    /// Files to check. Modules imported by multiple files are analyzed only
+    /// once.
+    ///
+    /// Defaults to the files of `--project`, using its `files`, `include` and
+    /// `exclude`.
+    #[clap(name = "file", required_unless_present = "project")]
+    pub files: Vec<String>,
 
     /// The builtin libraries to load. Defaults to `es5`.
     #[clap(long)]
     pub libs: Option<Vec<String>>,
 
+    /// Path to a `tsconfig.json` file. Its `compilerOptions` configure the
+    /// type checker.
+    #[clap(long)]
+    pub project: Option<PathBuf>,
+
+    /// The module specifier used to import the JSX factory functions.
+    /// Overrides `jsxImportSource` of `--project`.
+    #[clap(long)]
+    pub jsx_import_source: Option<String>,
+
     /// Directory name of typings to load.
     #[clap(long)]
     pub types: Option<Vec<String>>,
+
+    /// Build projects using project references, like `tsc --build`. Files
+    /// are `tsconfig.json` files or directories containing them.
+    #[clap(long)]
+    pub build: bool,
+
+    /// Type-aware lint rules to run, e.g. `no-floating-promises`.
+    #[clap(long)]
+    pub lints: Option<Vec<String>>,
+
+    /// Report exports which are not imported by any module reachable from the
+    /// entry points, and modules which are not reachable from them.
+    #[clap(long)]
+    pub report_unused_exports: bool,
+
+    /// Print the time taken by each phase, type instantiation and relation
+    /// counters, and the slowest files.
+    #[clap(long)]
+    pub extended_diagnostics: bool,
+
+    /// Write the timings to the given path, in the Chrome trace event format.
+    #[clap(long)]
+    pub generate_trace: Option<PathBuf>,
+
+    /// Entry points used by `--report-unused-exports`. Defaults to the files
+    /// being checked which are not imported by other files.
+    #[clap(long)]
+    pub entries: Option<Vec<String>>,
+}
+
+impl TestCommand {
+    /// Returns the files to check.
+    ///
+    /// `config` is the config loaded from `--project`.
+    pub fn root_files(&self, config: Option<&TsConfig>) -> Vec<PathBuf> {
+        match (config, &self.project) {
+            (Some(config), Some(project)) if self.files.is_empty() => {
+                let dir = match project.parent() {
+                    Some(dir) if !dir.as_os_str().is_empty() => dir,
+                    _ => Path::new("."),
+                };
+
+                config.root_files(dir)
+            }
+            _ => self.files.iter().map(PathBuf::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        cmd: TestCommand,
+    }
+
+    #[test]
+    fn files_are_required_without_project() {
+        assert!(Cli::try_parse_from(["stc"]).is_err());
+    }
+
+    #[test]
+    fn files_default_to_project() {
+        let dir = std::env::temp_dir().join(format!("stc-cli-project-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("tsconfig.json"), r#"{ "include": ["src"] }"#).unwrap();
+        fs::write(dir.join("src").join("a.ts"), "").unwrap();
+        fs::write(dir.join("b.ts"), "").unwrap();
+
+        let project = dir.join("tsconfig.json");
+        let cli = Cli::try_parse_from(["stc", "--project", project.to_str().unwrap()]).unwrap();
+        let config = TsConfig::load(&project).unwrap();
+
+        assert_eq!(cli.cmd.root_files(Some(&config)), vec![dir.join("src").join("a.ts")]);
+
+        // Files given explicitly are used instead.
+        let cli = Cli::try_parse_from(["stc", "--project", project.to_str().unwrap(), "b.ts"]).unwrap();
+        assert_eq!(cli.cmd.root_files(Some(&config)), vec![PathBuf::from("b.ts")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -10,7 +10,10 @@ use stc_ts_file_analyzer::env::EnvFactory;
 use stc_ts_lang_server::LspCommand;
 use stc_ts_module_loader::resolvers::node::NodeResolver;
 use stc_ts_type_checker::{
+    build::{BuildStatus, Builder, ProjectGraph},
     loader::{DefaultFileLoader, ModuleLoader},
+    timings::Timings,
+    tsconfig::TsConfig,
     Checker,
 };
 use swc_common::{
@@ -68,7 +71,7 @@ async fn main() -> Result<(), Error> {
             let libs = {
                 let start = Instant::now();
 
-                let mut libs = match cmd.libs {
+                let mut libs = match &cmd.libs {
                     Some(libs) => libs.iter().flat_map(|s| Lib::load(s)).collect::<Vec<_>>(),
                     None => Lib::load("es5"),
                 };
@@ -82,59 +85,126 @@ async fn main() -> Result<(), Error> {
                 libs
             };
 
-            let env = Env::simple(Rule { ..Default::default() }, EsVersion::latest(), ModuleConfig::None, &libs);
+            if cmd.build {
+                build_projects(cm, handler, libs, &cmd.files);
+            } else {
+                let config = cmd.project.as_deref().map(TsConfig::load).transpose()?;
+                for name in config.iter().flat_map(|config| config.compiler_options.unsupported_options()) {
+                    handler
+                        .struct_warn(&format!("compiler option `{}` is not supported yet, so errors may be wrong", name))
+                        .emit();
+                }
+                let env = match &config {
+                    Some(config) => config.compiler_options.env(EsVersion::latest(), &libs)?,
+                    None => Env::simple(Rule { ..Default::default() }, EsVersion::latest(), ModuleConfig::None, &libs),
+                };
+                let env = match &cmd.jsx_import_source {
+                    Some(source) => env.with_jsx_import_source(Some(source.as_str().into())),
+                    None => env,
+                };
 
-            let path = PathBuf::from(cmd.file);
+                let root_files = cmd.root_files(config.as_ref());
+                let files = root_files
+                    .iter()
+                    .map(|file| Arc::new(FileName::Real(file.clone())))
+                    .collect::<Vec<_>>();
 
-            {
-                let start = Instant::now();
+                let timings = (cmd.extended_diagnostics || cmd.generate_trace.is_some()).then(|| Arc::new(Timings::default()));
 
-                let checker = Checker::new(
-                    cm.clone(),
-                    handler.clone(),
-                    env.clone(),
-                    None,
-                    ModuleLoader::new(cm.clone(), env.clone(), NodeResolver, DefaultFileLoader),
-                );
+                let mut loader = ModuleLoader::new(cm.clone(), env.clone(), NodeResolver, DefaultFileLoader);
+                if let Some(timings) = &timings {
+                    loader = loader.with_timings(timings.clone());
+                }
 
-                checker.load_typings(&path, None, cmd.types.as_deref());
+                let mut checker = Checker::new(cm, handler.clone(), env, None, loader).with_lints(cmd.lints.unwrap_or_default());
+                if let Some(timings) = &timings {
+                    checker = checker.with_timings(timings.clone());
+                }
 
-                let end = Instant::now();
+                {
+                    let start = Instant::now();
 
-                log::info!("Loading typing libraries took {:?}", end - start);
-            }
+                    checker.load_typings_of_files(&root_files, None, cmd.types.as_deref());
 
-            let mut errors = vec![];
+                    let end = Instant::now();
 
-            let start = Instant::now();
-            {
-                let mut checker = Checker::new(
-                    cm.clone(),
-                    handler.clone(),
-                    env.clone(),
-                    None,
-                    ModuleLoader::new(cm, env, NodeResolver, DefaultFileLoader),
-                );
+                    log::info!("Loading typing libraries took {:?}", end - start);
+                }
 
-                checker.check(Arc::new(FileName::Real(path)));
+                let mut errors = vec![];
 
-                errors.extend(checker.take_errors());
-            }
-            let end = Instant::now();
+                let start = Instant::now();
+                {
+                    checker.check_all(&files);
+
+                    let mut errors_by_file = checker.take_errors_by_file().into_iter().collect::<Vec<_>>();
+                    errors_by_file.sort_by_cached_key(|(file, _)| file.to_string());
+
+                    for (file, file_errors) in errors_by_file {
+                        log::info!("Found {} errors in {}", file_errors.len(), file);
+                        errors.extend(file_errors);
+                    }
+
+                    if cmd.report_unused_exports {
+                        let entries = cmd
+                            .entries
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|v| Arc::new(FileName::Real(v.into())))
+                            .collect::<Vec<_>>();
+
+                        let report = checker.find_unused_exports(&files, &entries);
+
+                        for export in &report.unused {
+                            handler
+                                .struct_span_warn(export.span, &format!("`{}` is exported but never imported", export.name))
+                                .emit();
+                        }
+                        for export in &report.only_used_as_type {
+                            handler
+                                .struct_span_warn(
+                                    export.span,
+                                    &format!("`{}` is exported as a value but only imported as a type", export.name),
+                                )
+                                .emit();
+                        }
+                        for module in &report.unreachable_modules {
+                            handler
+                                .struct_warn(&format!("`{}` is not reachable from the entry points", module))
+                                .emit();
+                        }
+                    }
+                }
+                let end = Instant::now();
 
-            log::info!("Checking took {:?}", end - start);
+                log::info!("Checking took {:?}", end - start);
 
-            {
-                let start = Instant::now();
-                for err in &errors {
-                    err.emit(&handler);
+                if let Some(timings) = &timings {
+                    if cmd.extended_diagnostics {
+                        println!("{}", timings.report(10));
+                    }
+
+                    if let Some(path) = &cmd.generate_trace {
+                        if let Err(err) = timings.write_chrome_trace(path) {
+                            handler
+                                .struct_err(&format!("failed to write trace to `{}`: {:?}", path.display(), err))
+                                .emit();
+                        }
+                    }
                 }
 
-                let end = Instant::now();
+                {
+                    let start = Instant::now();
+                    for err in &errors {
+                        err.emit(&handler);
+                    }
+
+                    let end = Instant::now();
 
-                log::info!("Found {} errors", errors.len());
+                    log::info!("Found {} errors", errors.len());
 
-                log::info!("Error reporting took {:?}", end - start);
+                    log::info!("Error reporting took {:?}", end - start);
+                }
             }
         }
         Command::Lsp(cmd) => {
@@ -148,3 +218,46 @@ async fn main() -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Builds projects and reports errors.
+///
+/// Each project is checked using its own `compilerOptions`.
+fn build_projects(cm: Arc<SourceMap>, handler: Arc<Handler>, libs: Vec<Lib>, projects: &[String]) {
+    let builder = Builder::new(cm, handler.clone(), libs);
+
+    for project in projects {
+        let start = Instant::now();
+
+        let graph = match ProjectGraph::load(&PathBuf::from(project)) {
+            Ok(graph) => graph,
+            Err(err) => {
+                handler.struct_err(&format!("{:?}", err)).emit();
+                continue;
+            }
+        };
+
+        for result in builder.build(&graph) {
+            match result.status {
+                BuildStatus::UpToDate => {
+                    log::info!("`{}` is up to date", result.config_path.display());
+                }
+                BuildStatus::Checked { errors } => {
+                    log::info!("Found {} errors in `{}`", errors.len(), result.config_path.display());
+
+                    for err in &errors {
+                        err.emit(&handler);
+                    }
+                }
+                BuildStatus::Failed(err) => {
+                    handler
+                        .struct_err(&format!("failed to build `{}`: {:?}", result.config_path.display(), err))
+                        .emit();
+                }
+            }
+        }
+
+        let end = Instant::now();
+
+        log::info!("Building `{}` took {:?}", project, end - start);
+    }
+}